@@ -196,7 +196,7 @@ fn to_rust_type_uncached(full_ty: &GodotTy, ctx: &mut Context) -> RustTy {
     }
 
     // Only place where meta is relevant is here.
-    if !ty.starts_with("typedarray::") {
+    if !ty.starts_with("typedarray::") && !ty.starts_with("typeddictionary::") {
         if let Some(hardcoded) = to_hardcoded_rust_ident(full_ty) {
             return RustTy::BuiltinIdent {
                 ty: ident(hardcoded),
@@ -240,6 +240,8 @@ fn to_rust_type_uncached(full_ty: &GodotTy, ctx: &mut Context) -> RustTy {
                 elem_class: elem_ty.to_string(),
             }
         };
+    } else if let Some(kv_ty) = ty.strip_prefix("typeddictionary::") {
+        return to_typed_dictionary_type(kv_ty, ctx);
     }
 
     // Note: do not check if it's a known engine class, because that will not work in minimal mode (since not all classes are stored)
@@ -279,6 +281,42 @@ fn to_rust_type_uncached(full_ty: &GodotTy, ctx: &mut Context) -> RustTy {
     }
 }
 
+/// Converts the key/value part of a `typeddictionary::K;V` type to `Dict<K, V>`.
+///
+/// Enum keys or values are not supported as `Dict` parameters (they aren't array elements); such dictionaries fall back to untyped `Dictionary`.
+fn to_typed_dictionary_type(kv_ty: &str, ctx: &mut Context) -> RustTy {
+    let (key_ty, value_ty) = kv_ty
+        .split_once(';')
+        .unwrap_or_else(|| panic!("typed dictionary `{kv_ty}` has no `;` separator"));
+
+    let mut engine_classes = vec![];
+    let mut convert_side = |godot_ty: &str| -> Option<TokenStream> {
+        let rust_ty = to_rust_type(godot_ty, None, ctx);
+        match rust_ty {
+            RustTy::EngineEnum { .. } => None,
+            RustTy::EngineClass { .. } => {
+                engine_classes.push(godot_ty.to_string());
+
+                // Like in Array, store Gd and not Option<Gd>.
+                Some(rust_ty.tokens_non_null())
+            }
+            _ => Some(rust_ty.to_token_stream()),
+        }
+    };
+
+    let (Some(rust_key), Some(rust_value)) = (convert_side(key_ty), convert_side(value_ty)) else {
+        return RustTy::BuiltinIdent {
+            ty: ident("Dictionary"),
+            arg_passing: ArgPassing::ByRef,
+        };
+    };
+
+    RustTy::EngineDict {
+        tokens: quote! { Dict<#rust_key, #rust_value> },
+        engine_classes,
+    }
+}
+
 /// Converts a Godot JSON type-name to a Rust enum/bitfield.
 ///
 /// Input: `bitfield::Mesh.ArrayFormat` or `enum::Error` **without** the `bitfield::` or `enum::` prefix.  \
@@ -332,7 +370,12 @@ fn to_rust_expr_inner(expr: &str, ty: &RustTy, is_inner: bool) -> TokenStream {
         "false" => return quote! { false },
         "[]" | "{}" if is_inner => return quote! {},
         "[]" => return quote! { Array::new() }, // VariantArray or Array<T>
-        "{}" => return quote! { Dictionary::new() },
+        "{}" => {
+            return match ty {
+                RustTy::EngineDict { .. } => quote! { Dict::new() },
+                _ => quote! { Dictionary::new() },
+            }
+        }
         "null" => {
             return match ty {
                 RustTy::BuiltinIdent { ty: ident, .. } if ident == "Variant" => {
@@ -461,6 +504,13 @@ fn to_rust_expr_inner(expr: &str, ty: &RustTy, is_inner: bool) -> TokenStream {
                 assert_eq!(wrapped, "[]", "only empty typed arrays supported for now");
                 ("Array", "new")
             }
+            dict if dict.starts_with("Dictionary[") => {
+                assert_eq!(
+                    wrapped, "{}",
+                    "only empty typed dictionaries supported for now"
+                );
+                ("Dict", "new")
+            }
             _ => panic!("unsupported type: {godot_ty}"),
         };
 
@@ -550,6 +600,12 @@ fn gdscript_to_rust_expr() {
     };
     let ty_variant = Some(&ty_variant);
 
+    let ty_dict = RustTy::EngineDict {
+        tokens: quote! { Dict<GString, i64> },
+        engine_classes: vec![],
+    };
+    let ty_dict = Some(&ty_dict);
+
     // let ty_object = RustTy::EngineClass {
     //     tokens: quote! { Gd<MyClass> },
     //     class: "MyClass".to_string(),
@@ -643,6 +699,10 @@ fn gdscript_to_rust_expr() {
         ("Array[RDPipelineSpecializationConstant]([])",    None,               quote! { Array::new() }),
         ("Array[RID]([])",                                 None,               quote! { Array::new() }),
 
+        // Typed dictionaries
+        ("{}",                                             ty_dict,            quote! { Dict::new() }),
+        ("Dictionary[String, int]({})",                    ty_dict,            quote! { Dict::new() }),
+
         // Composites with destructuring
         ("Transform3D(1, 2, 3, 4, -1.1, -1.2, -1.3, -1.4, 0, 0, 0, 0)", None,  quote! {
             Transform3D::__internal_codegen(
@@ -490,7 +490,8 @@ pub(crate) fn make_param_or_field_type(
         }
         | RustTy::BuiltinArray { .. }
         | RustTy::GenericArray
-        | RustTy::EngineArray { .. } => {
+        | RustTy::EngineArray { .. }
+        | RustTy::EngineDict { .. } => {
             let lft = lifetimes.next();
             special_ty = Some(quote! { RefArg<#lft, #ty> });
 
@@ -543,7 +544,8 @@ pub(crate) fn make_arg_expr(name: &Ident, ty: &RustTy, expr: FnArgExpr) -> Token
             ..
         }
         | RustTy::BuiltinArray { .. }
-        | RustTy::EngineArray { .. } => match expr {
+        | RustTy::EngineArray { .. }
+        | RustTy::EngineDict { .. } => match expr {
             FnArgExpr::PassToFfi => quote! { RefArg::new(#name) },
             FnArgExpr::PassToFfiFromEx => quote! { #name.cow_as_arg() },
             FnArgExpr::Forward => quote! { #name },
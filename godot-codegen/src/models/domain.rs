@@ -745,6 +745,15 @@ pub enum RustTy {
         elem_class: String,
     },
 
+    /// `Dict<GString, Gd<Node>>`, from a `typeddictionary::K;V` type. Untyped dictionaries are mapped as `BuiltinIdent("Dictionary")`.
+    EngineDict {
+        tokens: TokenStream,
+
+        /// Engine classes appearing as key or value type.
+        #[allow(dead_code)] // Only read in minimal config.
+        engine_classes: Vec<String>,
+    },
+
     /// `module::Enum` or `module::Bitfield`
     EngineEnum {
         tokens: TokenStream,
@@ -858,6 +867,7 @@ impl ToTokens for RustTy {
                 is_const: false,
             } => quote! { *mut #inner }.to_tokens(tokens),
             RustTy::EngineArray { tokens: path, .. } => path.to_tokens(tokens),
+            RustTy::EngineDict { tokens: path, .. } => path.to_tokens(tokens),
             RustTy::EngineEnum { tokens: path, .. } => path.to_tokens(tokens),
             RustTy::EngineClass {
                 is_nullable,
@@ -52,6 +52,9 @@ fn is_type_excluded(ty: &str, ctx: &mut Context) -> bool {
             RustTy::RawPointer { inner, .. } => is_rust_type_excluded(inner),
             RustTy::SysPointerType { .. } => true,
             RustTy::EngineArray { elem_class, .. } => is_class_excluded(elem_class.as_str()),
+            RustTy::EngineDict { engine_classes, .. } => engine_classes
+                .iter()
+                .any(|class| is_class_excluded(class.as_str())),
             RustTy::EngineEnum {
                 surrounding_class, ..
             } => match surrounding_class.as_ref() {
@@ -151,6 +154,7 @@ const SELECTED_CLASSES: &[&str] = &[
     "ResourceLoader",
     "ResourceSaver",
     "FileAccess",
+    "DirAccess",
     //
     // Scene (node_test, rpc_test)
     "MainLoop", // base of SceneTree
@@ -183,8 +187,13 @@ const SELECTED_CLASSES: &[&str] = &[
     // Godot servers (for RID support)
     "RenderingServer",
     //
+    // Async runtime (spawn_blocking, sleep)
+    "WorkerThreadPool",
+    "SceneTreeTimer",
+    //
     // Misc
     "Time", // usage: enum_test.enum_hash()
     "HTTPRequest",
     "ResourceFormatLoader", // TODO: replace?
+    "ResourceFormatSaver",  // script_language
 ];
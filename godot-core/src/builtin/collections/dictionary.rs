@@ -21,7 +21,7 @@ use crate::meta::{ElementType, ExtVariantType, FromGodot, ToGodot};
 /// Ordered associative hash-table, mapping keys to values.
 ///
 /// The keys and values of the dictionary are all `Variant`s, so they can be of different types.
-/// Variants are designed to be generally cheap to clone. Typed dictionaries are planned in a future godot-rust version.
+/// Variants are designed to be generally cheap to clone. For statically typed keys and values, see [`Dict<K, V>`][crate::builtin::Dict].
 ///
 /// Check out the [book](https://godot-rust.github.io/book/godot-api/builtins.html#arrays-and-dictionaries) for a tutorial on dictionaries.
 ///
@@ -419,7 +419,7 @@ impl Dictionary {
 
     /// Returns the runtime element type information for keys in this dictionary.
     ///
-    /// Provides information about Godot typed dictionaries, even though godot-rust currently doesn't implement generics for those.
+    /// Provides information about Godot typed dictionaries. To work with those in a statically typed way, see [`Dict<K, V>`][crate::builtin::Dict].
     ///
     /// The result is generally cached, so feel free to call this method repeatedly.
     ///
@@ -439,7 +439,7 @@ impl Dictionary {
 
     /// Returns the runtime element type information for values in this dictionary.
     ///
-    /// Provides information about Godot typed dictionaries, even though godot-rust currently doesn't implement generics for those.
+    /// Provides information about Godot typed dictionaries. To work with those in a statically typed way, see [`Dict<K, V>`][crate::builtin::Dict].
     ///
     /// The result is generally cached, so feel free to call this method repeatedly.
     ///
@@ -473,6 +473,52 @@ impl Dictionary {
         unsafe { interface_fn!(dictionary_operator_index)(self.sys_mut(), key.var_sys()) }
     }
 
+    /// Sets the key and value types of the inner dictionary.
+    ///
+    /// Used by [`Dict<K, V>`][crate::builtin::Dict] to create Godot typed dictionaries.
+    ///
+    /// # Safety
+    /// Must only be called once, directly after creation.
+    #[cfg(since_api = "4.4")]
+    pub(super) unsafe fn init_inner_types(&mut self, key_ty: ElementType, value_ty: ElementType) {
+        sys::strict_assert!(self.is_empty());
+        sys::strict_assert!(
+            self.cached_key_type.get().is_none() && self.cached_value_type.get().is_none(),
+            "init_inner_types() called twice"
+        );
+
+        // Immediately set cache to static types.
+        let _ = self.cached_key_type.set(key_ty);
+        let _ = self.cached_value_type.set(value_ty);
+
+        if !key_ty.is_typed() && !value_ty.is_typed() {
+            return;
+        }
+
+        let script = Variant::nil();
+
+        // Class names must be empty if variant_type != OBJECT. Empty StringName must remain valid until the FFI call.
+        let empty_string_name = crate::builtin::StringName::default();
+        let class_name_sys = |elem_ty: &ElementType| match elem_ty.class_id() {
+            Some(class_id) => class_id.string_sys(),
+            None => empty_string_name.string_sys(),
+        };
+
+        // SAFETY: Valid pointers are passed in.
+        // Relevant for correctness, not safety: the dictionary is a newly created, empty, untyped dictionary.
+        unsafe {
+            interface_fn!(dictionary_set_typed)(
+                self.sys_mut(),
+                key_ty.variant_type().sys(),
+                class_name_sys(&key_ty),
+                script.var_sys(),
+                value_ty.variant_type().sys(),
+                class_name_sys(&value_ty),
+                script.var_sys(),
+            );
+        }
+    }
+
     /// Execute a function that creates a new Dictionary, transferring cached element types if available.
     ///
     /// This is a convenience helper for methods that create new Dictionary instances and want to preserve
@@ -847,6 +893,39 @@ impl<K: FromGodot> Iterator for TypedKeys<'_, K> {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    /// Serializes the dictionary as a sequence of `[key, value]` pairs, in insertion order.
+    ///
+    /// A map representation is not used, since keys can be arbitrary variants, which many formats (such as JSON) don't support as map keys.
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl Serialize for Dictionary {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_seq(self.iter_shared())
+        }
+    }
+
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<'de> Deserialize<'de> for Dictionary {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let pairs = Vec::<(Variant, Variant)>::deserialize(deserializer)?;
+            Ok(pairs.into_iter().collect())
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helper functions
 
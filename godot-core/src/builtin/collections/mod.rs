@@ -11,12 +11,14 @@ mod dictionary;
 mod extend_buffer;
 mod packed_array;
 mod packed_array_element;
+mod typed_dictionary;
 
 // Re-export in godot::builtin.
 pub(crate) mod containers {
     pub use super::array::{Array, VariantArray};
     pub use super::dictionary::Dictionary;
     pub use super::packed_array::*;
+    pub use super::typed_dictionary::Dict;
 }
 
 // Re-export in godot::builtin::iter.
@@ -770,6 +770,41 @@ impl<T: PackedArrayElement + fmt::Display> fmt::Display for PackedArray<T> {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<T> Serialize for PackedArray<T>
+    where
+        T: PackedArrayElement + Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_seq(self.as_slice())
+        }
+    }
+
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<'de, T> Deserialize<'de> for PackedArray<T>
+    where
+        T: PackedArrayElement + Deserialize<'de>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Vec::<T>::deserialize(deserializer).map(Self::from)
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Specific API for PackedByteArray
 
@@ -0,0 +1,535 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use godot_ffi as sys;
+
+use crate::builtin::iter::{DictTypedIter, DictTypedKeys};
+use crate::builtin::{Array, Dictionary, GString};
+use crate::meta;
+use crate::meta::error::ConvertError;
+use crate::meta::{
+    ArrayElement, AsArg, ElementType, FromGodot, GodotConvert, GodotType, PropertyHintInfo, RefArg,
+    ToGodot,
+};
+use crate::registry::property::{BuiltinExport, Export, Var};
+
+/// Statically typed Godot `Dictionary`, mapping keys of type `K` to values of type `V`.
+///
+/// `Dict<K, V>` is to [`Dictionary`] what [`Array<T>`] is to [`VariantArray`][crate::builtin::VariantArray]: the same engine type,
+/// but with key and value types known at compile time. Accessors take and return `K` and `V` directly, without going through `Variant`.
+///
+/// Key and value types are subject to the same restrictions as array elements (see [`ArrayElement`]). `Variant` can be used for either
+/// side, e.g. `Dict<GString, Variant>` maps strings to arbitrary values.
+///
+/// # Typed dictionaries in Godot
+/// Starting with Godot 4.4, the engine supports typed dictionaries natively (`Dictionary[String, int]` in GDScript). A `Dict<K, V>` created
+/// in Rust is registered as such a typed dictionary, and conversions from Godot (`FromGodot`, `#[func]` parameters, properties) verify that the
+/// runtime key/value types match `K` and `V`, returning a [`ConvertError`] otherwise.
+///
+/// Before Godot 4.4, the underlying dictionary is untyped. Conversions then validate every entry instead, which is `O(n)`.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// let mut scores: Dict<GString, i64> = Dict::new();
+/// scores.set("Alice", 120);
+/// scores.set("Bob", 95);
+///
+/// let alice: i64 = scores.at("Alice");
+/// let carol: Option<i64> = scores.get("Carol"); // None
+///
+/// for (name, score) in scores.iter_shared() {
+///     println!("{name}: {score}");
+/// }
+///
+/// // Access the untyped representation, e.g. for APIs taking `Dictionary`.
+/// let untyped: &Dictionary = scores.as_untyped();
+/// ```
+///
+/// # Reference semantics and thread safety
+/// The same principles apply as for [`Dictionary`]; `Clone` creates a new reference to the same data.
+///
+/// # Godot docs
+/// [`Dictionary[K, V]` (stable)](https://docs.godotengine.org/en/stable/classes/class_dictionary.html)
+pub struct Dict<K: ArrayElement, V: ArrayElement> {
+    inner: Dictionary,
+    _phantom: PhantomData<(K, V)>,
+}
+
+impl<K: ArrayElement, V: ArrayElement> Dict<K, V> {
+    /// Wraps a dictionary whose types have already been verified (or are about to be initialized).
+    fn from_untyped_unchecked(inner: Dictionary) -> Self {
+        Self {
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Constructs an empty `Dict`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ⚠️ Returns the value for the given key, or panics.
+    ///
+    /// If you want to check for presence, use [`get()`][Self::get].
+    ///
+    /// # Panics
+    /// If there is no value for the given key.
+    pub fn at(&self, key: impl AsArg<K>) -> V {
+        meta::arg_into_ref!(key: K);
+        let key = key.to_variant();
+
+        match self.inner.get(key.clone()) {
+            Some(value) => V::from_variant(&value),
+            None => panic!("key {key:?} missing in dictionary: {self:?}"),
+        }
+    }
+
+    /// Returns the value for the given key, or `None`.
+    ///
+    /// When you are certain that a key is present, use [`at()`][Self::at] instead.
+    pub fn get(&self, key: impl AsArg<K>) -> Option<V> {
+        meta::arg_into_ref!(key: K);
+
+        self.inner
+            .get(key.to_variant())
+            .map(|value| V::from_variant(&value))
+    }
+
+    /// Returns `true` if the dictionary contains the given key.
+    ///
+    /// _Godot equivalent: `has`_
+    #[doc(alias = "has")]
+    pub fn contains_key(&self, key: impl AsArg<K>) -> bool {
+        meta::arg_into_ref!(key: K);
+        self.inner.contains_key(key.to_variant())
+    }
+
+    /// Returns the number of entries in the dictionary.
+    ///
+    /// _Godot equivalent: `size`_
+    #[doc(alias = "size")]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the dictionary is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Removes all key-value pairs from the dictionary.
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+
+    /// Set a key to a given value.
+    ///
+    /// If you are interested in the previous value, use [`insert()`][Self::insert] instead.
+    ///
+    /// _Godot equivalent: `dict[key] = value`_
+    pub fn set(&mut self, key: impl AsArg<K>, value: impl AsArg<V>) {
+        meta::arg_into_ref!(key: K);
+        meta::arg_into_ref!(value: V);
+
+        self.inner.set(key.to_variant(), value.to_variant());
+    }
+
+    /// Insert a value at the given key, returning the previous value for that key (if available).
+    ///
+    /// If you don't need the previous value, use [`set()`][Self::set] instead.
+    #[must_use]
+    pub fn insert(&mut self, key: impl AsArg<K>, value: impl AsArg<V>) -> Option<V> {
+        meta::arg_into_ref!(key: K);
+        meta::arg_into_ref!(value: V);
+
+        self.inner
+            .insert(key.to_variant(), value.to_variant())
+            .map(|old| V::from_variant(&old))
+    }
+
+    /// Removes a key from the map, and returns the value associated with the key if the key was in the dictionary.
+    ///
+    /// _Godot equivalent: `erase`_
+    #[doc(alias = "erase")]
+    pub fn remove(&mut self, key: impl AsArg<K>) -> Option<V> {
+        meta::arg_into_ref!(key: K);
+
+        self.inner
+            .remove(key.to_variant())
+            .map(|old| V::from_variant(&old))
+    }
+
+    /// Creates a new typed `Array` containing all the keys currently in the dictionary.
+    ///
+    /// _Godot equivalent: `keys`_
+    #[doc(alias = "keys")]
+    pub fn keys_array(&self) -> Array<K> {
+        self.inner.keys_shared().typed::<K>().collect()
+    }
+
+    /// Creates a new typed `Array` containing all the values currently in the dictionary.
+    ///
+    /// _Godot equivalent: `values`_
+    #[doc(alias = "values")]
+    pub fn values_array(&self) -> Array<V> {
+        self.iter_shared().map(|(_key, value)| value).collect()
+    }
+
+    /// Copies all keys and values from `other` into `self`.
+    ///
+    /// If `overwrite` is true, it will overwrite pre-existing keys.
+    ///
+    /// _Godot equivalent: `merge`_
+    #[doc(alias = "merge")]
+    pub fn extend_dict(&mut self, other: &Self, overwrite: bool) {
+        self.inner.extend_dictionary(&other.inner, overwrite)
+    }
+
+    /// Deep copy, duplicating nested collections.
+    ///
+    /// See [`Dictionary::duplicate_deep()`] for details.
+    ///
+    /// _Godot equivalent: `dict.duplicate(true)`_
+    pub fn duplicate_deep(&self) -> Self {
+        // Godot preserves the key/value types on duplication.
+        Self::from_untyped_unchecked(self.inner.duplicate_deep())
+    }
+
+    /// Shallow copy, copying elements but sharing nested collections.
+    ///
+    /// See [`Dictionary::duplicate_shallow()`] for details.
+    ///
+    /// _Godot equivalent: `dict.duplicate(false)`_
+    pub fn duplicate_shallow(&self) -> Self {
+        Self::from_untyped_unchecked(self.inner.duplicate_shallow())
+    }
+
+    /// Returns an iterator over the `(K, V)` key-value pairs of the dictionary.
+    ///
+    /// Each pair is a (cheap, shallow) copy of the stored key and value. See [`Dictionary::iter_shared()`] for the semantics of
+    /// concurrent modification.
+    pub fn iter_shared(&self) -> DictTypedIter<'_, K, V> {
+        self.inner.iter_shared().typed::<K, V>()
+    }
+
+    /// Returns an iterator over the `K` keys of the dictionary.
+    ///
+    /// See [`Dictionary::keys_shared()`] for the semantics of concurrent modification.
+    pub fn keys_shared(&self) -> DictTypedKeys<'_, K> {
+        self.inner.keys_shared().typed::<K>()
+    }
+
+    /// Turns the dictionary into a shallow-immutable dictionary.
+    ///
+    /// See [`Dictionary::into_read_only()`] for details.
+    ///
+    /// _Godot equivalent: `make_read_only`_
+    #[doc(alias = "make_read_only")]
+    pub fn into_read_only(self) -> Self {
+        Self::from_untyped_unchecked(self.inner.into_read_only())
+    }
+
+    /// Returns true if the dictionary is read-only.
+    ///
+    /// See [`into_read_only()`][Self::into_read_only].
+    pub fn is_read_only(&self) -> bool {
+        self.inner.is_read_only()
+    }
+
+    /// Returns the runtime element type information for keys in this dictionary.
+    ///
+    /// For dictionaries created in Rust, this corresponds to `ElementType::of::<K>()`. Dictionaries received from Godot may additionally
+    /// carry script information, see [`ElementType::ScriptClass`].
+    #[cfg(since_api = "4.4")]
+    pub fn key_element_type(&self) -> ElementType {
+        self.inner.key_element_type()
+    }
+
+    /// Returns the runtime element type information for values in this dictionary.
+    ///
+    /// For dictionaries created in Rust, this corresponds to `ElementType::of::<V>()`. Dictionaries received from Godot may additionally
+    /// carry script information, see [`ElementType::ScriptClass`].
+    #[cfg(since_api = "4.4")]
+    pub fn value_element_type(&self) -> ElementType {
+        self.inner.value_element_type()
+    }
+
+    /// Returns a reference to the untyped dictionary.
+    ///
+    /// Useful to pass the dictionary to APIs expecting `&Dictionary`. Note that writing through a clone of this reference bypasses the
+    /// static typing: before Godot 4.4, nothing prevents values of other types from being inserted.
+    pub fn as_untyped(&self) -> &Dictionary {
+        &self.inner
+    }
+
+    /// Converts into the untyped dictionary, keeping the runtime type information intact.
+    pub fn into_untyped(self) -> Dictionary {
+        self.inner
+    }
+
+    /// Tries to interpret an untyped dictionary as `Dict<K, V>`.
+    ///
+    /// Returns an error if the runtime key/value types (Godot 4.4+) or the stored entries (before 4.4) do not match `K` and `V`.
+    pub fn try_from_untyped(dictionary: Dictionary) -> Result<Self, ConvertError> {
+        Self::from_untyped_unchecked(dictionary).with_checked_types()
+    }
+
+    /// Checks that the runtime key/value types of the inner dictionary match `K` and `V`.
+    #[cfg(since_api = "4.4")]
+    fn with_checked_types(self) -> Result<Self, ConvertError> {
+        let actual_key = self.inner.key_element_type();
+        let actual_value = self.inner.value_element_type();
+        let expected_key = ElementType::of::<K>();
+        let expected_value = ElementType::of::<V>();
+
+        if element_type_matches(&actual_key, &expected_key)
+            && element_type_matches(&actual_value, &expected_value)
+        {
+            return Ok(self);
+        }
+
+        Err(meta::error::FromGodotError::BadDictionaryType {
+            expected_key,
+            expected_value,
+            actual_key,
+            actual_value,
+        }
+        .into_error(self.inner))
+    }
+
+    /// Without native typed dictionaries, every entry is validated instead.
+    #[cfg(before_api = "4.4")]
+    fn with_checked_types(self) -> Result<Self, ConvertError> {
+        for (key, value) in self.inner.iter_shared() {
+            K::try_from_variant(&key)?;
+            V::try_from_variant(&value)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Whether both keys and values are `Variant`, i.e. the dictionary is untyped (compile-time check).
+    fn has_variant_kv() -> bool {
+        !ElementType::of::<K>().is_typed() && !ElementType::of::<V>().is_typed()
+    }
+}
+
+/// Exact match, or script class (runtime) matching its native base class (compile-time).
+///
+/// Mirrors the check for `Array<T>`, so that a `Dictionary[String, Enemy]` from GDScript can be used as `Dict<GString, Gd<RefCounted>>`.
+#[cfg(since_api = "4.4")]
+fn element_type_matches(actual: &ElementType, expected: &ElementType) -> bool {
+    if actual == expected {
+        return true;
+    }
+
+    if let (ElementType::ScriptClass(_), ElementType::Class(expected_class)) = (actual, expected) {
+        return actual.class_id() == Some(*expected_class);
+    }
+
+    false
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Traits
+
+impl<K: ArrayElement, V: ArrayElement> Default for Dict<K, V> {
+    fn default() -> Self {
+        #[allow(unused_mut)]
+        let mut dict = Self::from_untyped_unchecked(Dictionary::new());
+
+        // SAFETY: We just created this dictionary, and haven't called `init_inner_types` before.
+        #[cfg(since_api = "4.4")]
+        unsafe {
+            dict.inner
+                .init_inner_types(ElementType::of::<K>(), ElementType::of::<V>())
+        };
+
+        dict
+    }
+}
+
+/// Creates a new reference to the data in this dictionary. Changes to the original dictionary will be
+/// reflected in the copy and vice versa.
+///
+/// To create a (mostly) independent copy instead, see [`Dict::duplicate_shallow()`] and [`Dict::duplicate_deep()`].
+impl<K: ArrayElement, V: ArrayElement> Clone for Dict<K, V> {
+    fn clone(&self) -> Self {
+        Self::from_untyped_unchecked(self.inner.clone())
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> PartialEq for Dict<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> fmt::Debug for Dict<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl<K, V> fmt::Display for Dict<K, V>
+where
+    K: ArrayElement + fmt::Display,
+    V: ArrayElement + fmt::Display,
+{
+    /// Formats `Dict` to match Godot's string representation.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{ ")?;
+        for (count, (key, value)) in self.iter_shared().enumerate() {
+            if count != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{key}: {value}")?;
+        }
+        write!(f, " }}")
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> GodotConvert for Dict<K, V> {
+    type Via = Self;
+}
+
+impl<K: ArrayElement, V: ArrayElement> ToGodot for Dict<K, V> {
+    type Pass = meta::ByRef;
+
+    fn to_godot(&self) -> &Self::Via {
+        self
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> FromGodot for Dict<K, V> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        // Types are already checked in GodotType::try_from_ffi().
+        Ok(via)
+    }
+}
+
+// The FFI representation is the untyped `Dictionary`: both share the same memory layout and variant type.
+impl<K: ArrayElement, V: ArrayElement> GodotType for Dict<K, V> {
+    type Ffi = Dictionary;
+
+    type ToFfi<'f>
+        = RefArg<'f, Dictionary>
+    where
+        Self: 'f;
+
+    fn to_ffi(&self) -> Self::ToFfi<'_> {
+        RefArg::new(&self.inner)
+    }
+
+    fn into_ffi(self) -> Self::Ffi {
+        self.inner
+    }
+
+    fn try_from_ffi(ffi: Self::Ffi) -> Result<Self, ConvertError> {
+        Self::try_from_untyped(ffi)
+    }
+
+    fn godot_type_name() -> String {
+        "Dictionary".to_string()
+    }
+
+    fn property_hint_info() -> PropertyHintInfo {
+        // Dict<Variant, Variant>, aka untyped dictionary, has no hints.
+        if Self::has_variant_kv() {
+            return PropertyHintInfo::none();
+        }
+
+        // Typed dictionaries use type hint "K;V".
+        #[cfg(since_api = "4.4")]
+        {
+            PropertyHintInfo {
+                hint: crate::global::PropertyHint::DICTIONARY_TYPE,
+                hint_string: GString::from(&format!(
+                    "{};{}",
+                    meta::element_godot_type_name::<K>(),
+                    meta::element_godot_type_name::<V>()
+                )),
+            }
+        }
+
+        #[cfg(before_api = "4.4")]
+        {
+            PropertyHintInfo::none()
+        }
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> Var for Dict<K, V> {
+    fn get_property(&self) -> Self::Via {
+        self.clone()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        *self = value;
+    }
+}
+
+impl<K, V> Export for Dict<K, V>
+where
+    K: ArrayElement + Export,
+    V: ArrayElement + Export,
+{
+    fn export_hint() -> PropertyHintInfo {
+        // If K == V == Variant, or typed dictionaries are not supported, then we return "Dictionary" builtin type hint.
+        if Self::has_variant_kv() || sys::GdextBuild::before_api("4.4") {
+            return PropertyHintInfo::type_name::<Dictionary>();
+        }
+
+        // Same hint as the registered property, so that the editor and `#[var]` agree on the key/value types.
+        <Self as meta::GodotType>::property_hint_info()
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> BuiltinExport for Dict<K, V> {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversion traits
+
+/// Insert iterator range into dictionary.
+///
+/// Inserts all key-value pairs from the iterator into the dictionary. Previous values for keys appearing
+/// in `iter` will be overwritten.
+impl<K: ArrayElement, V: ArrayElement> Extend<(K, V)> for Dict<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter.into_iter() {
+            self.inner.set(key.to_variant(), value.to_variant());
+        }
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> FromIterator<(K, V)> for Dict<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut dict = Self::new();
+        dict.extend(iter);
+        dict
+    }
+}
+
+/// Typed view of an untyped dictionary; fails if the runtime types don't match.
+impl<K: ArrayElement, V: ArrayElement> TryFrom<Dictionary> for Dict<K, V> {
+    type Error = ConvertError;
+
+    fn try_from(dictionary: Dictionary) -> Result<Self, Self::Error> {
+        Self::try_from_untyped(dictionary)
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> From<Dict<K, V>> for Dictionary {
+    fn from(dict: Dict<K, V>) -> Self {
+        dict.into_untyped()
+    }
+}
@@ -174,6 +174,22 @@ impl<T: ArrayElement> IntoDynamicSend for Array<T> {
     }
 }
 
+impl<K: ArrayElement, V: ArrayElement> Sealed for ThreadConfined<Dict<K, V>> {}
+
+unsafe impl<K: ArrayElement, V: ArrayElement> DynamicSend for ThreadConfined<Dict<K, V>> {
+    type Inner = Dict<K, V>;
+    fn extract_if_safe(self) -> Option<Self::Inner> {
+        self.extract()
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> IntoDynamicSend for Dict<K, V> {
+    type Target = ThreadConfined<Dict<K, V>>;
+    fn into_dynamic_send(self) -> Self::Target {
+        ThreadConfined::new(self)
+    }
+}
+
 impl_dynamic_send!(
     Send;
     bool, u8, u16, u32, u64, i8, i16, i32, i64, f32, f64
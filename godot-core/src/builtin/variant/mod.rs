@@ -662,3 +662,133 @@ fn can_convert_godot_strict(from_type: VariantType, to_type: VariantType) -> boo
         can_convert_fn(from_type.sys(), to_type.sys()) == sys::conv::SYS_TRUE
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use serde::ser::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::builtin::*;
+    use crate::meta::ToGodot;
+
+    /// Generates `VariantRepr`, a tagged mirror of `Variant` with one enumerator per supported variant type.
+    ///
+    /// Types listed here are converted 1:1; `Nil`, `Rid` and `Array` need special handling and are declared separately.
+    macro_rules! declare_variant_repr {
+        ($( $(#[$attr:meta])* $Name:ident($Ty:ty) = $VARIANT_TYPE:ident, )*) => {
+            #[derive(Serialize, Deserialize)]
+            enum VariantRepr {
+                Nil,
+                /// Opaque ID, only meaningful for the server that created it and the same engine session.
+                Rid(u64),
+                /// Typed arrays are serialized with their elements, but lose their element type.
+                Array(VariantArray),
+                $(
+                    $(#[$attr])*
+                    $Name($Ty),
+                )*
+            }
+
+            impl VariantRepr {
+                fn try_from_variant(variant: &Variant) -> Result<Self, String> {
+                    let repr = match variant.get_type() {
+                        VariantType::NIL => Self::Nil,
+                        VariantType::RID => Self::Rid(variant.to::<Rid>().to_u64()),
+                        VariantType::ARRAY => {
+                            // SAFETY: type is checked. The array is only read, and its elements are serialized as `Variant`.
+                            Self::Array(unsafe { VariantArray::from_variant_unchecked(variant) })
+                        }
+                        $(
+                            $(#[$attr])*
+                            VariantType::$VARIANT_TYPE => Self::$Name(variant.to::<$Ty>()),
+                        )*
+                        ty @ (VariantType::OBJECT | VariantType::CALLABLE | VariantType::SIGNAL) => {
+                            return Err(format!(
+                                "cannot serialize Variant of type {ty:?}: values referring to objects are not serializable"
+                            ))
+                        }
+                        ty => return Err(format!("cannot serialize Variant of type {ty:?}")),
+                    };
+
+                    Ok(repr)
+                }
+
+                fn into_variant(self) -> Variant {
+                    match self {
+                        Self::Nil => Variant::nil(),
+                        Self::Rid(id) => Rid::new(id).to_variant(),
+                        Self::Array(array) => array.to_variant(),
+                        $(
+                            $(#[$attr])*
+                            Self::$Name(value) => value.to_variant(),
+                        )*
+                    }
+                }
+            }
+        };
+    }
+
+    declare_variant_repr!(
+        Bool(bool) = BOOL,
+        Int(i64) = INT,
+        Float(f64) = FLOAT,
+        String(GString) = STRING,
+        Vector2(Vector2) = VECTOR2,
+        Vector2i(Vector2i) = VECTOR2I,
+        Rect2(Rect2) = RECT2,
+        Rect2i(Rect2i) = RECT2I,
+        Vector3(Vector3) = VECTOR3,
+        Vector3i(Vector3i) = VECTOR3I,
+        Transform2D(Transform2D) = TRANSFORM2D,
+        Vector4(Vector4) = VECTOR4,
+        Vector4i(Vector4i) = VECTOR4I,
+        Plane(Plane) = PLANE,
+        Quaternion(Quaternion) = QUATERNION,
+        Aabb(Aabb) = AABB,
+        Basis(Basis) = BASIS,
+        Transform3D(Transform3D) = TRANSFORM3D,
+        Projection(Projection) = PROJECTION,
+        Color(Color) = COLOR,
+        StringName(StringName) = STRING_NAME,
+        NodePath(NodePath) = NODE_PATH,
+        Dictionary(Dictionary) = DICTIONARY,
+        PackedByteArray(PackedByteArray) = PACKED_BYTE_ARRAY,
+        PackedInt32Array(PackedInt32Array) = PACKED_INT32_ARRAY,
+        PackedInt64Array(PackedInt64Array) = PACKED_INT64_ARRAY,
+        PackedFloat32Array(PackedFloat32Array) = PACKED_FLOAT32_ARRAY,
+        PackedFloat64Array(PackedFloat64Array) = PACKED_FLOAT64_ARRAY,
+        PackedStringArray(PackedStringArray) = PACKED_STRING_ARRAY,
+        PackedVector2Array(PackedVector2Array) = PACKED_VECTOR2_ARRAY,
+        PackedVector3Array(PackedVector3Array) = PACKED_VECTOR3_ARRAY,
+        #[cfg(since_api = "4.3")]
+        PackedVector4Array(PackedVector4Array) = PACKED_VECTOR4_ARRAY,
+        PackedColorArray(PackedColorArray) = PACKED_COLOR_ARRAY,
+    );
+
+    /// Serializes a `Variant` as a tagged value, e.g. `{"Int": 5}` or `{"Vector2": {"x": 1.0, "y": 2.0}}` in JSON.
+    ///
+    /// Objects, callables and signals cannot be serialized and result in an error. `Rid` is stored as its opaque integer ID.
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl Serialize for Variant {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            VariantRepr::try_from_variant(self)
+                .map_err(S::Error::custom)?
+                .serialize(serializer)
+        }
+    }
+
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<'de> Deserialize<'de> for Variant {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            VariantRepr::deserialize(deserializer).map(VariantRepr::into_variant)
+        }
+    }
+}
@@ -191,6 +191,9 @@ where
     obj.upcast_object_mut()
         .notify(crate::classes::notify::ObjectNotification::POSTINITIALIZE);
 
+    #[cfg(safeguards_strict)]
+    crate::obj::tracking::track_created(&obj);
+
     obj
 }
 
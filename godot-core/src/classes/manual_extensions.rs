@@ -12,8 +12,10 @@
 //!
 //! See also sister module [super::type_safe_replacements].
 
-use crate::builtin::NodePath;
-use crate::classes::{Node, PackedScene};
+use std::collections::VecDeque;
+
+use crate::builtin::{GString, NodePath, StringName};
+use crate::classes::{Node, PackedScene, SceneTree};
 use crate::meta::{arg_into_ref, AsArg};
 use crate::obj::{Gd, Inherits};
 
@@ -51,6 +53,124 @@ impl Node {
         self.get_node_or_null(path)
             .and_then(|node| node.try_cast::<T>().ok())
     }
+
+    /// Iterates over the direct children of type `T` (or inherited), skipping all others.
+    ///
+    /// Children are accessed by index, so no array is allocated. The child count is determined when the iterator is created; children
+    /// added or removed during iteration may be skipped or visited twice. Internal children are not included.
+    pub fn children_of<T>(&self) -> impl Iterator<Item = Gd<T>> + '_
+    where
+        T: Inherits<Node>,
+    {
+        (0..self.get_child_count())
+            .filter_map(|index| self.get_child(index))
+            .filter_map(|child| child.try_cast::<T>().ok())
+    }
+
+    /// Iterates over all descendants of type `T` (or inherited) in depth-first pre-order, skipping all others.
+    ///
+    /// This is the order of [`find_children()`][Self::find_children] and of the editor's scene dock. Nodes of other types are skipped, but
+    /// their children are still visited. Internal children are not included.
+    ///
+    /// The children of each node are fetched when that node is reached, so changes to not-yet-visited parts of the tree are reflected.
+    pub fn descendants_of<T>(&self) -> impl Iterator<Item = Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        let mut stack = Vec::new();
+        push_children_reversed(&mut stack, self);
+
+        std::iter::from_fn(move || {
+            while let Some(node) = stack.pop() {
+                push_children_reversed(&mut stack, &node);
+                if let Ok(node) = node.try_cast::<T>() {
+                    return Some(node);
+                }
+            }
+            None
+        })
+    }
+
+    /// Iterates over all descendants of type `T` (or inherited) in breadth-first order, skipping all others.
+    ///
+    /// All children are visited before grandchildren, and so on. Otherwise, this behaves like [`descendants_of()`][Self::descendants_of].
+    pub fn descendants_breadth_first_of<T>(&self) -> impl Iterator<Item = Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        let mut queue = VecDeque::new();
+        queue.extend((0..self.get_child_count()).filter_map(|index| self.get_child(index)));
+
+        std::iter::from_fn(move || {
+            while let Some(node) = queue.pop_front() {
+                queue.extend((0..node.get_child_count()).filter_map(|index| node.get_child(index)));
+                if let Ok(node) = node.try_cast::<T>() {
+                    return Some(node);
+                }
+            }
+            None
+        })
+    }
+
+    /// Returns the nearest ancestor of type `T` (or inherited), or `None` if there is none.
+    ///
+    /// The node itself is not considered, only its parent, the parent's parent, and so on.
+    pub fn ancestor_of<T>(&self) -> Option<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        let mut current = self.get_parent();
+        while let Some(node) = current {
+            match node.try_cast::<T>() {
+                Ok(ancestor) => return Some(ancestor),
+                Err(node) => current = node.get_parent(),
+            }
+        }
+        None
+    }
+
+    /// Returns the first descendant of type `T` (or inherited) whose name matches `pattern`, in depth-first order.
+    ///
+    /// `pattern` may contain wildcards: `*` matches zero or more arbitrary characters and `?` matches any single character. Matching is
+    /// case-sensitive.
+    ///
+    /// Unlike [`find_child()`][Self::find_child] with default arguments, this also considers nodes without an owner, such as nodes
+    /// created in code.
+    pub fn find_child_as<T>(&self, pattern: impl AsArg<GString>) -> Option<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        arg_into_ref!(pattern);
+
+        self.descendants_of::<T>()
+            .find(|node| node.upcast_ref::<Node>().get_name().match_glob(pattern))
+    }
+}
+
+/// Pushes children of `node` onto `stack`, so that the first child is popped first.
+fn push_children_reversed(stack: &mut Vec<Gd<Node>>, node: &Node) {
+    let count = node.get_child_count();
+    stack.extend((0..count).rev().filter_map(|index| node.get_child(index)));
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `SceneTree` class.
+impl SceneTree {
+    /// Returns all nodes in group `group` that have type `T` (or inherited), in scene tree order.
+    ///
+    /// Nodes of other types are skipped.
+    pub fn nodes_in_group_as<T>(&mut self, group: impl AsArg<StringName>) -> Vec<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        arg_into_ref!(group);
+
+        self.get_nodes_in_group(group)
+            .iter_shared()
+            .filter_map(|node| node.try_cast::<T>().ok())
+            .collect()
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
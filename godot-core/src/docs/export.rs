@@ -0,0 +1,221 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashSet;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::gather_class_xml_docs;
+use super::render::{render_index, ClassPage, Markup};
+use crate::builtin::Callable;
+use crate::classes::{Engine, Os, SceneTree};
+use crate::obj::Singleton;
+
+/// Command-line user argument (after `--`) that exports the documentation of all registered classes, and then quits Godot.
+///
+/// Followed by the output directory, and optionally one or more formats (`xml`, `md`, `html`; default `xml`):
+/// ```text
+/// godot --headless --path my-project -- --godot-rust-export-docs docs/api md html
+/// ```
+pub const EXPORT_DOCS_ARG: &str = "--godot-rust-export-docs";
+
+/// Output format of [`export_docs()`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DocsFormat {
+    /// Godot class reference XML, the same format as registered with the editor (and as found in Godot's `doc/classes` directory).
+    Xml,
+
+    /// One Markdown page per class, with BBCode converted to Markdown.
+    Markdown,
+
+    /// One standalone HTML page per class, without styling.
+    Html,
+}
+
+impl DocsFormat {
+    /// File extension, without leading dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Xml => "xml",
+            Self::Markdown => "md",
+            Self::Html => "html",
+        }
+    }
+
+    /// Parses a format from its name or file extension, e.g. `"markdown"` or `"md"`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "xml" => Some(Self::Xml),
+            "md" | "markdown" => Some(Self::Markdown),
+            "html" | "htm" => Some(Self::Html),
+            _ => None,
+        }
+    }
+}
+
+/// Generated documentation file, as returned by [`class_docs()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DocsFile {
+    /// File name relative to the output directory, e.g. `Player.md`.
+    pub file_name: String,
+
+    /// Content of the file.
+    pub content: String,
+}
+
+/// Documentation for all classes registered with `#[derive(GodotClass)]` in this extension, in the given format.
+///
+/// Returns one file per class, sorted by class name. Markdown and HTML additionally include an `index` page listing all classes; links
+/// between pages are relative, so the files can be published as-is.
+///
+/// This does not require a running engine, but must be called after the extension library has been loaded.
+///
+/// # Panics
+/// If a class reference XML document is malformed. This indicates a bug in godot-rust.
+pub fn class_docs(format: DocsFormat) -> Vec<DocsFile> {
+    let mut xml_docs = gather_class_xml_docs()
+        .map(|(class, xml)| (class.to_string(), xml))
+        .collect::<Vec<_>>();
+    xml_docs.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let markup = match format {
+        DocsFormat::Xml => {
+            return xml_docs
+                .into_iter()
+                .map(|(class, xml)| DocsFile {
+                    file_name: format!("{class}.xml"),
+                    content: xml,
+                })
+                .collect();
+        }
+        DocsFormat::Markdown => Markup::Markdown,
+        DocsFormat::Html => Markup::Html,
+    };
+
+    let pages = xml_docs
+        .iter()
+        .map(|(class, xml)| {
+            ClassPage::from_xml(xml)
+                .unwrap_or_else(|e| panic!("malformed docs XML for class `{class}`: {e}"))
+        })
+        .collect::<Vec<_>>();
+
+    let known_classes = pages
+        .iter()
+        .map(|page| page.name.clone())
+        .collect::<HashSet<_>>();
+
+    let extension = format.extension();
+    let mut files = pages
+        .iter()
+        .map(|page| DocsFile {
+            file_name: format!("{}.{extension}", page.name),
+            content: page.render(markup, &known_classes),
+        })
+        .collect::<Vec<_>>();
+
+    files.push(DocsFile {
+        file_name: format!("index.{extension}"),
+        content: render_index(&pages, markup),
+    });
+
+    files
+}
+
+/// Writes the documentation returned by [`class_docs()`] to files in `dir`, which is created if necessary.
+///
+/// Existing files with the same names are overwritten; other files are left untouched. Returns the paths of all written files.
+///
+/// To export docs from the command line instead, see [`EXPORT_DOCS_ARG`].
+pub fn export_docs(dir: impl AsRef<Path>, format: DocsFormat) -> io::Result<Vec<PathBuf>> {
+    let dir = dir.as_ref();
+    std::fs::create_dir_all(dir)?;
+
+    class_docs(format)
+        .into_iter()
+        .map(|file| {
+            let path = dir.join(&file.file_name);
+            std::fs::write(&path, file.content)?;
+            Ok(path)
+        })
+        .collect()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Command line
+
+/// Called when the `Scene` init level is loaded. Exports docs and quits if requested via [`EXPORT_DOCS_ARG`].
+pub(crate) fn on_scene_init() {
+    let args = Os::singleton().get_cmdline_user_args();
+    let args: Vec<String> = args.as_slice().iter().map(|arg| arg.to_string()).collect();
+
+    let Some(pos) = args.iter().position(|arg| arg == EXPORT_DOCS_ARG) else {
+        return;
+    };
+
+    let success = match parse_export_args(&args[pos + 1..]) {
+        Ok((dir, formats)) => export_all(&dir, &formats),
+        Err(message) => {
+            crate::godot_error!("{EXPORT_DOCS_ARG}: {message}");
+            false
+        }
+    };
+
+    // The scene tree does not exist yet during init; deferred calls are flushed once the main loop runs.
+    let exit_code = if success { 0 } else { 1 };
+    let callable = Callable::from_fn("godot_rust_docs_quit", move |_| {
+        if let Some(mut tree) = Engine::singleton()
+            .get_main_loop()
+            .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+        {
+            tree.quit_ex().exit_code(exit_code).done();
+        }
+    });
+    callable.call_deferred(&[]);
+}
+
+fn parse_export_args(args: &[String]) -> Result<(PathBuf, Vec<DocsFormat>), String> {
+    let mut args = args.iter().take_while(|arg| !arg.starts_with("--"));
+
+    let dir = args
+        .next()
+        .ok_or_else(|| "missing output directory".to_string())?;
+
+    let mut formats = args
+        .map(|name| {
+            DocsFormat::from_name(name)
+                .ok_or_else(|| format!("unknown format `{name}`; expected xml, md or html"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if formats.is_empty() {
+        formats.push(DocsFormat::Xml);
+    }
+
+    Ok((PathBuf::from(dir), formats))
+}
+
+fn export_all(dir: &Path, formats: &[DocsFormat]) -> bool {
+    for &format in formats {
+        match export_docs(dir, format) {
+            Ok(paths) => crate::godot_print!(
+                "{EXPORT_DOCS_ARG}: wrote {} {format:?} files to {}",
+                paths.len(),
+                dir.display()
+            ),
+            Err(e) => {
+                crate::godot_error!(
+                    "{EXPORT_DOCS_ARG}: failed to write to {}: {e}",
+                    dir.display()
+                );
+                return false;
+            }
+        }
+    }
+
+    true
+}
@@ -5,8 +5,20 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+//! Documentation of registered classes, extracted from Rust doc comments by the `register-docs` feature.
+//!
+//! Besides being shown in the editor, the documentation can be exported as Godot class reference XML, Markdown or HTML; see
+//! [`export_docs()`].
+
+mod export;
+mod render;
+mod xml_reader;
+
 use std::collections::HashMap;
 
+pub(crate) use export::on_scene_init;
+pub use export::{class_docs, export_docs, DocsFile, DocsFormat, EXPORT_DOCS_ARG};
+
 use crate::meta::ClassId;
 use crate::obj::GodotClass;
 
@@ -42,6 +54,8 @@ pub enum DocsItem {
     InherentImpl(InherentImplDocs),
     /// Docs for `#[godot_api] impl ITrait for MyClass`.
     ITraitImpl(ITraitImplDocs),
+    /// Docs for `#[constant(enum)] type Name = MyEnum;` inside `#[godot_api] impl MyClass`.
+    ConstantEnum(ConstantEnumDocs),
 }
 
 /// Created for documentation on
@@ -61,6 +75,8 @@ pub struct StructDocs {
     pub experimental: &'static str,
     pub deprecated: &'static str,
     pub properties: &'static str,
+    /// `<link>` elements from `@tutorial` lines; empty if there are none.
+    pub tutorials: &'static str,
 }
 
 /// Keeps documentation for inherent `impl` blocks (primary and secondary), such as:
@@ -87,6 +103,71 @@ pub struct InherentImplDocs {
     pub constants_xml: &'static str,
 }
 
+/// Created for documentation of a Rust enum registered as enum or bitfield of a class:
+/// ```ignore
+/// #[derive(GodotConvert)]
+/// #[godot(via = i64)]
+/// enum Material {
+///     /// documented
+///     Wood,
+/// }
+///
+/// #[godot_api]
+/// impl Struct {
+///     #[constant(enum)]
+///     type Material = Material;
+/// }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct ConstantEnumDocs {
+    /// Name of the enum or bitfield in the class.
+    pub name: &'static str,
+    pub is_bitfield: bool,
+    pub enumerators: &'static [EnumeratorDocs],
+}
+
+/// Documentation of a single enumerator, extracted by `#[derive(GodotConvert)]`.
+///
+/// Name and XML parts are escaped where necessary.
+#[derive(Copy, Clone, Debug)]
+pub struct EnumeratorDocs {
+    pub name: &'static str,
+    pub value: i64,
+    /// ` deprecated="..."` and ` experimental="..."` XML attributes, or empty.
+    pub attributes: &'static str,
+    pub description: &'static str,
+}
+
+/// Documentation of the enumerators of an enum deriving `GodotConvert`, used for `#[constant(enum)]` and `#[constant(bitfield)]`.
+///
+/// Implemented by `#[derive(GodotConvert)]`. Only documented enumerators are listed, like for `#[constant]`.
+pub trait ConstantEnumDocsProvider {
+    const ENUMERATOR_DOCS: &'static [EnumeratorDocs];
+}
+
+impl ConstantEnumDocs {
+    fn to_xml(self) -> String {
+        let group_attrs = if self.is_bitfield {
+            format!(r#" enum="{}" is_bitfield="true""#, self.name)
+        } else {
+            format!(r#" enum="{}""#, self.name)
+        };
+
+        self.enumerators
+            .iter()
+            .map(|e| {
+                format!(
+                    r#"<constant name="{name}" value="{value}"{group_attrs}{attributes}>{description}</constant>"#,
+                    name = e.name,
+                    value = e.value,
+                    attributes = e.attributes,
+                    description = e.description,
+                )
+            })
+            .collect()
+    }
+}
+
 /// Godot editor documentation for a class, combined from individual definitions (struct + impls).
 ///
 /// All fields are collections of XML parts, escaped where necessary.
@@ -95,7 +176,7 @@ struct AggregatedDocs {
     definition: StructDocs,
     methods_xmls: Vec<&'static str>,
     signals_xmls: Vec<&'static str>,
-    constants_xmls: Vec<&'static str>,
+    constants_xmls: Vec<String>,
 }
 
 /// This function scours the registered plugins to find their documentation pieces,
@@ -113,6 +194,11 @@ struct AggregatedDocs {
 /// strings of not-yet-parented XML tags (or empty string if no method has been documented).
 #[doc(hidden)]
 pub fn gather_xml_docs() -> impl Iterator<Item = String> {
+    gather_class_xml_docs().map(|(_class, xml)| xml)
+}
+
+/// Like [`gather_xml_docs()`], but also returns the class of each XML document.
+fn gather_class_xml_docs() -> impl Iterator<Item = (ClassId, String)> {
     let mut map = HashMap::<ClassId, AggregatedDocs>::new();
 
     crate::private::iterate_docs_plugins(|shard| {
@@ -128,8 +214,11 @@ pub fn gather_xml_docs() -> impl Iterator<Item = String> {
                     .methods_xmls
                     .push(trait_docs.methods_xml);
 
-                map.entry(class_name)
-                    .and_modify(|pieces| pieces.constants_xmls.push(trait_docs.constants_xml));
+                map.entry(class_name).and_modify(|pieces| {
+                    pieces
+                        .constants_xmls
+                        .push(trait_docs.constants_xml.to_string())
+                });
 
                 map.entry(class_name)
                     .and_modify(|pieces| pieces.signals_xmls.push(trait_docs.signals_xml));
@@ -141,6 +230,13 @@ pub fn gather_xml_docs() -> impl Iterator<Item = String> {
                     .methods_xmls
                     .push(methods_xml);
             }
+
+            DocsItem::ConstantEnum(enum_docs) => {
+                map.entry(class_name)
+                    .or_default()
+                    .constants_xmls
+                    .push(enum_docs.to_xml());
+            }
         }
     });
 
@@ -151,8 +247,15 @@ pub fn gather_xml_docs() -> impl Iterator<Item = String> {
             experimental,
             deprecated,
             properties,
+            tutorials,
         } = pieces.definition;
 
+        let tutorials_block = if tutorials.is_empty() {
+            String::new()
+        } else {
+            format!("<tutorials>{tutorials}</tutorials>\n")
+        };
+
         let methods_block = wrap_in_xml_block("methods", pieces.methods_xmls);
         let signals_block = wrap_in_xml_block("signals", pieces.signals_xmls);
         let constants_block = wrap_in_xml_block("constants", pieces.constants_xmls);
@@ -162,24 +265,26 @@ pub fn gather_xml_docs() -> impl Iterator<Item = String> {
             None => (description, ""),
         };
 
-        format!(r#"<?xml version="1.0" encoding="UTF-8"?>
+        let xml = format!(r#"<?xml version="1.0" encoding="UTF-8"?>
 <class name="{class}" inherits="{base}"{deprecated}{experimental} xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:noNamespaceSchemaLocation="../class.xsd">
 <brief_description>{brief}</brief_description>
 <description>{description}</description>
-{methods_block}
+{tutorials_block}{methods_block}
 {constants_block}
 {signals_block}
 <members>{properties}</members>
-</class>"#)
+</class>"#);
+
+        (class, xml)
     })
 }
 
-fn wrap_in_xml_block(tag: &str, mut blocks: Vec<&'static str>) -> String {
+fn wrap_in_xml_block<S: AsRef<str> + Ord>(tag: &str, mut blocks: Vec<S>) -> String {
     // We sort the blocks for deterministic output. No need to sort individual methods/signals/constants, this is already done by Godot.
     // See https://github.com/godot-rust/gdext/pull/1391 for more information.
     blocks.sort();
 
-    let content = String::from_iter(blocks);
+    let content = blocks.iter().map(AsRef::as_ref).collect::<String>();
 
     if content.is_empty() {
         String::new()
@@ -0,0 +1,802 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Renders class reference XML as Markdown or HTML pages.
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+
+use super::xml_reader::{self, Element};
+
+/// Target markup of rendered pages.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(super) enum Markup {
+    Markdown,
+    Html,
+}
+
+/// Documentation of one class, as read back from its class reference XML. Text fields contain Godot BBCode.
+#[derive(Debug)]
+pub(super) struct ClassPage {
+    pub name: String,
+    inherits: String,
+    pub brief: String,
+    description: String,
+    status: Status,
+    /// `(title, url)` pairs; the title may be empty.
+    tutorials: Vec<(String, String)>,
+    properties: Vec<Entry>,
+    methods: Vec<Entry>,
+    signals: Vec<Entry>,
+    enums: Vec<EnumGroup>,
+    /// Constants not belonging to an enum.
+    constants: Vec<Entry>,
+}
+
+/// Constants sharing the same `enum` attribute.
+#[derive(Debug)]
+struct EnumGroup {
+    name: String,
+    is_bitfield: bool,
+    constants: Vec<Entry>,
+}
+
+impl EnumGroup {
+    fn signature(&self) -> String {
+        let keyword = if self.is_bitfield { "flags" } else { "enum" };
+        format!("{keyword} {}", self.name)
+    }
+}
+
+/// Documented property, method, signal or constant.
+#[derive(Debug)]
+struct Entry {
+    name: String,
+
+    /// Human-readable declaration, e.g. `heal(amount: i32) -> bool`.
+    signature: String,
+    description: String,
+    status: Status,
+}
+
+/// `@deprecated` and `@experimental` notes.
+#[derive(Debug, Default)]
+struct Status {
+    deprecated: Option<String>,
+    experimental: Option<String>,
+}
+
+impl Status {
+    fn from_element(element: &Element) -> Self {
+        Self {
+            deprecated: element.attr("deprecated").map(str::to_string),
+            experimental: element.attr("experimental").map(str::to_string),
+        }
+    }
+}
+
+impl ClassPage {
+    pub fn from_xml(xml: &str) -> Result<Self, String> {
+        let class = xml_reader::parse(xml)?;
+        if class.name != "class" {
+            return Err(format!(
+                "expected <class> root element, found <{}>",
+                class.name
+            ));
+        }
+
+        let section = |name: &str| -> Vec<&Element> {
+            class
+                .child(name)
+                .map(|block| block.elements().collect())
+                .unwrap_or_default()
+        };
+
+        let tutorials = class
+            .child("tutorials")
+            .map(|block| {
+                block
+                    .children_named("link")
+                    .map(|link| {
+                        (
+                            link.attr("title").unwrap_or_default().to_string(),
+                            link.text(),
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut enums: Vec<EnumGroup> = Vec::new();
+        let mut constants = Vec::new();
+        for element in section("constants") {
+            let entry = constant_entry(element);
+            let Some(enum_name) = element.attr("enum") else {
+                constants.push(entry);
+                continue;
+            };
+
+            match enums.iter_mut().find(|group| group.name == enum_name) {
+                Some(group) => group.constants.push(entry),
+                None => enums.push(EnumGroup {
+                    name: enum_name.to_string(),
+                    is_bitfield: element.attr("is_bitfield") == Some("true"),
+                    constants: vec![entry],
+                }),
+            }
+        }
+
+        Ok(Self {
+            name: class.attr("name").unwrap_or_default().to_string(),
+            inherits: class.attr("inherits").unwrap_or_default().to_string(),
+            brief: child_text(&class, "brief_description"),
+            description: child_text(&class, "description"),
+            status: Status::from_element(&class),
+            tutorials,
+            properties: section("members").into_iter().map(property_entry).collect(),
+            methods: section("methods")
+                .into_iter()
+                .map(|e| callable_entry(e, true))
+                .collect(),
+            signals: section("signals")
+                .into_iter()
+                .map(|e| callable_entry(e, false))
+                .collect(),
+            enums,
+            constants,
+        })
+    }
+
+    fn sections(&self) -> [(&'static str, &'static str, &[Entry]); 4] {
+        [
+            ("Properties", "property", &self.properties),
+            ("Methods", "method", &self.methods),
+            ("Signals", "signal", &self.signals),
+            ("Constants", "constant", &self.constants),
+        ]
+    }
+
+    /// Renders a standalone page. Classes in `known_classes` are linked to their own pages.
+    pub fn render(&self, markup: Markup, known_classes: &HashSet<String>) -> String {
+        let bb = |text: &str| bbcode_to_markup(text, markup, known_classes);
+        let class_ref = |name: &str| class_link(name, markup, known_classes);
+        let mut out = String::new();
+
+        match markup {
+            Markup::Markdown => {
+                let _ = write!(out, "# {}\n\n", self.name);
+                let _ = write!(out, "**Inherits:** {}\n\n", class_ref(&self.inherits));
+                write_status_markdown(&mut out, &self.status, &bb);
+
+                if !self.brief.is_empty() {
+                    let _ = write!(out, "{}\n\n", bb(&self.brief));
+                }
+                if !self.description.is_empty() {
+                    let _ = write!(out, "## Description\n\n{}\n\n", bb(&self.description));
+                }
+                if !self.tutorials.is_empty() {
+                    out.push_str("## Tutorials\n\n");
+                    for (title, url) in &self.tutorials {
+                        let title = if title.is_empty() { url } else { title };
+                        let _ = writeln!(out, "* [{title}]({url})");
+                    }
+                    out.push('\n');
+                }
+
+                for (title, kind, entries) in self.sections() {
+                    // Enums are listed right before the remaining constants.
+                    if kind == "constant" {
+                        self.write_enums_markdown(&mut out, &bb);
+                    }
+                    if entries.is_empty() {
+                        continue;
+                    }
+
+                    let _ = write!(out, "## {title}\n\n");
+                    write_entries_markdown(&mut out, entries, "###", &bb);
+                }
+            }
+
+            Markup::Html => {
+                let name = escape_html(&self.name);
+                let _ = write!(
+                    out,
+                    "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{name}</title>\n</head>\n<body>\n"
+                );
+                let _ = writeln!(out, "<h1>{name}</h1>");
+                let _ = writeln!(
+                    out,
+                    "<p><strong>Inherits:</strong> {}</p>",
+                    class_ref(&self.inherits)
+                );
+                write_status_html(&mut out, &self.status, &bb);
+
+                if !self.brief.is_empty() {
+                    let _ = writeln!(out, "<p>{}</p>", bb(&self.brief));
+                }
+                if !self.description.is_empty() {
+                    let _ = writeln!(
+                        out,
+                        "<h2 id=\"description\">Description</h2>\n<p>{}</p>",
+                        bb(&self.description)
+                    );
+                }
+                if !self.tutorials.is_empty() {
+                    out.push_str("<h2 id=\"tutorials\">Tutorials</h2>\n<ul>\n");
+                    for (title, url) in &self.tutorials {
+                        let title = if title.is_empty() { url } else { title };
+                        let _ = writeln!(
+                            out,
+                            "<li><a href=\"{}\">{}</a></li>",
+                            escape_html(url),
+                            escape_html(title)
+                        );
+                    }
+                    out.push_str("</ul>\n");
+                }
+
+                for (title, kind, entries) in self.sections() {
+                    if kind == "constant" {
+                        self.write_enums_html(&mut out, &bb);
+                    }
+                    if entries.is_empty() {
+                        continue;
+                    }
+
+                    let _ = writeln!(out, "<h2 id=\"{}\">{title}</h2>", title.to_lowercase());
+                    write_entries_html(&mut out, entries, kind, "h3", &bb);
+                }
+
+                out.push_str("</body>\n</html>\n");
+            }
+        }
+
+        out
+    }
+
+    fn write_enums_markdown(&self, out: &mut String, bb: &dyn Fn(&str) -> String) {
+        if self.enums.is_empty() {
+            return;
+        }
+
+        out.push_str("## Enumerations\n\n");
+        for group in &self.enums {
+            let _ = write!(out, "### `{}`\n\n", group.signature());
+            write_entries_markdown(out, &group.constants, "####", bb);
+        }
+    }
+
+    fn write_enums_html(&self, out: &mut String, bb: &dyn Fn(&str) -> String) {
+        if self.enums.is_empty() {
+            return;
+        }
+
+        out.push_str("<h2 id=\"enumerations\">Enumerations</h2>\n");
+        for group in &self.enums {
+            let _ = writeln!(
+                out,
+                "<h3 id=\"enum-{}\"><code>{}</code></h3>",
+                escape_html(&group.name),
+                escape_html(&group.signature())
+            );
+            write_entries_html(out, &group.constants, "constant", "h4", bb);
+        }
+    }
+}
+
+/// Renders an overview page linking to all classes, in the given order.
+pub(super) fn render_index(pages: &[ClassPage], markup: Markup) -> String {
+    let known_classes = pages.iter().map(|page| page.name.clone()).collect();
+    let mut out = String::new();
+
+    match markup {
+        Markup::Markdown => {
+            out.push_str("# Class reference\n\n");
+            for page in pages {
+                let _ = write!(out, "* {}", class_link(&page.name, markup, &known_classes));
+                if !page.brief.is_empty() {
+                    let brief = bbcode_to_markup(&page.brief, markup, &known_classes);
+                    let _ = write!(out, ": {brief}");
+                }
+                out.push('\n');
+            }
+        }
+
+        Markup::Html => {
+            out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>Class reference</title>\n</head>\n<body>\n");
+            out.push_str("<h1>Class reference</h1>\n<ul>\n");
+            for page in pages {
+                let _ = write!(
+                    out,
+                    "<li>{}",
+                    class_link(&page.name, markup, &known_classes)
+                );
+                if !page.brief.is_empty() {
+                    let brief = bbcode_to_markup(&page.brief, markup, &known_classes);
+                    let _ = write!(out, ": {brief}");
+                }
+                out.push_str("</li>\n");
+            }
+            out.push_str("</ul>\n</body>\n</html>\n");
+        }
+    }
+
+    out
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Reading entries
+
+fn child_text(element: &Element, name: &str) -> String {
+    element.child(name).map(Element::text).unwrap_or_default()
+}
+
+fn property_entry(member: &Element) -> Entry {
+    let name = member.attr("name").unwrap_or_default().to_string();
+    let ty = member.attr("type").unwrap_or_default();
+
+    let mut signature = format!("{name}: {ty}");
+    if let Some(default) = member.attr("default").filter(|d| !d.is_empty()) {
+        let _ = write!(signature, " = {default}");
+    }
+
+    Entry {
+        signature,
+        description: member.text(),
+        status: Status::from_element(member),
+        name,
+    }
+}
+
+fn constant_entry(constant: &Element) -> Entry {
+    let name = constant.attr("name").unwrap_or_default().to_string();
+    let value = constant.attr("value").unwrap_or_default();
+
+    Entry {
+        signature: format!("{name} = {value}"),
+        description: constant.text(),
+        status: Status::from_element(constant),
+        name,
+    }
+}
+
+/// Methods and signals, which have parameters and a `<description>` child.
+fn callable_entry(element: &Element, has_return: bool) -> Entry {
+    let name = element.attr("name").unwrap_or_default().to_string();
+
+    let params = element
+        .children_named("param")
+        .map(|param| {
+            format!(
+                "{}: {}",
+                param.attr("name").unwrap_or_default(),
+                param.attr("type").unwrap_or_default()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut signature = format!("{name}({params})");
+    if has_return {
+        let return_ty = element
+            .child("return")
+            .and_then(|ret| ret.attr("type"))
+            .unwrap_or("void");
+
+        if !matches!(return_ty, "()" | "void") {
+            let _ = write!(signature, " -> {return_ty}");
+        }
+    }
+
+    Entry {
+        signature,
+        description: child_text(element, "description"),
+        status: Status::from_element(element),
+        name,
+    }
+}
+
+fn write_entries_markdown(
+    out: &mut String,
+    entries: &[Entry],
+    heading: &str,
+    bb: &dyn Fn(&str) -> String,
+) {
+    for entry in entries {
+        let _ = write!(out, "{heading} `{}`\n\n", entry.signature);
+        write_status_markdown(out, &entry.status, bb);
+        if !entry.description.is_empty() {
+            let _ = write!(out, "{}\n\n", bb(&entry.description));
+        }
+    }
+}
+
+fn write_entries_html(
+    out: &mut String,
+    entries: &[Entry],
+    kind: &str,
+    heading: &str,
+    bb: &dyn Fn(&str) -> String,
+) {
+    for entry in entries {
+        let _ = writeln!(
+            out,
+            "<{heading} id=\"{kind}-{}\"><code>{}</code></{heading}>",
+            escape_html(&entry.name),
+            escape_html(&entry.signature)
+        );
+        write_status_html(out, &entry.status, bb);
+        if !entry.description.is_empty() {
+            let _ = writeln!(out, "<p>{}</p>", bb(&entry.description));
+        }
+    }
+}
+
+fn write_status_markdown(out: &mut String, status: &Status, bb: &dyn Fn(&str) -> String) {
+    if let Some(deprecated) = &status.deprecated {
+        let _ = write!(out, "> **Deprecated:** {}\n\n", bb(deprecated.trim()));
+    }
+    if let Some(experimental) = &status.experimental {
+        let _ = write!(out, "> **Experimental:** {}\n\n", bb(experimental.trim()));
+    }
+}
+
+fn write_status_html(out: &mut String, status: &Status, bb: &dyn Fn(&str) -> String) {
+    if let Some(deprecated) = &status.deprecated {
+        let _ = writeln!(
+            out,
+            "<blockquote><strong>Deprecated:</strong> {}</blockquote>",
+            bb(deprecated.trim())
+        );
+    }
+    if let Some(experimental) = &status.experimental {
+        let _ = writeln!(
+            out,
+            "<blockquote><strong>Experimental:</strong> {}</blockquote>",
+            bb(experimental.trim())
+        );
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// BBCode conversion
+
+fn class_link(name: &str, markup: Markup, known_classes: &HashSet<String>) -> String {
+    let known = known_classes.contains(name);
+
+    match markup {
+        Markup::Markdown if known => format!("[`{name}`]({name}.md)"),
+        Markup::Markdown => format!("`{name}`"),
+        Markup::Html if known => {
+            let name = escape_html(name);
+            format!("<a href=\"{name}.html\"><code>{name}</code></a>")
+        }
+        Markup::Html => format!("<code>{}</code>", escape_html(name)),
+    }
+}
+
+fn inline_code(code: &str, markup: Markup) -> String {
+    match markup {
+        Markup::Markdown if code.contains('`') => format!("`` {code} ``"),
+        Markup::Markdown => format!("`{code}`"),
+        Markup::Html => format!("<code>{}</code>", escape_html(code)),
+    }
+}
+
+/// Converts the BBCode subset used in Godot class references to Markdown or HTML.
+///
+/// Unknown tags are kept as literal text. Formatting without an equivalent (colors, fonts, alignment) is dropped.
+pub(super) fn bbcode_to_markup(
+    text: &str,
+    markup: Markup,
+    known_classes: &HashSet<String>,
+) -> String {
+    let md = markup == Markup::Markdown;
+    let mut out = String::with_capacity(text.len());
+    let mut open_urls = Vec::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('[') {
+        push_text(&mut out, &rest[..start], markup);
+        rest = &rest[start..];
+
+        let Some(end) = rest.find(']') else {
+            break;
+        };
+
+        let tag = &rest[1..end];
+        let after = &rest[end + 1..];
+        let (tag_name, tag_arg) = match tag.split_once([' ', '=']) {
+            Some((name, arg)) => (name, Some(arg.trim())),
+            None => (tag, None),
+        };
+
+        // Tags whose content is taken verbatim.
+        let verbatim = match tag_name {
+            "code" | "codeblock" | "codeblocks" => true,
+            "url" | "img" => tag_arg.is_none(),
+            _ => false,
+        };
+
+        if verbatim {
+            let raw_end_tag = format!("[/{tag_name}]");
+            let content_len = after.find(&raw_end_tag).unwrap_or(after.len());
+            let content = &after[..content_len];
+            rest = after[content_len..]
+                .strip_prefix(raw_end_tag.as_str())
+                .unwrap_or_default();
+
+            match tag_name {
+                "code" => out.push_str(&inline_code(content, markup)),
+                "codeblock" | "codeblocks" => {
+                    let lang = tag_arg
+                        .and_then(|arg| arg.strip_prefix("lang="))
+                        .unwrap_or("");
+
+                    if md {
+                        let _ = write!(out, "\n```{lang}\n{content}\n```\n");
+                    } else {
+                        let class = if lang.is_empty() {
+                            String::new()
+                        } else {
+                            format!(" class=\"language-{}\"", escape_html(lang))
+                        };
+                        let _ = write!(
+                            out,
+                            "<pre><code{class}>{}</code></pre>",
+                            escape_html(content)
+                        );
+                    }
+                }
+                "url" if md => {
+                    let _ = write!(out, "<{content}>");
+                }
+                "url" => {
+                    let url = escape_html(content);
+                    let _ = write!(out, "<a href=\"{url}\">{url}</a>");
+                }
+                "img" if md => {
+                    let _ = write!(out, "![]({content})");
+                }
+                _ => {
+                    let _ = write!(out, "<img src=\"{}\">", escape_html(content));
+                }
+            }
+            continue;
+        }
+
+        let converted = match (tag_name, tag_arg) {
+            ("br", None) => {
+                // Two consecutive line breaks separate paragraphs.
+                if let Some(after_second) = after.strip_prefix("[br]") {
+                    rest = after_second;
+                    out.push_str(if md { "\n\n" } else { "<br>\n<br>\n" });
+                    continue;
+                }
+
+                Some(if md { "  \n" } else { "<br>\n" }.to_string())
+            }
+            ("lb", None) => Some(if md { "\\[" } else { "[" }.to_string()),
+            ("rb", None) => Some(if md { "\\]" } else { "]" }.to_string()),
+            ("b" | "/b", None) if md => Some("**".to_string()),
+            ("i" | "/i", None) if md => Some("*".to_string()),
+            ("s" | "/s", None) if md => Some("~~".to_string()),
+            ("kbd" | "/kbd", None) if md => Some("`".to_string()),
+            ("u" | "/u", None) if md => Some(String::new()),
+            ("b" | "i" | "s" | "u" | "kbd", None) => Some(format!("<{}>", html_tag(tag_name))),
+            ("/b" | "/i" | "/s" | "/u" | "/kbd", None) => {
+                Some(format!("</{}>", html_tag(&tag_name[1..])))
+            }
+            ("url", Some(url)) => {
+                open_urls.push(url.to_string());
+                Some(if md {
+                    "[".to_string()
+                } else {
+                    format!("<a href=\"{}\">", escape_html(url))
+                })
+            }
+            ("/url", None) => open_urls.pop().map(|url| {
+                if md {
+                    format!("]({url})")
+                } else {
+                    "</a>".to_string()
+                }
+            }),
+            (
+                "center" | "/center" | "/color" | "/font" | "/font_size" | "indent" | "/indent",
+                None,
+            )
+            | ("color" | "font" | "font_size", Some(_)) => Some(String::new()),
+            (
+                "method" | "member" | "signal" | "constant" | "enum" | "param" | "annotation"
+                | "theme_item",
+                Some(name),
+            ) => Some(inline_code(name, markup)),
+            (class, None) if is_class_name(class) => Some(class_link(class, markup, known_classes)),
+            _ => None,
+        };
+
+        match converted {
+            Some(converted) => {
+                out.push_str(&converted);
+                rest = after;
+            }
+            None => {
+                // Not a known tag: keep the bracket literally and continue after it.
+                push_text(&mut out, "[", markup);
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    push_text(&mut out, rest, markup);
+    out
+}
+
+fn html_tag(bbcode_tag: &str) -> &'static str {
+    match bbcode_tag {
+        "b" => "strong",
+        "i" => "em",
+        "s" => "del",
+        "u" => "u",
+        _ => "kbd",
+    }
+}
+
+fn is_class_name(tag: &str) -> bool {
+    tag.starts_with(|c: char| c.is_ascii_uppercase())
+        && tag.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn push_text(out: &mut String, text: &str, markup: Markup) {
+    match markup {
+        Markup::Markdown => out.push_str(text),
+        Markup::Html => out.push_str(&escape_html(text)),
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<class name="Player" inherits="Node" deprecated="use [Hero]." xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:noNamespaceSchemaLocation="../class.xsd">
+<brief_description>Controls the [b]player[/b].</brief_description>
+<description>Moves around.[br][br]See [url=https://example.com]docs[/url] and [code]Gd&lt;Node&gt;[/code].</description>
+<methods>
+<method name="heal">
+  <return type="bool" />
+  <param index="0" name="amount" type="i32" />
+  <description>
+  Heals by [param amount].
+  </description>
+</method>
+</methods>
+
+<signals>
+<signal name="died">
+  <description>
+  Emitted on death.
+  </description>
+</signal>
+</signals>
+<members><member name="speed" type="f32" default="1.5">Speed in m/s.</member></members>
+</class>"#;
+
+    fn known() -> HashSet<String> {
+        ["Player", "Hero"].map(String::from).into()
+    }
+
+    #[test]
+    fn page_from_xml() {
+        let page = ClassPage::from_xml(XML).expect("valid XML");
+
+        assert_eq!(page.name, "Player");
+        assert_eq!(page.inherits, "Node");
+        assert_eq!(page.brief, "Controls the [b]player[/b].");
+        assert_eq!(page.status.deprecated.as_deref(), Some("use [Hero]."));
+        assert_eq!(page.methods[0].signature, "heal(amount: i32) -> bool");
+        assert_eq!(page.methods[0].description, "Heals by [param amount].");
+        assert_eq!(page.signals[0].signature, "died()");
+        assert_eq!(page.properties[0].signature, "speed: f32 = 1.5");
+        assert_eq!(page.properties[0].description, "Speed in m/s.");
+        assert!(page.constants.is_empty());
+    }
+
+    #[test]
+    fn render_markdown_page() {
+        let page = ClassPage::from_xml(XML).unwrap();
+        let md = page.render(Markup::Markdown, &known());
+
+        assert!(md.starts_with("# Player\n\n**Inherits:** `Node`\n\n"));
+        assert!(md.contains("> **Deprecated:** use [`Hero`](Hero.md).\n"));
+        assert!(md.contains("Controls the **player**."));
+        assert!(md.contains("Moves around.\n\nSee [docs](https://example.com) and `Gd<Node>`."));
+        assert!(md.contains("## Methods\n\n### `heal(amount: i32) -> bool`\n\nHeals by `amount`."));
+        assert!(md.contains("## Properties\n\n### `speed: f32 = 1.5`\n\nSpeed in m/s."));
+    }
+
+    #[test]
+    fn render_html_page() {
+        let page = ClassPage::from_xml(XML).unwrap();
+        let html = page.render(Markup::Html, &known());
+
+        assert!(html.contains("<h1>Player</h1>"));
+        assert!(html.contains("<a href=\"Hero.html\"><code>Hero</code></a>"));
+        assert!(html.contains(
+            "See <a href=\"https://example.com\">docs</a> and <code>Gd&lt;Node&gt;</code>."
+        ));
+        assert!(
+            html.contains("<h3 id=\"method-heal\"><code>heal(amount: i32) -&gt; bool</code></h3>")
+        );
+    }
+
+    #[test]
+    fn enums_and_tutorials() {
+        let xml = r#"<class name="Door" inherits="Node">
+<brief_description></brief_description>
+<description></description>
+<tutorials><link title="Doors 101">https://example.com/doors</link><link title="">https://example.com/more</link></tutorials>
+<constants><constant name="OPEN" value="0" enum="State">Open.</constant><constant name="LOCKED" value="1" is_bitfield="true" enum="Flags"></constant><constant name="MAX" value="9"></constant><constant name="CLOSED" value="1" enum="State"></constant></constants>
+<members></members>
+</class>"#;
+
+        let page = ClassPage::from_xml(xml).expect("valid XML");
+        assert_eq!(page.tutorials.len(), 2);
+        assert_eq!(page.enums.len(), 2);
+        assert_eq!(page.enums[0].signature(), "enum State");
+        assert_eq!(page.enums[0].constants.len(), 2);
+        assert_eq!(page.enums[1].signature(), "flags Flags");
+        assert_eq!(page.constants[0].name, "MAX");
+
+        let md = page.render(Markup::Markdown, &known());
+        assert!(md.contains("## Tutorials\n\n* [Doors 101](https://example.com/doors)\n* [https://example.com/more](https://example.com/more)\n"));
+        assert!(md.contains("## Enumerations\n\n### `enum State`\n\n#### `OPEN = 0`\n\nOpen.\n\n#### `CLOSED = 1`\n\n### `flags Flags`"));
+        assert!(md.contains("## Constants\n\n### `MAX = 9`"));
+
+        let html = page.render(Markup::Html, &known());
+        assert!(html.contains(
+            "<h3 id=\"enum-State\"><code>enum State</code></h3>\n<h4 id=\"constant-OPEN\">"
+        ));
+    }
+
+    #[test]
+    fn bbcode_conversion() {
+        let known = known();
+        let md = |text| bbcode_to_markup(text, Markup::Markdown, &known);
+        let html = |text| bbcode_to_markup(text, Markup::Html, &known);
+
+        assert_eq!(md("[i]a[/i] [s]b[/s] [lb]c[rb]"), "*a* ~~b~~ \\[c\\]");
+        assert_eq!(md("line[br]next"), "line  \nnext");
+        assert_eq!(
+            md("[codeblock lang=rust]let x = [1];[/codeblock]"),
+            "\n```rust\nlet x = [1];\n```\n"
+        );
+        assert_eq!(md("[unknown] [Player]"), "[unknown] [`Player`](Player.md)");
+        assert_eq!(html("[b]x < y[/b]"), "<strong>x &lt; y</strong>");
+        assert_eq!(
+            html("[url]https://a.b[/url]"),
+            "<a href=\"https://a.b\">https://a.b</a>"
+        );
+    }
+}
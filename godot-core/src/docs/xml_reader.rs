@@ -0,0 +1,338 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Minimal reader for the class reference XML produced by [`super::gather_xml_docs()`].
+//!
+//! Supports elements, attributes, text, comments, CDATA sections and the entities emitted by the proc-macro's escaping. Namespaces and
+//! DTDs are not needed and thus not supported.
+
+/// XML element with attributes and children.
+#[derive(Debug, Default)]
+pub(super) struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<XmlNode>,
+}
+
+#[derive(Debug)]
+pub(super) enum XmlNode {
+    Element(Element),
+    Text(String),
+}
+
+impl Element {
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Child elements, skipping text.
+    pub fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|node| match node {
+            XmlNode::Element(element) => Some(element),
+            XmlNode::Text(_) => None,
+        })
+    }
+
+    /// Child elements with the given tag name.
+    pub fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+        self.elements().filter(move |element| element.name == name)
+    }
+
+    pub fn child(&self, name: &str) -> Option<&Element> {
+        self.elements().find(|element| element.name == name)
+    }
+
+    /// Concatenated text content of this element (not recursive), with surrounding whitespace removed.
+    pub fn text(&self) -> String {
+        let text = self
+            .children
+            .iter()
+            .filter_map(|node| match node {
+                XmlNode::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect::<String>();
+
+        text.trim().to_string()
+    }
+}
+
+/// Parses an XML document and returns its root element.
+pub(super) fn parse(xml: &str) -> Result<Element, String> {
+    let mut parser = Parser { input: xml, pos: 0 };
+    parser.skip_prolog();
+
+    let root = parser.parse_element()?;
+
+    parser.skip_whitespace();
+    if parser.pos != xml.len() {
+        return Err(format!(
+            "unexpected content after root element at byte {}",
+            parser.pos
+        ));
+    }
+
+    Ok(root)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn rest(&self) -> &str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn skip_prolog(&mut self) {
+        self.skip_whitespace();
+        if self.rest().starts_with("<?") {
+            if let Some(end) = self.rest().find("?>") {
+                self.pos += end + 2;
+            }
+        }
+        self.skip_whitespace();
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), String> {
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            Ok(())
+        } else {
+            Err(format!("expected `{token}` at byte {}", self.pos))
+        }
+    }
+
+    fn parse_name(&mut self) -> Result<String, String> {
+        let len = self
+            .rest()
+            .find(|c: char| c.is_whitespace() || matches!(c, '=' | '>' | '/'))
+            .unwrap_or(self.rest().len());
+
+        if len == 0 {
+            return Err(format!("expected name at byte {}", self.pos));
+        }
+
+        let name = self.rest()[..len].to_string();
+        self.pos += len;
+        Ok(name)
+    }
+
+    fn parse_element(&mut self) -> Result<Element, String> {
+        self.expect("<")?;
+        let mut element = Element {
+            name: self.parse_name()?,
+            ..Default::default()
+        };
+
+        // Attributes.
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.pos += 2;
+                return Ok(element);
+            }
+            if self.rest().starts_with('>') {
+                self.pos += 1;
+                break;
+            }
+
+            let key = self.parse_name()?;
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            self.expect("\"")?;
+
+            let len = self
+                .rest()
+                .find('"')
+                .ok_or_else(|| format!("unterminated attribute `{key}`"))?;
+            let value = unescape(&self.rest()[..len]);
+            self.pos += len + 1;
+
+            element.attributes.push((key, value));
+        }
+
+        // Content.
+        loop {
+            let text_len = self
+                .rest()
+                .find('<')
+                .ok_or_else(|| format!("unclosed element `{}`", element.name))?;
+
+            if text_len > 0 {
+                let text = unescape(&self.rest()[..text_len]);
+                element.children.push(XmlNode::Text(text));
+                self.pos += text_len;
+            }
+
+            if self.rest().starts_with("<!--") {
+                let len = self.rest().find("-->").ok_or("unterminated comment")?;
+                self.pos += len + 3;
+                continue;
+            }
+
+            if let Some(cdata) = self.rest().strip_prefix("<![CDATA[") {
+                let len = cdata.find("]]>").ok_or("unterminated CDATA section")?;
+
+                // CDATA content is taken verbatim, without unescaping entities.
+                element
+                    .children
+                    .push(XmlNode::Text(cdata[..len].to_string()));
+                self.pos += "<![CDATA[".len() + len + "]]>".len();
+                continue;
+            }
+
+            if self.rest().starts_with("</") {
+                self.pos += 2;
+                let name = self.parse_name()?;
+                if name != element.name {
+                    return Err(format!(
+                        "closing tag `{name}` does not match `{}`",
+                        element.name
+                    ));
+                }
+
+                self.skip_whitespace();
+                self.expect(">")?;
+                return Ok(element);
+            }
+
+            let child = self.parse_element()?;
+            element.children.push(XmlNode::Element(child));
+        }
+    }
+}
+
+fn unescape(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let Some(end) = rest.find(';') else {
+            break;
+        };
+
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix('#')
+                .and_then(|code| match code.strip_prefix('x') {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => code.parse().ok(),
+                })
+                .and_then(char::from_u32),
+        };
+
+        match decoded {
+            Some(c) => {
+                result.push(c);
+                rest = &rest[end + 1..];
+            }
+            // Not an entity; keep the ampersand literally.
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_nested_and_self_closing() {
+        let root = parse(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<class name="Player">
+  <members>
+    <member name="speed" type="float" />
+    <member name="health" type="int"/>
+  </members>
+  <signals></signals>
+</class>
+"#,
+        )
+        .expect("valid XML");
+
+        assert_eq!(root.name, "class");
+        assert_eq!(root.attr("name"), Some("Player"));
+        assert_eq!(root.attr("missing"), None);
+
+        let members = root.child("members").expect("<members>");
+        let names = members
+            .children_named("member")
+            .map(|member| (member.attr("name").unwrap(), member.attr("type").unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(names, [("speed", "float"), ("health", "int")]);
+        assert!(members
+            .children_named("member")
+            .all(|member| member.children.is_empty()));
+
+        let signals = root.child("signals").expect("<signals>");
+        assert!(signals.children.is_empty());
+    }
+
+    #[test]
+    fn parse_escapes() {
+        let root = parse(
+            r#"<description title="a &quot;b&quot; &amp; &apos;c&apos;">Gd&lt;Node&gt; &#65;&#x42; &unknown; & done</description>"#,
+        )
+        .expect("valid XML");
+
+        assert_eq!(root.attr("title"), Some(r#"a "b" & 'c'"#));
+        assert_eq!(root.text(), "Gd<Node> AB &unknown; & done");
+    }
+
+    #[test]
+    fn parse_cdata_and_comments() {
+        let root = parse(
+            "<description>\n  <!-- ignored <tag> -->before <![CDATA[<b>&amp;</b>]]> after\n</description>",
+        )
+        .expect("valid XML");
+
+        assert!(root.elements().next().is_none());
+        assert_eq!(root.text(), "before <b>&amp;</b> after");
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(parse("<class>").is_err());
+        assert!(parse("<class></members>").is_err());
+        assert!(parse(r#"<class name="unterminated></class>"#).is_err());
+        assert!(parse("<class><![CDATA[open</class>").is_err());
+        assert!(parse("<class/><extra/>").is_err());
+    }
+}
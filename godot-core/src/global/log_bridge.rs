@@ -0,0 +1,429 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Forwarding of `log` records and `tracing` events to Godot's output.
+
+use std::fmt;
+
+use crate::sys;
+
+/// Verbosity of log output, from least to most verbose.
+///
+/// Used by [`LogFilter`] to select which messages are forwarded to Godot. Mirrors the levels of the `log` and `tracing` crates.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum LogLevel {
+    /// Nothing is logged. Only valid as a filter level.
+    Off,
+
+    /// Forwarded to [`godot_error!`](crate::global::godot_error), shown in the debugger's _Errors_ tab.
+    Error,
+
+    /// Forwarded to [`godot_warn!`](crate::global::godot_warn), shown in the debugger's _Errors_ tab.
+    Warn,
+
+    /// Printed to the output panel, while the engine is running.
+    Info,
+
+    /// Printed to the output panel, dimmed.
+    Debug,
+
+    /// Printed to the output panel, dimmed.
+    Trace,
+}
+
+impl LogLevel {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Off => "OFF",
+            Self::Error => "ERROR",
+            Self::Warn => "WARN",
+            Self::Info => "INFO",
+            Self::Debug => "DEBUG",
+            Self::Trace => "TRACE",
+        }
+    }
+
+    /// BBCode color name used by `print_rich()`.
+    fn color(self) -> &'static str {
+        match self {
+            Self::Off | Self::Error => "red",
+            Self::Warn => "yellow",
+            Self::Info => "green",
+            Self::Debug => "gray",
+            Self::Trace => "dim_gray",
+        }
+    }
+}
+
+/// Selects which log messages are forwarded to Godot, depending on their level and target (usually the module path).
+///
+/// Configured per extension via [`ExtensionLibrary::log_filter()`](crate::init::ExtensionLibrary::log_filter):
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::global::{LogFilter, LogLevel};
+/// struct MyExtension;
+///
+/// #[gdextension]
+/// unsafe impl ExtensionLibrary for MyExtension {
+///     fn log_filter() -> Option<LogFilter> {
+///         let filter = LogFilter::new(LogLevel::Info)
+///             .with_module("my_game::net", LogLevel::Debug)
+///             .with_module("noisy_dependency", LogLevel::Warn);
+///
+///         Some(filter)
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LogFilter {
+    default_level: LogLevel,
+    modules: Vec<(String, LogLevel)>,
+}
+
+impl LogFilter {
+    /// Creates a filter that allows messages up to `default_level`, for all modules.
+    pub fn new(default_level: LogLevel) -> Self {
+        Self {
+            default_level,
+            modules: Vec::new(),
+        }
+    }
+
+    /// Overrides the level for a module and its submodules, e.g. `"my_crate::network"`.
+    ///
+    /// If multiple modules match a target, the most specific one wins.
+    pub fn with_module(mut self, module: impl Into<String>, level: LogLevel) -> Self {
+        let module = module.into();
+        self.modules.retain(|(existing, _)| *existing != module);
+        self.modules.push((module, level));
+        self
+    }
+
+    /// Maximum level enabled for the given target.
+    pub fn level_for(&self, target: &str) -> LogLevel {
+        self.modules
+            .iter()
+            .filter(|(module, _)| {
+                target
+                    .strip_prefix(module.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .max_by_key(|(module, _)| module.len())
+            .map_or(self.default_level, |(_, level)| *level)
+    }
+
+    /// Whether a message with `level` and `target` passes this filter.
+    pub fn is_enabled(&self, target: &str, level: LogLevel) -> bool {
+        level != LogLevel::Off && level <= self.level_for(target)
+    }
+
+    /// Most verbose level enabled for any target.
+    pub fn max_level(&self) -> LogLevel {
+        self.modules
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default_level, LogLevel::max)
+    }
+}
+
+impl Default for LogFilter {
+    /// Allows messages up to [`LogLevel::Info`].
+    fn default() -> Self {
+        Self::new(LogLevel::Info)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Output
+
+/// Source location and origin of a forwarded message.
+struct Origin<'a> {
+    target: &'a str,
+    file: Option<&'a str>,
+    line: Option<u32>,
+}
+
+/// Sends a message to Godot: errors and warnings with their source location, other levels via `print_rich()`.
+fn forward(level: LogLevel, origin: Origin, message: fmt::Arguments) {
+    let file = origin.file.unwrap_or("<unknown>");
+    let line = origin.line.unwrap_or(0);
+
+    match level {
+        LogLevel::Off => {}
+        LogLevel::Error => crate::inner_godot_msg!(
+            print_error;
+            location = (origin.target, file, line);
+            "{}", message
+        ),
+        LogLevel::Warn => crate::inner_godot_msg!(
+            print_warning;
+            location = (origin.target, file, line);
+            "{}", message
+        ),
+        // Like `godot_print_rich!`, this needs a running engine. Messages outside its lifetime (e.g. during shutdown) are dropped, while
+        // errors and warnings fall back to stderr inside `inner_godot_msg!`.
+        LogLevel::Info | LogLevel::Debug | LogLevel::Trace if sys::is_initialized() => {
+            let color = level.color();
+            let label = level.label();
+            let target = origin.target;
+
+            // Escape opening brackets, so that the message itself is not interpreted as BBCode.
+            let message = message.to_string().replace('[', "[lb]");
+
+            crate::godot_print_rich!("[color={color}]{label}[/color] [i]{target}[/i]: {message}");
+        }
+        LogLevel::Info | LogLevel::Debug | LogLevel::Trace => {}
+    }
+}
+
+/// Installs the `log` and `tracing` bridges, if enabled. Called once the `Core` level is loaded.
+pub(crate) fn install(filter: Option<LogFilter>) {
+    let Some(filter) = filter else {
+        return;
+    };
+
+    // Fails if a logger or subscriber was already set, e.g. by the user; that one is kept.
+    #[cfg(feature = "log")]
+    let _ = GodotLogger::new(filter.clone()).install();
+
+    #[cfg(feature = "tracing")]
+    {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let subscriber = tracing_subscriber::registry().with(GodotLayer::new(filter));
+        let _ =
+            tracing_core::dispatcher::set_global_default(tracing_core::Dispatch::new(subscriber));
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// log
+
+#[cfg(feature = "log")]
+pub use log_impl::GodotLogger;
+
+#[cfg(feature = "log")]
+mod log_impl {
+    use super::*;
+
+    /// [`log::Log`] implementation that forwards records to Godot.
+    ///
+    /// Installed automatically during extension initialization, unless [`ExtensionLibrary::log_filter()`] returns `None`. Errors and
+    /// warnings appear in the debugger with the record's file and line; other levels are printed with colors via `print_rich()`.
+    ///
+    /// [`ExtensionLibrary::log_filter()`]: crate::init::ExtensionLibrary::log_filter
+    #[derive(Debug)]
+    pub struct GodotLogger {
+        filter: LogFilter,
+    }
+
+    impl GodotLogger {
+        pub fn new(filter: LogFilter) -> Self {
+            Self { filter }
+        }
+
+        /// Sets this logger as the global logger of the `log` crate. Fails if another logger has already been set.
+        pub fn install(self) -> Result<(), log::SetLoggerError> {
+            let max_level = self.filter.max_level();
+            log::set_boxed_logger(Box::new(self))?;
+            log::set_max_level(max_level.into());
+            Ok(())
+        }
+    }
+
+    impl log::Log for GodotLogger {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            self.filter
+                .is_enabled(metadata.target(), metadata.level().into())
+        }
+
+        fn log(&self, record: &log::Record) {
+            if !self.enabled(record.metadata()) {
+                return;
+            }
+
+            let origin = Origin {
+                target: record.target(),
+                file: record.file(),
+                line: record.line(),
+            };
+
+            forward(record.level().into(), origin, *record.args());
+        }
+
+        fn flush(&self) {}
+    }
+
+    impl From<log::Level> for LogLevel {
+        fn from(level: log::Level) -> Self {
+            match level {
+                log::Level::Error => Self::Error,
+                log::Level::Warn => Self::Warn,
+                log::Level::Info => Self::Info,
+                log::Level::Debug => Self::Debug,
+                log::Level::Trace => Self::Trace,
+            }
+        }
+    }
+
+    impl From<LogLevel> for log::LevelFilter {
+        fn from(level: LogLevel) -> Self {
+            match level {
+                LogLevel::Off => Self::Off,
+                LogLevel::Error => Self::Error,
+                LogLevel::Warn => Self::Warn,
+                LogLevel::Info => Self::Info,
+                LogLevel::Debug => Self::Debug,
+                LogLevel::Trace => Self::Trace,
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// tracing
+
+#[cfg(feature = "tracing")]
+pub use tracing_impl::GodotLayer;
+
+#[cfg(feature = "tracing")]
+mod tracing_impl {
+    use std::fmt::Write as _;
+
+    use tracing_core::field::{Field, Visit};
+    use tracing_core::{Event, Subscriber};
+    use tracing_subscriber::layer::Context;
+    use tracing_subscriber::Layer;
+
+    use super::*;
+
+    /// [`tracing_subscriber::Layer`] that forwards events to Godot.
+    ///
+    /// Unless [`ExtensionLibrary::log_filter()`] returns `None`, a global subscriber with this layer is installed during extension
+    /// initialization. To combine it with other layers, return `None` there and set up the subscriber yourself:
+    /// ```no_run
+    /// # use godot::global::{GodotLayer, LogFilter};
+    /// use tracing_subscriber::prelude::*;
+    ///
+    /// tracing_subscriber::registry()
+    ///     .with(GodotLayer::new(LogFilter::default()))
+    ///     .init();
+    /// ```
+    ///
+    /// Event fields other than the message are appended as `key=value`.
+    ///
+    /// [`ExtensionLibrary::log_filter()`]: crate::init::ExtensionLibrary::log_filter
+    #[derive(Debug)]
+    pub struct GodotLayer {
+        filter: LogFilter,
+    }
+
+    impl GodotLayer {
+        pub fn new(filter: LogFilter) -> Self {
+            Self { filter }
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for GodotLayer {
+        fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+            let metadata = event.metadata();
+            let level = LogLevel::from(*metadata.level());
+            if !self.filter.is_enabled(metadata.target(), level) {
+                return;
+            }
+
+            let mut fields = FieldCollector::default();
+            event.record(&mut fields);
+
+            let origin = Origin {
+                target: metadata.target(),
+                file: metadata.file(),
+                line: metadata.line(),
+            };
+
+            forward(
+                level,
+                origin,
+                format_args!("{}{}", fields.message, fields.others),
+            );
+        }
+    }
+
+    impl From<tracing_core::Level> for LogLevel {
+        fn from(level: tracing_core::Level) -> Self {
+            match level {
+                tracing_core::Level::ERROR => Self::Error,
+                tracing_core::Level::WARN => Self::Warn,
+                tracing_core::Level::INFO => Self::Info,
+                tracing_core::Level::DEBUG => Self::Debug,
+                _ => Self::Trace,
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct FieldCollector {
+        message: String,
+        others: String,
+    }
+
+    impl Visit for FieldCollector {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "message" {
+                self.message.push_str(value);
+            } else {
+                let _ = write!(self.others, " {}={value}", field.name());
+            }
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            if field.name() == "message" {
+                let _ = write!(self.message, "{value:?}");
+            } else {
+                let _ = write!(self.others, " {}={value:?}", field.name());
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_by_module() {
+        let filter = LogFilter::new(LogLevel::Warn)
+            .with_module("game", LogLevel::Info)
+            .with_module("game::net", LogLevel::Trace)
+            .with_module("game::net::noisy", LogLevel::Off);
+
+        assert_eq!(filter.level_for("other"), LogLevel::Warn);
+        assert_eq!(filter.level_for("game"), LogLevel::Info);
+        assert_eq!(filter.level_for("game::ui"), LogLevel::Info);
+        assert_eq!(filter.level_for("gamepad"), LogLevel::Warn);
+        assert_eq!(filter.level_for("game::net::peer"), LogLevel::Trace);
+        assert_eq!(filter.level_for("game::net::noisy"), LogLevel::Off);
+
+        assert!(filter.is_enabled("game::net", LogLevel::Debug));
+        assert!(!filter.is_enabled("game::ui", LogLevel::Debug));
+        assert!(!filter.is_enabled("game::net::noisy", LogLevel::Error));
+        assert!(filter.is_enabled("other", LogLevel::Error));
+        assert_eq!(filter.max_level(), LogLevel::Trace);
+    }
+
+    #[test]
+    fn filter_replaces_module() {
+        let filter = LogFilter::default()
+            .with_module("game", LogLevel::Debug)
+            .with_module("game", LogLevel::Error);
+
+        assert_eq!(filter.level_for("game"), LogLevel::Error);
+        assert_eq!(filter.max_level(), LogLevel::Info);
+    }
+}
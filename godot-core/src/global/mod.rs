@@ -40,8 +40,13 @@
     alias = "is_instance_id_valid"
 )]
 
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub(crate) mod log_bridge;
 mod print;
 
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub use log_bridge::*;
+
 // Some enums are directly re-exported from crate::builtin.
 pub use crate::gen::central::global_enums::*;
 pub use crate::gen::utilities::*;
@@ -26,19 +26,36 @@ macro_rules! inner_function {
 macro_rules! inner_godot_msg {
     // FIXME expr needs to be parenthesised, see usages
     ($godot_fn:ident; $fmt:literal $(, $args:expr)* $(,)?) => {
-    //($($args:tt),* $(,)?) => {
+        $crate::inner_godot_msg!(
+            @emit $godot_fn;
+            ($crate::inner_function!(), concat!(file!(), "\0"), line!());
+            $fmt $(, $args)*
+        );
+    };
+
+    // Explicit source location, for messages that originate elsewhere (e.g. forwarded `log` records).
+    ($godot_fn:ident; location = ($function:expr, $file:expr, $line:expr); $fmt:literal $(, $args:expr)* $(,)?) => {
+        $crate::inner_godot_msg!(
+            @emit $godot_fn;
+            ($function, &format!("{}\0", $file), $line);
+            $fmt $(, $args)*
+        );
+    };
+
+    // `$file_nul` must be nul-terminated; it is only evaluated if the engine is loaded.
+    (@emit $godot_fn:ident; ($function:expr, $file_nul:expr, $line:expr); $fmt:literal $(, $args:expr)*) => {
         unsafe {
             let msg = format!("{}\0", format_args!($fmt $(, $args)*));
             // Godot supports Unicode messages, not only ASCII. See `do_panic` test.
 
             // Check whether engine is loaded, otherwise fall back to stderr.
             if $crate::sys::is_initialized() {
-                let function = format!("{}\0", $crate::inner_function!());
+                let function = format!("{}\0", $function);
                 $crate::sys::interface_fn!($godot_fn)(
                     $crate::sys::c_str_from_str(&msg),
                     $crate::sys::c_str_from_str(&function),
-                    $crate::sys::c_str_from_str(concat!(file!(), "\0")),
-                    line!() as i32,
+                    $crate::sys::c_str_from_str($file_nul),
+                    $line as i32,
                     false as $crate::sys::GDExtensionBool, // whether to create a toast notification in editor
                 );
             } else {
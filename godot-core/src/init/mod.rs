@@ -146,6 +146,13 @@ unsafe extern "C" fn ffi_initialize_layer<E: ExtensionLibrary>(
         // SAFETY: Godot will call this from the main thread, after `__gdext_load_library` where the library is initialized,
         // and only once per level.
         unsafe { gdext_on_level_init(level, userdata) };
+
+        // Before user code, so that it can already log during init.
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        if level == InitLevel::Core {
+            crate::global::log_bridge::install(E::log_filter());
+        }
+
         E::on_stage_init(level.to_stage());
     }
 
@@ -210,6 +217,11 @@ unsafe fn gdext_on_level_init(level: InitLevel, userdata: &InitUserData) {
         InitLevel::Scene => {
             // SAFETY: On the main thread, api initialized, `Scene` was initialized above.
             unsafe { ensure_godot_features_compatible() };
+
+            crate::testing::on_scene_init();
+
+            #[cfg(all(since_api = "4.3", feature = "register-docs"))]
+            crate::docs::on_scene_init();
         }
         InitLevel::Editor => {
             #[cfg(all(since_api = "4.3", feature = "register-docs"))]
@@ -221,10 +233,22 @@ unsafe fn gdext_on_level_init(level: InitLevel, userdata: &InitUserData) {
     }
 
     crate::registry::class::auto_register_classes(level);
+
+    if level == InitLevel::Scene {
+        // Loaders and savers are instances of classes registered just above.
+        crate::tools::resource_format::register_all();
+    }
 }
 
 /// Tasks needed to be done by gdext internally upon unloading an initialization level. Called after user code.
 fn gdext_on_level_deinit(level: InitLevel) {
+    if level == InitLevel::Scene {
+        #[cfg(safeguards_strict)]
+        crate::obj::tracking::report_leaks();
+
+        crate::tools::resource_format::unregister_all();
+    }
+
     crate::registry::class::unregister_classes(level);
 
     if level == InitLevel::Core {
@@ -332,6 +356,20 @@ pub unsafe trait ExtensionLibrary {
         InitLevel::Scene
     }
 
+    /// Configures forwarding of `log` records and `tracing` events to Godot's output.
+    ///
+    /// <div class="stab portability">Available on <strong>crate features <code>log</code> or <code>tracing</code></strong> only.</div>
+    ///
+    /// By default, messages up to [`LogLevel::Info`][crate::global::LogLevel::Info] are forwarded from all modules. Return a custom
+    /// [`LogFilter`][crate::global::LogFilter] to change levels per module, or `None` to not install the Godot logger (e.g. because
+    /// you set up your own `tracing` subscriber).
+    ///
+    /// The logger is installed when the `Core` level is loaded, before [`on_stage_init()`][Self::on_stage_init] is invoked.
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    fn log_filter() -> Option<crate::global::LogFilter> {
+        Some(crate::global::LogFilter::default())
+    }
+
     /// Custom logic when a certain initialization stage is loaded.
     ///
     /// This will be invoked for stages >= [`Self::min_level()`], in ascending order. Use `if` or `match` to hook to specific stages.
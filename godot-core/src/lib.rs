@@ -28,6 +28,7 @@ pub mod meta;
 pub mod obj;
 pub mod registry;
 pub mod task;
+pub mod testing;
 pub mod tools;
 
 mod storage;
@@ -188,6 +188,15 @@ pub(crate) enum FromGodotError {
         actual: ElementType,
     },
 
+    /// Destination `Dict<K, V>` has different key or value type than source's runtime types.
+    #[cfg(since_api = "4.4")]
+    BadDictionaryType {
+        expected_key: ElementType,
+        expected_value: ElementType,
+        actual_key: ElementType,
+        actual_value: ElementType,
+    },
+
     /// Special case of `BadArrayType` where a custom int type such as `i8` cannot hold a dynamic `i64` value.
     #[cfg(safeguards_strict)]
     BadArrayTypeInt {
@@ -234,6 +243,18 @@ impl fmt::Display for FromGodotError {
 
                 write!(f, "expected array of type {exp_class}, got {act_class}")
             }
+            #[cfg(since_api = "4.4")]
+            Self::BadDictionaryType {
+                expected_key,
+                expected_value,
+                actual_key,
+                actual_value,
+            } => {
+                write!(
+                    f,
+                    "expected dictionary of type [{expected_key:?}, {expected_value:?}], got [{actual_key:?}, {actual_value:?}]"
+                )
+            }
             #[cfg(safeguards_strict)]
             Self::BadArrayTypeInt {
                 expected_int_type,
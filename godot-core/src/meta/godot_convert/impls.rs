@@ -5,9 +5,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::hash::{BuildHasher, Hash};
+
 use godot_ffi as sys;
 
-use crate::builtin::{Array, Variant};
+use crate::builtin::{Array, Dict, Variant, VariantArray};
 use crate::meta;
 use crate::meta::error::{ConvertError, ErrorKind, FromFfiError, FromVariantError};
 use crate::meta::{
@@ -448,6 +451,173 @@ impl<T: ArrayElement> ToGodot for &[T] {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Maps and sets
+
+// Maps are converted to typed dictionaries, sets to typed arrays. Iteration order of the Godot side follows the Rust iteration order.
+// When converting back, duplicate set elements are merged.
+
+impl<K, V, S> GodotConvert for HashMap<K, V, S>
+where
+    K: ArrayElement + Eq + Hash,
+    V: ArrayElement,
+{
+    type Via = Dict<K, V>;
+}
+
+impl<K, V, S> ToGodot for HashMap<K, V, S>
+where
+    K: ArrayElement + Eq + Hash,
+    V: ArrayElement,
+{
+    type Pass = meta::ByValue;
+
+    fn to_godot(&self) -> Self::Via {
+        map_to_dict(self.iter())
+    }
+}
+
+impl<K, V, S> FromGodot for HashMap<K, V, S>
+where
+    K: ArrayElement + Eq + Hash,
+    V: ArrayElement,
+    S: BuildHasher + Default,
+{
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Ok(via.iter_shared().collect())
+    }
+}
+
+impl<K: ArrayElement + Ord, V: ArrayElement> GodotConvert for BTreeMap<K, V> {
+    type Via = Dict<K, V>;
+}
+
+impl<K: ArrayElement + Ord, V: ArrayElement> ToGodot for BTreeMap<K, V> {
+    type Pass = meta::ByValue;
+
+    fn to_godot(&self) -> Self::Via {
+        map_to_dict(self.iter())
+    }
+}
+
+impl<K: ArrayElement + Ord, V: ArrayElement> FromGodot for BTreeMap<K, V> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Ok(via.iter_shared().collect())
+    }
+}
+
+impl<T, S> GodotConvert for HashSet<T, S>
+where
+    T: ArrayElement + Eq + Hash,
+{
+    type Via = Array<T>;
+}
+
+impl<T, S> ToGodot for HashSet<T, S>
+where
+    T: ArrayElement + Eq + Hash,
+{
+    type Pass = meta::ByValue;
+
+    fn to_godot(&self) -> Self::Via {
+        set_to_array(self.iter())
+    }
+}
+
+impl<T, S> FromGodot for HashSet<T, S>
+where
+    T: ArrayElement + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Ok(via.iter_shared().collect())
+    }
+}
+
+impl<T: ArrayElement + Ord> GodotConvert for BTreeSet<T> {
+    type Via = Array<T>;
+}
+
+impl<T: ArrayElement + Ord> ToGodot for BTreeSet<T> {
+    type Pass = meta::ByValue;
+
+    fn to_godot(&self) -> Self::Via {
+        set_to_array(self.iter())
+    }
+}
+
+impl<T: ArrayElement + Ord> FromGodot for BTreeSet<T> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Ok(via.iter_shared().collect())
+    }
+}
+
+fn map_to_dict<'a, K, V>(entries: impl Iterator<Item = (&'a K, &'a V)>) -> Dict<K, V>
+where
+    K: ArrayElement,
+    V: ArrayElement,
+{
+    let mut dict = Dict::new();
+    for (key, value) in entries {
+        dict.set(meta::ref_to_arg(key), meta::ref_to_arg(value));
+    }
+    dict
+}
+
+fn set_to_array<'a, T: ArrayElement>(elements: impl Iterator<Item = &'a T>) -> Array<T> {
+    let mut array = Array::new();
+    for element in elements {
+        array.push(meta::ref_to_arg(element));
+    }
+    array
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tuples
+
+// Tuples are converted to untyped arrays of fixed length, with each element converted individually.
+// Heterogeneous element types rule out `Array<T>`.
+
+macro_rules! impl_tuple_convert {
+    ($len:literal; $($T:ident : $idx:tt),+) => {
+        impl<$($T),+> GodotConvert for ($($T,)+) {
+            type Via = VariantArray;
+        }
+
+        impl<$($T: ToGodot),+> ToGodot for ($($T,)+) {
+            type Pass = meta::ByValue;
+
+            fn to_godot(&self) -> Self::Via {
+                [$( self.$idx.to_variant() ),+].into_iter().collect()
+            }
+        }
+
+        impl<$($T: FromGodot),+> FromGodot for ($($T,)+) {
+            fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+                let via_len = via.len();
+                if via_len != $len {
+                    let message = format!(
+                        "Array of length {via_len} cannot be converted to a tuple of length {}", $len
+                    );
+                    return Err(ConvertError::with_kind_value(
+                        ErrorKind::Custom(Some(message.into())),
+                        via,
+                    ));
+                }
+
+                Ok(($( via.at($idx).try_to::<$T>()?, )+))
+            }
+        }
+    };
+}
+
+impl_tuple_convert!(1; T0: 0);
+impl_tuple_convert!(2; T0: 0, T1: 1);
+impl_tuple_convert!(3; T0: 0, T1: 1, T2: 2);
+impl_tuple_convert!(4; T0: 0, T1: 1, T2: 2, T3: 3);
+impl_tuple_convert!(5; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4);
+impl_tuple_convert!(6; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5);
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Raw pointers
 
@@ -7,9 +7,9 @@
 
 use godot_ffi::conv::u32_to_usize;
 
-use crate::builtin::{StringName, Variant};
+use crate::builtin::{vdict, Dictionary, StringName, Variant, VariantArray};
 use crate::global::MethodFlags;
-use crate::meta::{ClassId, PropertyInfo};
+use crate::meta::{ClassId, PropertyInfo, ToGodot};
 use crate::sys;
 
 /// Describes a method in Godot.
@@ -31,6 +31,30 @@ pub struct MethodInfo {
 }
 
 impl MethodInfo {
+    /// Converts to the dictionary format used by Godot's reflection APIs, e.g. `Object.get_method_list()`.
+    ///
+    /// Keys are `name`, `args`, `default_args`, `flags`, `id` and `return`; argument and return types are
+    /// [`PropertyInfo` dictionaries](PropertyInfo::to_dictionary).
+    pub fn to_dictionary(&self) -> Dictionary {
+        use crate::obj::EngineBitfield as _;
+
+        let args: VariantArray = self
+            .arguments
+            .iter()
+            .map(|arg| arg.to_dictionary().to_variant())
+            .collect();
+        let default_args: VariantArray = self.default_arguments.iter().cloned().collect();
+
+        vdict! {
+            "name": self.method_name.clone(),
+            "args": args,
+            "default_args": default_args,
+            "flags": self.flags.ord(),
+            "id": self.id,
+            "return": self.return_type.to_dictionary(),
+        }
+    }
+
     /// Consumes self and turns it into a `sys::GDExtensionMethodInfo`, should be used together with
     /// [`free_owned_method_sys`](Self::free_owned_method_sys).
     ///
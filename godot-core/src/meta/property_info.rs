@@ -7,7 +7,7 @@
 
 use godot_ffi::VariantType;
 
-use crate::builtin::{GString, StringName};
+use crate::builtin::{vdict, Dictionary, GString, StringName};
 use crate::global::{PropertyHint, PropertyUsageFlags};
 use crate::meta::{element_godot_type_name, ArrayElement, ClassId, GodotType, PackedArrayElement};
 use crate::obj::{bounds, Bounds, EngineBitfield, EngineEnum, GodotClass};
@@ -140,6 +140,34 @@ impl PropertyInfo {
         }
     }
 
+    /// Create a new `PropertyInfo` representing a category in Godot.
+    ///
+    /// Categories are shown as headers in the Inspector, one level above groups. See also
+    /// [`EditorInspector`](https://docs.godotengine.org/en/latest/classes/class_editorinspector.html#class-editorinspector) in Godot.
+    pub fn new_category(category_name: &str) -> Self {
+        Self {
+            variant_type: VariantType::NIL,
+            class_id: ClassId::none(),
+            property_name: category_name.into(),
+            hint_info: PropertyHintInfo::none(),
+            usage: PropertyUsageFlags::CATEGORY,
+        }
+    }
+
+    /// Converts to the dictionary format used by Godot's reflection APIs, e.g. `Object.get_property_list()`.
+    ///
+    /// Keys are `name`, `class_name`, `type`, `hint`, `hint_string` and `usage`.
+    pub fn to_dictionary(&self) -> Dictionary {
+        vdict! {
+            "name": self.property_name.clone(),
+            "class_name": self.class_id.to_string_name(),
+            "type": self.variant_type.ord(),
+            "hint": self.hint_info.hint.ord(),
+            "hint_string": self.hint_info.hint_string.clone(),
+            "usage": self.usage.ord(),
+        }
+    }
+
     // ------------------------------------------------------------------------------------------------------------------------------------------
     // Introspection API -- could be made public in the future
 
@@ -45,6 +45,7 @@ impl Sealed for Signal {}
 impl Sealed for Transform2D {}
 impl Sealed for Transform3D {}
 impl Sealed for Dictionary {}
+impl<K: ArrayElement, V: ArrayElement> Sealed for Dict<K, V> {}
 impl Sealed for bool {}
 impl Sealed for i64 {}
 impl Sealed for i32 {}
@@ -20,7 +20,7 @@ use crate::meta::{
 };
 use crate::obj::{
     bounds, cap, Bounds, DynGd, GdDerefTarget, GdMut, GdRef, GodotClass, Inherits, InstanceId,
-    OnEditor, RawGd, WithBaseField, WithSignals,
+    OnEditor, RawGd, WeakGd, WithBaseField, WithSignals,
 };
 use crate::private::{callbacks, PanicPayload};
 use crate::registry::class::try_dynify_object;
@@ -159,7 +159,12 @@ where
         let object_ptr = callbacks::create_custom(init, true) // or propagate panic.
             .unwrap_or_else(|payload| PanicPayload::repanic(payload));
 
-        unsafe { Gd::from_obj_sys(object_ptr) }
+        let obj = unsafe { Gd::from_obj_sys(object_ptr) };
+
+        #[cfg(safeguards_strict)]
+        crate::obj::tracking::track_created(&obj);
+
+        obj
     }
 
     /// Moves a user-created object into this smart pointer, submitting ownership to the Godot engine.
@@ -293,6 +298,14 @@ impl<T: GodotClass> Gd<T> {
         self.raw.is_instance_valid()
     }
 
+    /// Creates a [`WeakGd`] pointer to this object, which does not keep it alive.
+    ///
+    /// # Panics
+    /// If this object is no longer alive.
+    pub fn downgrade(&self) -> WeakGd<T> {
+        WeakGd::from_instance_id(self.instance_id())
+    }
+
     /// Returns the dynamic class name of the object as `StringName`.
     ///
     /// This method retrieves the class name of the object at runtime, which can be different from [`T::class_id()`][GodotClass::class_name]
@@ -538,7 +551,12 @@ impl<T: GodotClass> Gd<T> {
             #[cfg(before_api = "4.4")]
             let object_ptr = callbacks::create::<T>(std::ptr::null_mut());
 
-            Gd::from_obj_sys(object_ptr)
+            let obj = Gd::from_obj_sys(object_ptr);
+
+            #[cfg(safeguards_strict)]
+            crate::obj::tracking::track_created(&obj);
+
+            obj
         }
     }
 
@@ -906,6 +924,22 @@ where
     }
 }
 
+#[cfg(feature = "codegen-full")]
+impl<T> Gd<T>
+where
+    T: crate::obj::WithUserRpcs,
+{
+    /// Access typed remote procedure calls of this object.
+    ///
+    /// Returns a collection with one method per `#[rpc]`, see [`WithUserRpcs::rpcs()`]. When you are within the `impl` of a class, use
+    /// `self.rpcs()` directly instead.
+    ///
+    /// [`WithUserRpcs::rpcs()`]: crate::obj::WithUserRpcs::rpcs()
+    pub fn rpcs(&self) -> T::RpcCollection<'_> {
+        T::__rpcs_from_external(self)
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Trait impls
 
@@ -22,6 +22,7 @@ mod on_ready;
 mod passive_gd;
 mod raw_gd;
 mod traits;
+mod weak_gd;
 
 pub(crate) mod rtti;
 
@@ -35,9 +36,13 @@ pub use on_ready::*;
 pub(crate) use passive_gd::PassiveGd;
 pub use raw_gd::*;
 pub use traits::*;
+pub use weak_gd::WeakGd;
 
 pub mod bounds;
 pub mod script;
+pub mod script_language;
+#[cfg(safeguards_strict)]
+pub mod tracking;
 pub use bounds::private::Bounds;
 
 // Do not re-export rtti here.
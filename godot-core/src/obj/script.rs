@@ -11,6 +11,9 @@
 //! the [`IScriptExtension` trait][crate::classes::IScriptExtension].
 //!
 //! See [`ScriptInstance`](trait.ScriptInstance.html) for usage.
+//!
+//! To implement a whole scripting language without writing every virtual method by hand, see the higher-level
+//! [`script_language`][crate::obj::script_language] module.
 
 use std::ffi::c_void;
 use std::ops::{Deref, DerefMut};
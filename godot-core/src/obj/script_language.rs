@@ -0,0 +1,1105 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! High-level framework for custom scripting languages.
+//!
+//! Implementing a scripting language from scratch requires a [`ScriptLanguageExtension`] and a [`ScriptExtension`] class with dozens of
+//! virtual methods each, a resource loader and saver for script files, and wiring it all up with the engine. This module reduces that to:
+//!
+//! 1. Two user classes: one for the language and one for scripts.
+//! 2. An implementation of [`CustomScriptLanguage`] and [`CustomScript`] for them. Only a handful of methods are required, the rest has
+//!    sensible defaults.
+//! 3. An implementation of [`ScriptInstance`] for the per-object state of your interpreter.
+//! 4. One invocation of [`script_language!`], which implements the engine interfaces and generates the resource loader/saver.
+//! 5. [`register_script_language()`] and [`unregister_script_language()`] calls during extension initialization and shutdown.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use godot::classes::{Object, Script, ScriptExtension, ScriptLanguageExtension};
+//! use godot::obj::script::ScriptInstance;
+//! use godot::obj::script_language::{script_language, CustomScript, CustomScriptLanguage, ScriptDiagnostic};
+//! use godot::prelude::*;
+//!
+//! #[derive(GodotClass)]
+//! #[class(init, base=ScriptLanguageExtension, tool)]
+//! struct ToyLanguage {
+//!     base: Base<ScriptLanguageExtension>,
+//! }
+//!
+//! impl CustomScriptLanguage for ToyLanguage {
+//!     type Script = ToyScript;
+//!
+//!     fn name(&self) -> GString {
+//!         "Toy".into()
+//!     }
+//!
+//!     fn file_extension(&self) -> GString {
+//!         "toy".into()
+//!     }
+//!
+//!     fn create_script(&self) -> Gd<ToyScript> {
+//!         let language = self.to_gd();
+//!         Gd::from_init_fn(|base| ToyScript { base, language, source: GString::new() })
+//!     }
+//! }
+//!
+//! #[derive(GodotClass)]
+//! #[class(no_init, base=ScriptExtension, tool)]
+//! struct ToyScript {
+//!     base: Base<ScriptExtension>,
+//!     language: Gd<ToyLanguage>,
+//!     source: GString,
+//! }
+//!
+//! impl CustomScript for ToyScript {
+//!     type Language = ToyLanguage;
+//!     type Instance = ToyInstance;
+//!
+//!     fn language(&self) -> Gd<ToyLanguage> {
+//!         self.language.clone()
+//!     }
+//!
+//!     fn source_code(&self) -> GString {
+//!         self.source.clone()
+//!     }
+//!
+//!     fn set_source_code(&mut self, code: GString) {
+//!         self.source = code;
+//!     }
+//!
+//!     fn reload(&mut self, _keep_state: bool) -> Result<(), ScriptDiagnostic> {
+//!         // Parse/compile `self.source` here.
+//!         Ok(())
+//!     }
+//!
+//!     fn instantiate(&self, _for_object: &Gd<Object>) -> ToyInstance {
+//!         # unimplemented!()
+//!         // ToyInstance::new(self.to_gd().upcast(), ...)
+//!     }
+//! }
+//!
+//! struct ToyInstance { /* interpreter state */ }
+//! # impl ScriptInstance for ToyInstance {
+//! #     type Base = Object;
+//! #     fn class_name(&self) -> GString { unimplemented!() }
+//! #     fn set_property(this: godot::obj::script::SiMut<Self>, name: StringName, value: &Variant) -> bool { unimplemented!() }
+//! #     fn get_property(&self, name: StringName) -> Option<Variant> { unimplemented!() }
+//! #     fn get_property_list(&self) -> Vec<godot::meta::PropertyInfo> { unimplemented!() }
+//! #     fn get_method_list(&self) -> Vec<godot::meta::MethodInfo> { unimplemented!() }
+//! #     fn call(this: godot::obj::script::SiMut<Self>, method: StringName, args: &[&Variant]) -> Result<Variant, godot::meta::error::CallErrorType> { unimplemented!() }
+//! #     fn is_placeholder(&self) -> bool { unimplemented!() }
+//! #     fn has_method(&self, method: StringName) -> bool { unimplemented!() }
+//! #     fn get_script(&self) -> &Gd<Script> { unimplemented!() }
+//! #     fn get_property_type(&self, name: StringName) -> VariantType { unimplemented!() }
+//! #     fn to_string(&self) -> GString { unimplemented!() }
+//! #     fn get_property_state(&self) -> Vec<(StringName, Variant)> { unimplemented!() }
+//! #     fn get_language(&self) -> Gd<godot::classes::ScriptLanguage> { unimplemented!() }
+//! #     fn on_refcount_decremented(&self) -> bool { unimplemented!() }
+//! #     fn on_refcount_incremented(&self) { unimplemented!() }
+//! #     fn property_get_fallback(&self, name: StringName) -> Option<Variant> { unimplemented!() }
+//! #     fn property_set_fallback(this: godot::obj::script::SiMut<Self>, name: StringName, value: &Variant) -> bool { unimplemented!() }
+//! #     fn get_method_argument_count(&self, _method: StringName) -> Option<u32> { unimplemented!() }
+//! # }
+//!
+//! // Implements the engine interfaces and generates the `ToyLoader` and `ToySaver` classes.
+//! script_language! {
+//!     language: ToyLanguage,
+//!     script: ToyScript,
+//!     loader: ToyLoader,
+//!     saver: ToySaver,
+//! }
+//! ```
+//!
+//! Then, in [`ExtensionLibrary::on_level_init()`][crate::init::ExtensionLibrary::on_level_init] for [`InitLevel::Scene`][crate::init::InitLevel::Scene],
+//! call `register_script_language(ToyLanguage::new_alloc())`; and `unregister_script_language::<ToyLanguage>()` in `on_level_deinit()`.
+//!
+//! Features not covered by the traits (debugging, profiling, code completion, documentation) are reported as unsupported to the engine.
+//!
+//! [`ScriptInstance`]: crate::obj::script::ScriptInstance
+
+use std::any::TypeId;
+use std::ffi::c_void;
+use std::fmt;
+
+#[doc(hidden)]
+pub use crate::__script_language_interfaces;
+use crate::builtin::{vdict, Array, Dictionary, GString, PackedStringArray, StringName, Variant};
+use crate::classes::file_access::ModeFlags;
+use crate::classes::{
+    Engine, FileAccess, Object, Resource, ResourceFormatLoader, ResourceFormatSaver,
+    ResourceLoader, ResourceSaver, Script, ScriptExtension, ScriptLanguage,
+    ScriptLanguageExtension,
+};
+use crate::global::Error;
+use crate::meta::{MethodInfo, PropertyInfo, ToGodot};
+use crate::obj::script::ScriptInstance;
+use crate::obj::{Gd, GodotClass, Inherits, InstanceId, Singleton, WithBaseField};
+pub use crate::script_language;
+use crate::sys;
+use crate::sys::GodotFfi as _;
+
+/// Describes a custom scripting language.
+///
+/// Implemented for the user class inheriting [`ScriptLanguageExtension`]; see the [module docs](self) for the full picture.
+///
+/// Only [`name()`][Self::name], [`file_extension()`][Self::file_extension] and [`create_script()`][Self::create_script] are required.
+pub trait CustomScriptLanguage:
+    GodotClass<Base = ScriptLanguageExtension> + WithBaseField + Inherits<ScriptLanguage>
+{
+    /// The script class of this language.
+    type Script: CustomScript<Language = Self>;
+
+    /// Human-readable name of the language, e.g. `"Lua"`.
+    fn name(&self) -> GString;
+
+    /// File extension of scripts in this language, without dot, e.g. `"lua"`.
+    fn file_extension(&self) -> GString;
+
+    /// Creates a new, empty script of this language.
+    fn create_script(&self) -> Gd<Self::Script>;
+
+    /// Resource type of the scripts, as shown in the editor.
+    ///
+    /// Defaults to the class name of [`Self::Script`].
+    fn script_type(&self) -> GString {
+        Self::Script::class_id().to_gstring()
+    }
+
+    /// Keywords of the language, used for syntax highlighting.
+    fn reserved_words(&self) -> PackedStringArray {
+        PackedStringArray::new()
+    }
+
+    /// Whether `keyword` is a control-flow keyword (`if`, `return`, ...), which the editor highlights differently.
+    fn is_control_flow_keyword(&self, _keyword: &GString) -> bool {
+        false
+    }
+
+    /// Comment delimiters, e.g. `"#"` for line comments or `"/* */"` for block comments.
+    fn comment_delimiters(&self) -> PackedStringArray {
+        PackedStringArray::new()
+    }
+
+    /// String delimiters, as pairs separated by a space.
+    ///
+    /// Defaults to `"\" \""` and `"' '"`.
+    fn string_delimiters(&self) -> PackedStringArray {
+        ["\" \"", "' '"].into_iter().map(GString::from).collect()
+    }
+
+    /// Checks `source` for errors, without loading it.
+    ///
+    /// Used by the editor to report errors while typing. The default accepts every source.
+    fn validate(&self, _source: &GString, _path: &GString) -> Result<(), Vec<ScriptDiagnostic>> {
+        Ok(())
+    }
+
+    /// Source code for a new script, when created through the editor's "Attach Script" dialog.
+    fn template_source(&self, _class_name: &GString, _base_class_name: &GString) -> GString {
+        GString::new()
+    }
+
+    /// Called when the engine initializes the language, after registration.
+    fn on_init(&mut self) {}
+
+    /// Called when the engine shuts down the language.
+    fn on_finish(&mut self) {}
+
+    /// Called once per frame, e.g. for garbage collection in the interpreter.
+    fn on_frame(&mut self) {}
+}
+
+/// Describes a script of a [`CustomScriptLanguage`].
+///
+/// Implemented for the user class inheriting [`ScriptExtension`]. A script holds the source code and everything derived from it (compiled
+/// form, members), while per-object state lives in the [`Self::Instance`] script instances.
+///
+/// Methods returning class members ([`methods()`][Self::methods], [`properties()`][Self::properties], etc.) are used by the editor and
+/// by placeholder instances. The defaults declare no members.
+pub trait CustomScript:
+    GodotClass<Base = ScriptExtension> + WithBaseField + Inherits<Script>
+{
+    /// The language of this script.
+    type Language: CustomScriptLanguage<Script = Self>;
+
+    /// State of the script, when attached to an object.
+    type Instance: ScriptInstance<Base = Object>;
+
+    /// Returns the language object (the one registered with the engine).
+    fn language(&self) -> Gd<Self::Language>;
+
+    /// The current source code.
+    fn source_code(&self) -> GString;
+
+    /// Replaces the source code. Compilation happens in [`reload()`][Self::reload].
+    fn set_source_code(&mut self, code: GString);
+
+    /// Creates the script instance for an object that this script is attached to.
+    fn instantiate(&self, for_object: &Gd<Object>) -> Self::Instance;
+
+    /// (Re-)compiles the source code.
+    ///
+    /// Called after loading and whenever the engine requests a reload. `keep_state` indicates that existing instances should keep their
+    /// state. Errors are printed by the engine, and the script is reported as failed to load.
+    fn reload(&mut self, _keep_state: bool) -> Result<(), ScriptDiagnostic> {
+        Ok(())
+    }
+
+    /// Whether the script compiled successfully and can be instantiated.
+    fn is_valid(&self) -> bool {
+        true
+    }
+
+    /// Whether the script runs in the editor. Otherwise, objects in the editor get a placeholder instance.
+    fn is_tool(&self) -> bool {
+        false
+    }
+
+    /// The native class that objects with this script must inherit.
+    fn instance_base_type(&self) -> StringName {
+        Object::class_id().to_string_name()
+    }
+
+    /// Global class name, if the script registers one (like `class_name` in GDScript).
+    fn global_name(&self) -> StringName {
+        StringName::default()
+    }
+
+    /// Methods declared by the script.
+    fn methods(&self) -> Vec<MethodInfo> {
+        vec![]
+    }
+
+    /// Properties declared by the script.
+    fn properties(&self) -> Vec<PropertyInfo> {
+        vec![]
+    }
+
+    /// Default value of a property, used for placeholder instances and to revert properties in the inspector.
+    fn property_default(&self, _name: &StringName) -> Option<Variant> {
+        None
+    }
+
+    /// Signals declared by the script.
+    fn signals(&self) -> Vec<MethodInfo> {
+        vec![]
+    }
+
+    /// Constants declared by the script.
+    fn constants(&self) -> Vec<(StringName, Variant)> {
+        vec![]
+    }
+}
+
+/// Connects a [`CustomScriptLanguage`] with its generated resource loader and saver.
+///
+/// Implemented by [`script_language!`]; you don't need to implement this yourself.
+pub trait ScriptLanguageFormats: CustomScriptLanguage {
+    /// Creates loader and saver for script files of `language`.
+    fn create_resource_formats(
+        language: &Gd<Self>,
+    ) -> (Gd<ResourceFormatLoader>, Gd<ResourceFormatSaver>);
+}
+
+/// Error or warning in a script's source code.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScriptDiagnostic {
+    /// 1-based line number; 0 if unknown.
+    pub line: i32,
+
+    /// 1-based column number; 0 if unknown.
+    pub column: i32,
+
+    pub message: String,
+}
+
+impl ScriptDiagnostic {
+    /// Creates a diagnostic at the given position.
+    pub fn new(line: i32, column: i32, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    fn to_dictionary(&self) -> Dictionary {
+        vdict! {
+            "line": self.line,
+            "column": self.column,
+            "message": self.message.as_str(),
+        }
+    }
+}
+
+impl fmt::Display for ScriptDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ScriptDiagnostic {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Registration
+
+struct RegisteredLanguage {
+    type_id: TypeId,
+    language: InstanceId,
+    loader: InstanceId,
+    saver: InstanceId,
+}
+
+static REGISTERED_LANGUAGES: sys::Global<Vec<RegisteredLanguage>> = sys::Global::default();
+
+/// Registers a script language with the engine, together with its resource loader and saver.
+///
+/// Should be called during [`InitLevel::Scene`][crate::init::InitLevel::Scene] initialization. The engine does not take ownership of
+/// `language`; it is freed in [`unregister_script_language()`].
+///
+/// # Panics
+/// If the engine rejects the language, e.g. because one with the same name is already registered.
+pub fn register_script_language<L: ScriptLanguageFormats>(language: Gd<L>) {
+    let result = Engine::singleton().register_script_language(&language);
+    assert_eq!(
+        result,
+        Error::OK,
+        "failed to register script language {}",
+        L::class_id()
+    );
+
+    let (loader, saver) = L::create_resource_formats(&language);
+    ResourceLoader::singleton().add_resource_format_loader(&loader);
+    ResourceSaver::singleton().add_resource_format_saver(&saver);
+
+    REGISTERED_LANGUAGES.lock().push(RegisteredLanguage {
+        type_id: TypeId::of::<L>(),
+        language: language.instance_id(),
+        loader: loader.instance_id(),
+        saver: saver.instance_id(),
+    });
+}
+
+/// Unregisters a script language previously registered with [`register_script_language()`], and frees the language object.
+///
+/// Does nothing if the language is not registered.
+pub fn unregister_script_language<L: ScriptLanguageFormats>() {
+    let registered = {
+        let mut languages = REGISTERED_LANGUAGES.lock();
+        let Some(index) = languages
+            .iter()
+            .position(|entry| entry.type_id == TypeId::of::<L>())
+        else {
+            return;
+        };
+
+        languages.remove(index)
+    };
+
+    if let Ok(loader) = Gd::<ResourceFormatLoader>::try_from_instance_id(registered.loader) {
+        ResourceLoader::singleton().remove_resource_format_loader(&loader);
+    }
+
+    if let Ok(saver) = Gd::<ResourceFormatSaver>::try_from_instance_id(registered.saver) {
+        ResourceSaver::singleton().remove_resource_format_saver(&saver);
+    }
+
+    if let Ok(language) = Gd::<ScriptLanguage>::try_from_instance_id(registered.language) {
+        Engine::singleton().unregister_script_language(&language);
+        language.free();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of generated code
+
+#[doc(hidden)]
+pub mod __private {
+    use super::*;
+
+    pub fn member_list(members: Vec<MethodInfo>) -> Array<Dictionary> {
+        members.iter().map(MethodInfo::to_dictionary).collect()
+    }
+
+    pub fn property_list(properties: Vec<PropertyInfo>) -> Array<Dictionary> {
+        properties.iter().map(PropertyInfo::to_dictionary).collect()
+    }
+
+    pub fn find_member(members: Vec<MethodInfo>, name: &StringName) -> Option<MethodInfo> {
+        members.into_iter().find(|m| &m.method_name == name)
+    }
+
+    pub fn constants<S: CustomScript>(script: &S) -> Dictionary {
+        let mut dict = Dictionary::new();
+        for (name, value) in script.constants() {
+            dict.set(name, value);
+        }
+        dict
+    }
+
+    pub fn can_instantiate<S: CustomScript>(script: &S) -> bool {
+        script.is_valid() && (script.is_tool() || !Engine::singleton().is_editor_hint())
+    }
+
+    pub fn reload<S: CustomScript>(script: &mut S, keep_state: bool) -> Error {
+        match script.reload(keep_state) {
+            Ok(()) => Error::OK,
+            Err(diagnostic) => {
+                crate::godot_error!("{}: {diagnostic}", script.base().get_path());
+                Error::ERR_PARSE_ERROR
+            }
+        }
+    }
+
+    pub fn validate<L: CustomScriptLanguage>(
+        language: &L,
+        source: &GString,
+        path: &GString,
+    ) -> Dictionary {
+        match language.validate(source, path) {
+            Ok(()) => vdict! { "valid": true },
+            Err(diagnostics) => {
+                let errors: Array<Dictionary> = diagnostics
+                    .iter()
+                    .map(ScriptDiagnostic::to_dictionary)
+                    .collect();
+
+                vdict! { "valid": false, "errors": errors }
+            }
+        }
+    }
+
+    pub fn make_template<L: CustomScriptLanguage>(
+        language: &L,
+        class_name: &GString,
+        base_class_name: &GString,
+    ) -> Gd<Script> {
+        let mut script = language.create_script();
+        let source = language.template_source(class_name, base_class_name);
+        script.bind_mut().set_source_code(source);
+        script.upcast()
+    }
+
+    /// Result of `complete_code` and `lookup_code`, for languages without such support.
+    pub fn unavailable() -> Dictionary {
+        vdict! { "result": Error::ERR_UNAVAILABLE }
+    }
+
+    pub fn placeholder_instance_create<S: CustomScript>(
+        script: &S,
+        for_object: Gd<Object>,
+    ) -> *mut c_void {
+        let language = script.language();
+        let script_gd = script.to_gd();
+
+        let properties = script.properties();
+        let values: Dictionary = properties
+            .iter()
+            .filter_map(|property| {
+                let name = &property.property_name;
+                script
+                    .property_default(name)
+                    .map(|v| (name.to_variant(), v))
+            })
+            .collect();
+        let properties = property_list(properties);
+
+        // SAFETY: Language, script and owner are alive; the engine takes ownership of the returned placeholder instance.
+        // Properties and values are only read during the update call.
+        unsafe {
+            let placeholder = sys::interface_fn!(placeholder_script_instance_create)(
+                language.obj_sys(),
+                script_gd.obj_sys(),
+                for_object.obj_sys(),
+            );
+
+            sys::interface_fn!(placeholder_script_instance_update)(
+                placeholder,
+                properties.sys(),
+                values.sys(),
+            );
+
+            placeholder as *mut c_void
+        }
+    }
+
+    // ------------------------------------------------------------------------------------------------------------------------------------------
+    // Resource loader and saver
+
+    fn has_extension(path: &GString, extension: &GString) -> bool {
+        let path = path.to_string();
+        path.rsplit_once('.')
+            .is_some_and(|(_, ext)| ext.eq_ignore_ascii_case(&extension.to_string()))
+    }
+
+    pub fn recognized_extensions<L: CustomScriptLanguage>(language: &Gd<L>) -> PackedStringArray {
+        [language.bind().file_extension()].into_iter().collect()
+    }
+
+    pub fn handles_type<L: CustomScriptLanguage>(language: &Gd<L>, type_: &StringName) -> bool {
+        *type_ == Script::class_id().to_string_name()
+            || type_.to_string() == language.bind().script_type().to_string()
+    }
+
+    pub fn resource_type<L: CustomScriptLanguage>(language: &Gd<L>, path: &GString) -> GString {
+        let language = language.bind();
+        if has_extension(path, &language.file_extension()) {
+            language.script_type()
+        } else {
+            GString::new()
+        }
+    }
+
+    pub fn load<L: CustomScriptLanguage>(language: &Gd<L>, path: &GString) -> Variant {
+        if !FileAccess::file_exists(path) {
+            return Error::ERR_FILE_NOT_FOUND.to_variant();
+        }
+
+        let source = FileAccess::get_file_as_string(path);
+        let open_error = FileAccess::get_open_error();
+        if open_error != Error::OK {
+            return open_error.to_variant();
+        }
+
+        let mut script = language.bind().create_script();
+        {
+            let mut script = script.bind_mut();
+            script.set_source_code(source);
+
+            if let Err(diagnostic) = script.reload(false) {
+                crate::godot_error!("{path}:{diagnostic}");
+                return Error::ERR_PARSE_ERROR.to_variant();
+            }
+        }
+
+        script.to_variant()
+    }
+
+    fn cast_script<S: CustomScript>(resource: Option<Gd<Resource>>) -> Option<Gd<S>> {
+        resource?.try_cast::<Script>().ok()?.try_cast::<S>().ok()
+    }
+
+    pub fn recognize<S: CustomScript>(resource: Option<Gd<Resource>>) -> bool {
+        cast_script::<S>(resource).is_some()
+    }
+
+    pub fn saver_extensions<S: CustomScript>(resource: Option<Gd<Resource>>) -> PackedStringArray {
+        match cast_script::<S>(resource) {
+            Some(script) => recognized_extensions(&script.bind().language()),
+            None => PackedStringArray::new(),
+        }
+    }
+
+    pub fn save<S: CustomScript>(resource: Option<Gd<Resource>>, path: &GString) -> Error {
+        let Some(script) = cast_script::<S>(resource) else {
+            return Error::ERR_INVALID_PARAMETER;
+        };
+
+        let Some(mut file) = FileAccess::open(path, ModeFlags::WRITE) else {
+            return FileAccess::get_open_error();
+        };
+
+        file.store_string(&script.bind().source_code());
+        file.close();
+
+        Error::OK
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Macros
+
+/// Implements the engine interfaces for a [`CustomScriptLanguage`] and its [`CustomScript`], and generates a resource loader and saver.
+///
+/// The language and script classes must already be declared with `#[derive(GodotClass)]`; the loader and saver are new classes with the
+/// given names. See the [module docs](crate::obj::script_language) for a complete example.
+///
+/// ```ignore
+/// script_language! {
+///     language: MyLanguage,
+///     script: MyScript,
+///     loader: MyScriptLoader,
+///     saver: MyScriptSaver,
+/// }
+/// ```
+///
+/// This implements `IScriptLanguageExtension` and `IScriptExtension`, so the classes cannot have their own `#[godot_api]` interface impls.
+/// Inherent `#[godot_api]` blocks (for `#[func]` etc.) are still possible.
+#[macro_export]
+macro_rules! script_language {
+    (
+        language: $Language:ident,
+        script: $Script:ident,
+        loader: $Loader:ident,
+        saver: $Saver:ident $(,)?
+    ) => {
+        ::godot::obj::script_language::__script_language_interfaces! {
+            $Language, $Script;
+
+            // IScriptLanguageExtension
+            {
+                fn get_name(&self) -> ::godot::builtin::GString {
+                    ::godot::obj::script_language::CustomScriptLanguage::name(self)
+                }
+                fn init_ext(&mut self) {
+                    ::godot::obj::script_language::CustomScriptLanguage::on_init(self)
+                }
+                fn get_type(&self) -> ::godot::builtin::GString {
+                    ::godot::obj::script_language::CustomScriptLanguage::script_type(self)
+                }
+                fn get_extension(&self) -> ::godot::builtin::GString {
+                    ::godot::obj::script_language::CustomScriptLanguage::file_extension(self)
+                }
+                fn finish(&mut self) {
+                    ::godot::obj::script_language::CustomScriptLanguage::on_finish(self)
+                }
+                fn get_reserved_words(&self) -> ::godot::builtin::PackedStringArray {
+                    ::godot::obj::script_language::CustomScriptLanguage::reserved_words(self)
+                }
+                fn is_control_flow_keyword(&self, keyword: ::godot::builtin::GString) -> bool {
+                    ::godot::obj::script_language::CustomScriptLanguage::is_control_flow_keyword(self, &keyword)
+                }
+                fn get_comment_delimiters(&self) -> ::godot::builtin::PackedStringArray {
+                    ::godot::obj::script_language::CustomScriptLanguage::comment_delimiters(self)
+                }
+                fn get_string_delimiters(&self) -> ::godot::builtin::PackedStringArray {
+                    ::godot::obj::script_language::CustomScriptLanguage::string_delimiters(self)
+                }
+                fn make_template(
+                    &self,
+                    _template: ::godot::builtin::GString,
+                    class_name: ::godot::builtin::GString,
+                    base_class_name: ::godot::builtin::GString,
+                ) -> Option<::godot::obj::Gd<::godot::classes::Script>> {
+                    Some(::godot::obj::script_language::__private::make_template(self, &class_name, &base_class_name))
+                }
+                fn get_built_in_templates(&self, _object: ::godot::builtin::StringName) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                    ::godot::builtin::Array::new()
+                }
+                fn is_using_templates(&mut self) -> bool {
+                    false
+                }
+                fn validate(
+                    &self,
+                    script: ::godot::builtin::GString,
+                    path: ::godot::builtin::GString,
+                    _validate_functions: bool,
+                    _validate_errors: bool,
+                    _validate_warnings: bool,
+                    _validate_safe_lines: bool,
+                ) -> ::godot::builtin::Dictionary {
+                    ::godot::obj::script_language::__private::validate(self, &script, &path)
+                }
+                fn validate_path(&self, _path: ::godot::builtin::GString) -> ::godot::builtin::GString {
+                    ::godot::builtin::GString::new()
+                }
+                fn create_script(&self) -> Option<::godot::obj::Gd<::godot::classes::Object>> {
+                    Some(::godot::obj::script_language::CustomScriptLanguage::create_script(self).upcast())
+                }
+                fn has_named_classes(&self) -> bool {
+                    false
+                }
+                fn supports_builtin_mode(&self) -> bool {
+                    false
+                }
+                fn supports_documentation(&self) -> bool {
+                    false
+                }
+                fn can_inherit_from_file(&self) -> bool {
+                    false
+                }
+                fn find_function(&self, _class_name: ::godot::builtin::GString, _function_name: ::godot::builtin::GString) -> i32 {
+                    -1
+                }
+                fn make_function(
+                    &self,
+                    _class_name: ::godot::builtin::GString,
+                    _function_name: ::godot::builtin::GString,
+                    _function_args: ::godot::builtin::PackedStringArray,
+                ) -> ::godot::builtin::GString {
+                    ::godot::builtin::GString::new()
+                }
+                fn open_in_external_editor(
+                    &mut self,
+                    _script: Option<::godot::obj::Gd<::godot::classes::Script>>,
+                    _line: i32,
+                    _column: i32,
+                ) -> ::godot::global::Error {
+                    ::godot::global::Error::ERR_UNAVAILABLE
+                }
+                fn overrides_external_editor(&mut self) -> bool {
+                    false
+                }
+                fn complete_code(
+                    &self,
+                    _code: ::godot::builtin::GString,
+                    _path: ::godot::builtin::GString,
+                    _owner: Option<::godot::obj::Gd<::godot::classes::Object>>,
+                ) -> ::godot::builtin::Dictionary {
+                    ::godot::obj::script_language::__private::unavailable()
+                }
+                fn lookup_code(
+                    &self,
+                    _code: ::godot::builtin::GString,
+                    _symbol: ::godot::builtin::GString,
+                    _path: ::godot::builtin::GString,
+                    _owner: Option<::godot::obj::Gd<::godot::classes::Object>>,
+                ) -> ::godot::builtin::Dictionary {
+                    ::godot::obj::script_language::__private::unavailable()
+                }
+                fn auto_indent_code(&self, code: ::godot::builtin::GString, _from_line: i32, _to_line: i32) -> ::godot::builtin::GString {
+                    code
+                }
+                fn add_global_constant(&mut self, _name: ::godot::builtin::StringName, _value: ::godot::builtin::Variant) {}
+                fn add_named_global_constant(&mut self, _name: ::godot::builtin::StringName, _value: ::godot::builtin::Variant) {}
+                fn remove_named_global_constant(&mut self, _name: ::godot::builtin::StringName) {}
+                fn thread_enter(&mut self) {}
+                fn thread_exit(&mut self) {}
+                fn debug_get_error(&self) -> ::godot::builtin::GString {
+                    ::godot::builtin::GString::new()
+                }
+                fn debug_get_stack_level_count(&self) -> i32 {
+                    0
+                }
+                fn debug_get_stack_level_line(&self, _level: i32) -> i32 {
+                    -1
+                }
+                fn debug_get_stack_level_function(&self, _level: i32) -> ::godot::builtin::GString {
+                    ::godot::builtin::GString::new()
+                }
+                fn debug_get_stack_level_locals(&mut self, _level: i32, _max_subitems: i32, _max_depth: i32) -> ::godot::builtin::Dictionary {
+                    ::godot::builtin::Dictionary::new()
+                }
+                fn debug_get_stack_level_members(&mut self, _level: i32, _max_subitems: i32, _max_depth: i32) -> ::godot::builtin::Dictionary {
+                    ::godot::builtin::Dictionary::new()
+                }
+                unsafe fn debug_get_stack_level_instance_rawptr(&mut self, _level: i32) -> *mut ::std::ffi::c_void {
+                    ::std::ptr::null_mut()
+                }
+                fn debug_get_globals(&mut self, _max_subitems: i32, _max_depth: i32) -> ::godot::builtin::Dictionary {
+                    ::godot::builtin::Dictionary::new()
+                }
+                fn debug_parse_stack_level_expression(
+                    &mut self,
+                    _level: i32,
+                    _expression: ::godot::builtin::GString,
+                    _max_subitems: i32,
+                    _max_depth: i32,
+                ) -> ::godot::builtin::GString {
+                    ::godot::builtin::GString::new()
+                }
+                fn debug_get_current_stack_info(&mut self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                    ::godot::builtin::Array::new()
+                }
+                fn reload_all_scripts(&mut self) {}
+                fn reload_tool_script(&mut self, _script: Option<::godot::obj::Gd<::godot::classes::Script>>, _soft_reload: bool) {}
+                fn get_recognized_extensions(&self) -> ::godot::builtin::PackedStringArray {
+                    ::godot::obj::script_language::__private::recognized_extensions(&::godot::obj::WithBaseField::to_gd(self))
+                }
+                fn get_public_functions(&self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                    ::godot::builtin::Array::new()
+                }
+                fn get_public_constants(&self) -> ::godot::builtin::Dictionary {
+                    ::godot::builtin::Dictionary::new()
+                }
+                fn get_public_annotations(&self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                    ::godot::builtin::Array::new()
+                }
+                fn profiling_start(&mut self) {}
+                fn profiling_stop(&mut self) {}
+                unsafe fn profiling_get_accumulated_data_rawptr(
+                    &mut self,
+                    _info_array: *mut ::godot::classes::native::ScriptLanguageExtensionProfilingInfo,
+                    _info_max: i32,
+                ) -> i32 {
+                    0
+                }
+                unsafe fn profiling_get_frame_data_rawptr(
+                    &mut self,
+                    _info_array: *mut ::godot::classes::native::ScriptLanguageExtensionProfilingInfo,
+                    _info_max: i32,
+                ) -> i32 {
+                    0
+                }
+                fn frame(&mut self) {
+                    ::godot::obj::script_language::CustomScriptLanguage::on_frame(self)
+                }
+                fn handles_global_class_type(&self, _type_: ::godot::builtin::GString) -> bool {
+                    false
+                }
+                fn get_global_class_name(&self, _path: ::godot::builtin::GString) -> ::godot::builtin::Dictionary {
+                    ::godot::builtin::Dictionary::new()
+                }
+            }
+
+            // IScriptExtension
+            {
+                fn editor_can_reload_from_file(&mut self) -> bool {
+                    true
+                }
+                fn can_instantiate(&self) -> bool {
+                    ::godot::obj::script_language::__private::can_instantiate(self)
+                }
+                fn get_base_script(&self) -> Option<::godot::obj::Gd<::godot::classes::Script>> {
+                    None
+                }
+                fn get_global_name(&self) -> ::godot::builtin::StringName {
+                    ::godot::obj::script_language::CustomScript::global_name(self)
+                }
+                fn inherits_script(&self, script: ::godot::obj::Gd<::godot::classes::Script>) -> bool {
+                    script.instance_id() == ::godot::obj::WithBaseField::to_gd(self).instance_id()
+                }
+                fn get_instance_base_type(&self) -> ::godot::builtin::StringName {
+                    ::godot::obj::script_language::CustomScript::instance_base_type(self)
+                }
+                unsafe fn instance_create_rawptr(&self, for_object: ::godot::obj::Gd<::godot::classes::Object>) -> *mut ::std::ffi::c_void {
+                    let instance = ::godot::obj::script_language::CustomScript::instantiate(self, &for_object);
+                    ::godot::obj::script::create_script_instance(instance, for_object)
+                }
+                unsafe fn placeholder_instance_create_rawptr(
+                    &self,
+                    for_object: ::godot::obj::Gd<::godot::classes::Object>,
+                ) -> *mut ::std::ffi::c_void {
+                    ::godot::obj::script_language::__private::placeholder_instance_create(self, for_object)
+                }
+                fn instance_has(&self, object: ::godot::obj::Gd<::godot::classes::Object>) -> bool {
+                    ::godot::obj::script::script_instance_exists(&object, &::godot::obj::WithBaseField::to_gd(self))
+                }
+                fn has_source_code(&self) -> bool {
+                    !::godot::obj::script_language::CustomScript::source_code(self).is_empty()
+                }
+                fn get_source_code(&self) -> ::godot::builtin::GString {
+                    ::godot::obj::script_language::CustomScript::source_code(self)
+                }
+                fn set_source_code(&mut self, code: ::godot::builtin::GString) {
+                    ::godot::obj::script_language::CustomScript::set_source_code(self, code)
+                }
+                fn reload(&mut self, keep_state: bool) -> ::godot::global::Error {
+                    ::godot::obj::script_language::__private::reload(self, keep_state)
+                }
+                fn get_documentation(&self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                    ::godot::builtin::Array::new()
+                }
+                fn has_method(&self, method: ::godot::builtin::StringName) -> bool {
+                    let methods = ::godot::obj::script_language::CustomScript::methods(self);
+                    ::godot::obj::script_language::__private::find_member(methods, &method).is_some()
+                }
+                fn has_static_method(&self, _method: ::godot::builtin::StringName) -> bool {
+                    false
+                }
+                fn get_method_info(&self, method: ::godot::builtin::StringName) -> ::godot::builtin::Dictionary {
+                    let methods = ::godot::obj::script_language::CustomScript::methods(self);
+                    ::godot::obj::script_language::__private::find_member(methods, &method)
+                        .map(|info| info.to_dictionary())
+                        .unwrap_or_default()
+                }
+                fn is_tool(&self) -> bool {
+                    ::godot::obj::script_language::CustomScript::is_tool(self)
+                }
+                fn is_valid(&self) -> bool {
+                    ::godot::obj::script_language::CustomScript::is_valid(self)
+                }
+                fn has_script_signal(&self, signal: ::godot::builtin::StringName) -> bool {
+                    let signals = ::godot::obj::script_language::CustomScript::signals(self);
+                    ::godot::obj::script_language::__private::find_member(signals, &signal).is_some()
+                }
+                fn get_script_signal_list(&self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                    ::godot::obj::script_language::__private::member_list(::godot::obj::script_language::CustomScript::signals(self))
+                }
+                fn has_property_default_value(&self, property: ::godot::builtin::StringName) -> bool {
+                    ::godot::obj::script_language::CustomScript::property_default(self, &property).is_some()
+                }
+                fn get_property_default_value(&self, property: ::godot::builtin::StringName) -> ::godot::builtin::Variant {
+                    ::godot::obj::script_language::CustomScript::property_default(self, &property).unwrap_or_default()
+                }
+                fn update_exports(&mut self) {}
+                fn get_script_method_list(&self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                    ::godot::obj::script_language::__private::member_list(::godot::obj::script_language::CustomScript::methods(self))
+                }
+                fn get_script_property_list(&self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                    ::godot::obj::script_language::__private::property_list(::godot::obj::script_language::CustomScript::properties(self))
+                }
+                fn get_member_line(&self, _member: ::godot::builtin::StringName) -> i32 {
+                    -1
+                }
+                fn get_constants(&self) -> ::godot::builtin::Dictionary {
+                    ::godot::obj::script_language::__private::constants(self)
+                }
+                fn get_members(&self) -> ::godot::builtin::Array<::godot::builtin::StringName> {
+                    ::godot::obj::script_language::CustomScript::properties(self)
+                        .into_iter()
+                        .map(|property| property.property_name)
+                        .collect()
+                }
+                fn is_placeholder_fallback_enabled(&self) -> bool {
+                    false
+                }
+                fn get_rpc_config(&self) -> ::godot::builtin::Variant {
+                    ::godot::meta::ToGodot::to_variant(&::godot::builtin::Dictionary::new())
+                }
+                fn get_language(&self) -> Option<::godot::obj::Gd<::godot::classes::ScriptLanguage>> {
+                    Some(::godot::obj::script_language::CustomScript::language(self).upcast())
+                }
+            }
+        }
+
+        #[derive(::godot::register::GodotClass)]
+        #[class(no_init, base = ResourceFormatLoader, tool, internal)]
+        struct $Loader {
+            language: ::godot::obj::Gd<$Language>,
+            base: ::godot::obj::Base<::godot::classes::ResourceFormatLoader>,
+        }
+
+        #[::godot::register::godot_api]
+        impl ::godot::classes::IResourceFormatLoader for $Loader {
+            fn get_recognized_extensions(&self) -> ::godot::builtin::PackedStringArray {
+                ::godot::obj::script_language::__private::recognized_extensions(&self.language)
+            }
+            fn handles_type(&self, type_: ::godot::builtin::StringName) -> bool {
+                ::godot::obj::script_language::__private::handles_type(&self.language, &type_)
+            }
+            fn get_resource_type(&self, path: ::godot::builtin::GString) -> ::godot::builtin::GString {
+                ::godot::obj::script_language::__private::resource_type(&self.language, &path)
+            }
+            fn exists(&self, path: ::godot::builtin::GString) -> bool {
+                ::godot::classes::FileAccess::file_exists(&path)
+            }
+            fn load(
+                &self,
+                path: ::godot::builtin::GString,
+                _original_path: ::godot::builtin::GString,
+                _use_sub_threads: bool,
+                _cache_mode: i32,
+            ) -> ::godot::builtin::Variant {
+                ::godot::obj::script_language::__private::load(&self.language, &path)
+            }
+        }
+
+        #[derive(::godot::register::GodotClass)]
+        #[class(init, base = ResourceFormatSaver, tool, internal)]
+        struct $Saver {
+            base: ::godot::obj::Base<::godot::classes::ResourceFormatSaver>,
+        }
+
+        #[::godot::register::godot_api]
+        impl ::godot::classes::IResourceFormatSaver for $Saver {
+            fn save(
+                &mut self,
+                resource: Option<::godot::obj::Gd<::godot::classes::Resource>>,
+                path: ::godot::builtin::GString,
+                _flags: u32,
+            ) -> ::godot::global::Error {
+                ::godot::obj::script_language::__private::save::<$Script>(resource, &path)
+            }
+            fn recognize(&self, resource: Option<::godot::obj::Gd<::godot::classes::Resource>>) -> bool {
+                ::godot::obj::script_language::__private::recognize::<$Script>(resource)
+            }
+            fn get_recognized_extensions(
+                &self,
+                resource: Option<::godot::obj::Gd<::godot::classes::Resource>>,
+            ) -> ::godot::builtin::PackedStringArray {
+                ::godot::obj::script_language::__private::saver_extensions::<$Script>(resource)
+            }
+        }
+
+        impl ::godot::obj::script_language::ScriptLanguageFormats for $Language {
+            fn create_resource_formats(
+                language: &::godot::obj::Gd<Self>,
+            ) -> (
+                ::godot::obj::Gd<::godot::classes::ResourceFormatLoader>,
+                ::godot::obj::Gd<::godot::classes::ResourceFormatSaver>,
+            ) {
+                let language = language.clone();
+                let loader = ::godot::obj::Gd::from_init_fn(|base| $Loader { language, base });
+                let saver = <$Saver as ::godot::obj::NewGd>::new_gd();
+
+                (loader.upcast(), saver.upcast())
+            }
+        }
+    };
+}
+
+// Virtual methods added in later Godot versions. Each variant receives the version-independent methods and appends its own.
+
+#[cfg(before_api = "4.3")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __script_language_interfaces {
+    ($Language:ident, $Script:ident; { $($language_methods:tt)* } { $($script_methods:tt)* }) => {
+        #[::godot::register::godot_api]
+        impl ::godot::classes::IScriptLanguageExtension for $Language {
+            $($language_methods)*
+        }
+
+        #[::godot::register::godot_api]
+        impl ::godot::classes::IScriptExtension for $Script {
+            $($script_methods)*
+        }
+    };
+}
+
+#[cfg(all(since_api = "4.3", before_api = "4.4"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __script_language_interfaces {
+    ($Language:ident, $Script:ident; { $($language_methods:tt)* } { $($script_methods:tt)* }) => {
+        #[::godot::register::godot_api]
+        impl ::godot::classes::IScriptLanguageExtension for $Language {
+            $($language_methods)*
+
+            fn profiling_set_save_native_calls(&mut self, _enable: bool) {}
+            fn debug_get_stack_level_source(&self, _level: i32) -> ::godot::builtin::GString {
+                ::godot::builtin::GString::new()
+            }
+            fn can_make_function(&self) -> bool {
+                false
+            }
+            fn preferred_file_name_casing(&self) -> ::godot::classes::script_language::ScriptNameCasing {
+                ::godot::classes::script_language::ScriptNameCasing::AUTO
+            }
+        }
+
+        #[::godot::register::godot_api]
+        impl ::godot::classes::IScriptExtension for $Script {
+            $($script_methods)*
+        }
+    };
+}
+
+#[cfg(since_api = "4.4")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __script_language_interfaces {
+    ($Language:ident, $Script:ident; { $($language_methods:tt)* } { $($script_methods:tt)* }) => {
+        #[::godot::register::godot_api]
+        impl ::godot::classes::IScriptLanguageExtension for $Language {
+            $($language_methods)*
+
+            fn profiling_set_save_native_calls(&mut self, _enable: bool) {}
+            fn debug_get_stack_level_source(&self, _level: i32) -> ::godot::builtin::GString {
+                ::godot::builtin::GString::new()
+            }
+            fn can_make_function(&self) -> bool {
+                false
+            }
+            fn preferred_file_name_casing(&self) -> ::godot::classes::script_language::ScriptNameCasing {
+                ::godot::classes::script_language::ScriptNameCasing::AUTO
+            }
+            fn reload_scripts(&mut self, _scripts: ::godot::builtin::Array<::godot::builtin::Variant>, _soft: bool) {}
+        }
+
+        #[::godot::register::godot_api]
+        impl ::godot::classes::IScriptExtension for $Script {
+            $($script_methods)*
+
+            fn get_doc_class_name(&self) -> ::godot::builtin::StringName {
+                ::godot::obj::script_language::CustomScript::global_name(self)
+            }
+        }
+    };
+}
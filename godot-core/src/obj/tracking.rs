@@ -0,0 +1,233 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Diagnostics for the lifetime of objects created from Rust (debug builds only).
+//!
+//! Every object instantiated through Rust -- with [`NewAlloc::new_alloc()`], [`NewGd::new_gd()`], [`Gd::from_init_fn()`],
+//! [`Gd::from_object()`] or [`Gd::default()`] -- is recorded together with its class. Objects created by Godot (e.g. when instantiating
+//! a scene or calling `ClassDB.instantiate()`) are not tracked.
+//!
+//! Use [`live_count()`] and [`live_objects()`] to inspect objects that are still alive, for example to assert that a test has freed
+//! everything it allocated:
+//!
+//! ```no_run
+//! use godot::classes::Node;
+//! use godot::obj::{tracking, NewAlloc};
+//!
+//! let before = tracking::live_count::<Node>();
+//!
+//! let node = Node::new_alloc();
+//! assert_eq!(tracking::live_count::<Node>(), before + 1);
+//!
+//! node.free();
+//! assert_eq!(tracking::live_count::<Node>(), before);
+//! ```
+//!
+//! When the extension library is unloaded at engine shutdown, godot-rust prints a warning for each tracked object that is still alive.
+//! For manually managed objects, this means `free()` was never called and they were not added to the scene tree. Ref-counted objects
+//! that are still alive are typically part of a reference cycle (see [`WeakGd`][crate::obj::WeakGd] to break it) or held in a `static`.
+//!
+//! If the `RUST_BACKTRACE` environment variable is set, a backtrace is captured on creation of each object, and included in
+//! [`LiveObject`] as well as in the report. This is expensive, so only enable it while looking for the origin of a leak.
+//!
+//! This module is only available with strict safeguards, i.e. in dev builds by default (see
+//! [safeguard levels](../../index.html#safeguard-levels)).
+
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use godot_ffi as sys;
+
+use crate::classes::Engine;
+use crate::meta::ClassId;
+use crate::obj::{Gd, GodotClass, InstanceId, Singleton};
+
+/// Minimum number of tracked objects, before dead ones are removed during creation of new ones.
+const MIN_PRUNE_THRESHOLD: usize = 1024;
+
+struct TrackedObjects {
+    objects: HashMap<InstanceId, TrackedObject>,
+
+    /// When this many objects are tracked, dead ones are removed. Avoids unbounded growth without checking validity on every creation.
+    prune_threshold: usize,
+}
+
+impl Default for TrackedObjects {
+    fn default() -> Self {
+        Self {
+            objects: HashMap::new(),
+            prune_threshold: MIN_PRUNE_THRESHOLD,
+        }
+    }
+}
+
+impl TrackedObjects {
+    fn prune(&mut self) {
+        self.objects.retain(|id, _| id.lookup_validity());
+        self.prune_threshold = MIN_PRUNE_THRESHOLD.max(self.objects.len() * 2);
+    }
+}
+
+struct TrackedObject {
+    class_id: ClassId,
+    backtrace: Arc<Backtrace>,
+}
+
+static TRACKED_OBJECTS: sys::Global<TrackedObjects> = sys::Global::default();
+
+/// Object created from Rust, which is still alive. Returned by [`live_objects()`].
+#[derive(Clone)]
+pub struct LiveObject {
+    instance_id: InstanceId,
+    class_id: ClassId,
+    backtrace: Arc<Backtrace>,
+}
+
+impl LiveObject {
+    /// Instance ID of the object.
+    pub fn instance_id(&self) -> InstanceId {
+        self.instance_id
+    }
+
+    /// Class with which the object was created.
+    pub fn class_id(&self) -> ClassId {
+        self.class_id
+    }
+
+    /// Whether the object inherits `RefCounted`.
+    pub fn is_ref_counted(&self) -> bool {
+        self.instance_id.is_ref_counted()
+    }
+
+    /// Backtrace of the object's creation. Only captured if the `RUST_BACKTRACE` environment variable is set.
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+}
+
+impl fmt::Debug for LiveObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LiveObject")
+            .field("instance_id", &self.instance_id)
+            .field("class_id", &self.class_id)
+            .finish()
+    }
+}
+
+/// Returns the number of live objects of class `T` that were created from Rust.
+///
+/// Only counts objects created as exactly `T`, not those of derived classes.
+pub fn live_count<T: GodotClass>() -> usize {
+    let class_id = T::class_id();
+
+    live_objects()
+        .iter()
+        .filter(|obj| obj.class_id == class_id)
+        .count()
+}
+
+/// Returns the number of live objects created from Rust, per class.
+pub fn live_counts() -> HashMap<ClassId, usize> {
+    let mut counts = HashMap::new();
+    for obj in live_objects() {
+        *counts.entry(obj.class_id).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+/// Returns all live objects created from Rust, ordered by creation.
+pub fn live_objects() -> Vec<LiveObject> {
+    let mut tracked = TRACKED_OBJECTS.lock();
+    tracked.prune();
+
+    let mut objects = tracked
+        .objects
+        .iter()
+        .map(|(&instance_id, obj)| LiveObject {
+            instance_id,
+            class_id: obj.class_id,
+            backtrace: obj.backtrace.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    // Instance IDs are increasing, apart from the ref-counted flag in the highest bit.
+    objects.sort_by_key(|obj| obj.instance_id.to_u64() & !(1 << 63));
+    objects
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Crate-internal hooks
+
+/// Records an object that has just been created from Rust.
+pub(crate) fn track_created<T: GodotClass>(obj: &Gd<T>) {
+    let Some(instance_id) = obj.instance_id_or_none() else {
+        return;
+    };
+
+    let tracked_object = TrackedObject {
+        class_id: T::class_id(),
+        backtrace: Arc::new(Backtrace::capture()),
+    };
+
+    let mut tracked = TRACKED_OBJECTS.lock();
+    if tracked.objects.len() >= tracked.prune_threshold {
+        tracked.prune();
+    }
+
+    tracked.objects.insert(instance_id, tracked_object);
+}
+
+/// Prints a warning for each tracked object still alive, and clears the tracking state.
+pub(crate) fn report_leaks() {
+    // Objects can only be considered leaked on engine shutdown, where the main loop (including the scene tree) has already been destroyed.
+    // This is not the case when the library is unloaded for hot-reload.
+    if Engine::singleton().get_main_loop().is_some() {
+        return;
+    }
+
+    let leaked = live_objects();
+    *TRACKED_OBJECTS.lock() = TrackedObjects::default();
+
+    if leaked.is_empty() {
+        return;
+    }
+
+    let mut report = format!(
+        "{} object(s) created from Rust are still alive on shutdown:",
+        leaked.len()
+    );
+
+    for obj in &leaked {
+        let reason = if obj.is_ref_counted() {
+            "still referenced (reference cycle or static?)"
+        } else {
+            "never freed"
+        };
+
+        report += &format!(
+            "\n  - {class} #{id}: {reason}",
+            class = obj.class_id,
+            id = obj.instance_id
+        );
+
+        if obj.backtrace.status() == BacktraceStatus::Captured {
+            report += &crate::format_backtrace!("created at", obj.backtrace());
+        }
+    }
+
+    if !leaked
+        .iter()
+        .any(|obj| obj.backtrace.status() == BacktraceStatus::Captured)
+    {
+        report += "\n  (run with `RUST_BACKTRACE=1` environment variable to see where these objects were created)";
+    }
+
+    crate::godot_warn!("{report}");
+}
@@ -635,6 +635,33 @@ pub trait WithUserSignals: WithSignals + WithBaseField {
     fn signals(&mut self) -> Self::SignalCollection<'_, Self>;
 }
 
+/// Implemented for user-defined classes with at least one `#[rpc]` method.
+///
+/// Allows to send remote procedure calls with type-checked names and arguments, as `self.rpcs()`. From outside the class, use
+/// [`Gd::rpcs()`] instead.
+#[cfg(feature = "codegen-full")]
+pub trait WithUserRpcs: WithBaseField + Inherits<crate::classes::Node> {
+    /// The associated struct with one method per `#[rpc]`.
+    type RpcCollection<'c>;
+
+    /// Access remote procedure calls of the current object `self`.
+    ///
+    /// The returned collection provides a method for each `#[rpc]`, with the same name and parameters. Each method returns a
+    /// [`RpcCall`](crate::registry::RpcCall), on which the recipients are chosen. For example, if you have...
+    /// ```ignore
+    /// #[rpc(any_peer, reliable)]
+    /// fn take_damage(&mut self, amount: i32);
+    /// ```
+    /// ...then you can send the call as `self.rpcs().take_damage(5).to_peer(peer_id)` or `self.rpcs().take_damage(5).to_all()`.
+    ///
+    /// Until the call is sent, `self` is accessible to re-entrant calls, e.g. through RPCs configured with `call_local`.
+    fn rpcs(&mut self) -> Self::RpcCollection<'_>;
+
+    /// Create from existing `Gd`, to enable `Gd::rpcs()`.
+    #[doc(hidden)]
+    fn __rpcs_from_external(external: &Gd<Self>) -> Self::RpcCollection<'_>;
+}
+
 /// Extension trait for all reference-counted classes.
 pub trait NewGd: GodotClass {
     /// Return a new, ref-counted `Gd` containing a default-constructed instance.
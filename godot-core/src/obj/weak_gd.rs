@@ -0,0 +1,194 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::builtin::Variant;
+use crate::classes::Object;
+use crate::meta::error::ConvertError;
+use crate::meta::{FromGodot, GodotConvert, ToGodot};
+use crate::obj::{Gd, GodotClass, InstanceId};
+use crate::registry::property::Var;
+use crate::{global, meta};
+
+/// Weak pointer to objects owned by the Godot engine.
+///
+/// A `WeakGd<T>` refers to an object without keeping it alive. It is obtained with [`Gd::downgrade()`], and turned back into a strong
+/// pointer with [`upgrade()`][Self::upgrade], which returns `None` once the object has been destroyed.
+///
+/// This works for both memory strategies:
+/// - For [`RefCounted`][crate::classes::RefCounted] objects, a `WeakGd` does not contribute to the reference count. This breaks
+///   reference cycles, e.g. between a parent resource and its children pointing back to it.
+/// - For manually managed objects (such as nodes), a `WeakGd` replaces the pattern of storing an [`InstanceId`] and calling
+///   [`Gd::try_from_instance_id()`] on each access.
+///
+/// Like `Gd`, this type can be used as a field in `#[derive(GodotClass)]` structs. `#[var]` properties appear in Godot as a
+/// [`WeakRef`](https://docs.godotengine.org/en/stable/classes/class_weakref.html) object, which GDScript can access via `get_ref()`.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Resource)]
+/// struct Chapter {
+///     // Does not keep the book alive; the book owns its chapters.
+///     book: WeakGd<Resource>,
+/// }
+///
+/// #[godot_api]
+/// impl Chapter {
+///     #[func]
+///     fn book_title(&self) -> GString {
+///         match self.book.upgrade() {
+///             Some(book) => book.get_name(),
+///             None => GString::from("<no book>"),
+///         }
+///     }
+/// }
+/// ```
+pub struct WeakGd<T: GodotClass> {
+    // Instance IDs are unique for the entire session and never reused, so a stale ID cannot point to another object.
+    instance_id: Option<InstanceId>,
+
+    // Not Send/Sync, like Gd<T>.
+    _marker: PhantomData<*const T>,
+}
+
+impl<T: GodotClass> WeakGd<T> {
+    /// Creates a weak pointer that doesn't point to any object; [`upgrade()`][Self::upgrade] always returns `None`.
+    ///
+    /// Equivalent to [`WeakGd::default()`].
+    pub fn new() -> Self {
+        Self {
+            instance_id: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a strong pointer to the object, or `None` if it has been destroyed.
+    ///
+    /// For `RefCounted` objects, this increments the reference count as long as the returned `Gd` lives.
+    pub fn upgrade(&self) -> Option<Gd<T>> {
+        let instance_id = self.instance_id?;
+        Gd::try_from_instance_id(instance_id).ok()
+    }
+
+    /// Checks if the object is still alive, without upgrading it.
+    pub fn is_alive(&self) -> bool {
+        self.instance_id
+            .is_some_and(|instance_id| instance_id.lookup_validity())
+    }
+
+    /// Returns the instance ID of the object, if this pointer was created from one. The object may no longer be alive.
+    pub fn instance_id(&self) -> Option<InstanceId> {
+        self.instance_id
+    }
+
+    pub(crate) fn from_instance_id(instance_id: InstanceId) -> Self {
+        Self {
+            instance_id: Some(instance_id),
+            _marker: PhantomData,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Trait impls
+
+impl<T: GodotClass> Default for WeakGd<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: GodotClass> Clone for WeakGd<T> {
+    fn clone(&self) -> Self {
+        Self {
+            instance_id: self.instance_id,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Weak pointers are equal if they refer to the same object, or both don't refer to any object.
+impl<T: GodotClass> PartialEq for WeakGd<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.instance_id == other.instance_id
+    }
+}
+
+impl<T: GodotClass> Eq for WeakGd<T> {}
+
+impl<T: GodotClass> std::hash::Hash for WeakGd<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.instance_id.hash(state);
+    }
+}
+
+impl<T: GodotClass> fmt::Debug for WeakGd<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.instance_id {
+            Some(id) if id.lookup_validity() => write!(f, "WeakGd<{}>(#{id})", T::class_id()),
+            Some(id) => write!(f, "WeakGd<{}>(#{id}, dead)", T::class_id()),
+            None => write!(f, "WeakGd<{}>(none)", T::class_id()),
+        }
+    }
+}
+
+impl<T: GodotClass> GodotConvert for WeakGd<T> {
+    type Via = Variant;
+}
+
+/// Converts to a Godot `WeakRef` object, or nil if the pointer is dead or empty.
+impl<T: GodotClass> ToGodot for WeakGd<T> {
+    type Pass = meta::ByValue;
+
+    fn to_godot(&self) -> Self::Via {
+        match self.upgrade() {
+            Some(obj) => global::weakref(&obj.to_variant()),
+            None => Variant::nil(),
+        }
+    }
+}
+
+/// Converts from a Godot `WeakRef`, an object of class `T` (which is downgraded) or nil (resulting in an empty pointer).
+impl<T: GodotClass> FromGodot for WeakGd<T> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        if via.is_nil() {
+            return Ok(Self::new());
+        }
+
+        let obj = via.try_to::<Gd<Object>>()?;
+        if !obj.is_class("WeakRef") {
+            return obj
+                .try_cast::<T>()
+                .map(|obj| obj.downgrade())
+                .map_err(|obj| ConvertError::with_error_value("object has wrong class", obj));
+        }
+
+        // A dead WeakRef returns nil.
+        let target = via.call("get_ref", &[]);
+        if target.is_nil() {
+            return Ok(Self::new());
+        }
+
+        let target = target.try_to::<Gd<T>>()?;
+        Ok(target.downgrade())
+    }
+}
+
+impl<T: GodotClass> Var for WeakGd<T> {
+    fn get_property(&self) -> Self::Via {
+        self.to_godot()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        *self = FromGodot::from_godot(value)
+    }
+}
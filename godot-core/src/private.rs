@@ -23,7 +23,7 @@ use crate::{classes, sys};
 
 mod reexport_pub {
     #[cfg(all(since_api = "4.3", feature = "register-docs"))]
-    pub use crate::docs::{DocsItem, DocsPlugin, InherentImplDocs, StructDocs};
+    pub use crate::docs::{ConstantEnumDocs, DocsItem, DocsPlugin, InherentImplDocs, StructDocs};
     pub use crate::gen::classes::class_macros;
     pub use crate::gen::virtuals; // virtual fn names, hashes, signatures
     #[cfg(feature = "trace")]
@@ -57,6 +57,8 @@ static ERROR_PRINT_LEVEL: atomic::AtomicU8 = atomic::AtomicU8::new(2);
 sys::plugin_registry!(pub __GODOT_PLUGIN_REGISTRY: ClassPlugin);
 #[cfg(all(since_api = "4.3", feature = "register-docs"))]
 sys::plugin_registry!(pub __GODOT_DOCS_REGISTRY: DocsPlugin);
+sys::plugin_registry!(pub __GODOT_TRANSLATION_REGISTRY: crate::tools::TranslationMessage);
+sys::plugin_registry!(pub __GODOT_RESOURCE_FORMAT_REGISTRY: crate::tools::resource_format::__private::ResourceFormatPlugin);
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Call error handling
@@ -238,6 +240,130 @@ pub fn is_class_runtime(is_tool: bool) -> bool {
     global_config.tool_only_in_editor
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversion helpers for #[derive(GodotConvert)] with #[godot(via = Dictionary)]
+
+/// Reads the dictionary entry for a field of `type_name`.
+///
+/// Returns `Ok(None)` if the key is absent, and an error naming the field if the value has the wrong type.
+pub fn dict_field<T: crate::meta::FromGodot>(
+    dict: &crate::builtin::Dictionary,
+    type_name: &str,
+    key: &str,
+) -> Result<Option<T>, crate::meta::error::ConvertError> {
+    let Some(value) = dict.get(key) else {
+        return Ok(None);
+    };
+
+    value.try_to::<T>().map(Some).map_err(|err| {
+        let error = DictFieldError {
+            message: format!("field `{key}` of `{type_name}` has invalid value"),
+            cause: Box::new(err.into_erased()),
+        };
+
+        crate::meta::error::ConvertError::with_error_value(error, value)
+    })
+}
+
+/// Error for a dictionary field that failed to convert, keeping the original conversion error as its source.
+#[derive(Debug)]
+struct DictFieldError {
+    message: String,
+    cause: Box<dyn std::error::Error + Send + Sync>,
+}
+
+impl std::fmt::Display for DictFieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.message, self.cause)
+    }
+}
+
+impl std::error::Error for DictFieldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&*self.cause)
+    }
+}
+
+/// Error for a required field which is absent from the dictionary.
+pub fn dict_missing_field(
+    dict: &crate::builtin::Dictionary,
+    type_name: &str,
+    key: &str,
+) -> crate::meta::error::ConvertError {
+    crate::meta::error::ConvertError::with_error_value(
+        format!("missing field `{key}` in dictionary for `{type_name}`"),
+        dict.clone(),
+    )
+}
+
+/// Reads the enumerator name stored under `tag_key`, for tagged dictionary encoding of enums.
+pub fn dict_enum_tag(
+    dict: &crate::builtin::Dictionary,
+    type_name: &str,
+    tag_key: &str,
+) -> Result<String, crate::meta::error::ConvertError> {
+    match dict_field::<crate::builtin::GString>(dict, type_name, tag_key)? {
+        Some(tag) => Ok(tag.to_string()),
+        None => Err(dict_missing_field(dict, type_name, tag_key)),
+    }
+}
+
+/// Error for a tag that doesn't correspond to any enumerator.
+pub fn dict_unknown_enumerator(
+    dict: &crate::builtin::Dictionary,
+    type_name: &str,
+    tag: &str,
+) -> crate::meta::error::ConvertError {
+    crate::meta::error::ConvertError::with_error_value(
+        format!("unknown enumerator `{tag}` in dictionary for `{type_name}`"),
+        dict.clone(),
+    )
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Async #[func] support
+
+/// Name of the signal emitted by the handle object of an `async #[func]`.
+pub const ASYNC_FUNC_COMPLETED_SIGNAL: &str = "completed";
+
+/// Spawns the future of an `async #[func]` on the engine's async runtime.
+///
+/// Returns the object handed to Godot callers, which emits `completed(result)` once the future resolves. GDScript can thus use
+/// `var result = await obj.some_func().completed`.
+pub fn spawn_async_func<F>(future: F) -> Gd<classes::RefCounted>
+where
+    F: std::future::Future + 'static,
+    F::Output: crate::meta::ToGodot,
+{
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use crate::meta::ToGodot;
+    use crate::obj::NewGd;
+
+    let mut handle = classes::RefCounted::new_gd();
+    handle.add_user_signal(ASYNC_FUNC_COMPLETED_SIGNAL);
+
+    // The task keeps the handle alive until completion, even if the caller discards it.
+    let mut emitter = handle.clone();
+    let returned = Rc::new(Cell::new(false));
+    let returned_in_task = returned.clone();
+
+    crate::task::spawn(async move {
+        let result = future.await;
+
+        // If the future completed during the first poll, the caller has not received the handle yet and could not connect.
+        if !returned_in_task.get() {
+            crate::task::yield_now().await;
+        }
+
+        emitter.emit_signal(ASYNC_FUNC_COMPLETED_SIGNAL, &[result.to_variant()]);
+    });
+
+    returned.set(true);
+    handle
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Panic *hook* management
 
@@ -93,3 +93,15 @@ impl ExportConstant {
         self.kind.register(self.class_name)
     }
 }
+
+/// Rust enum whose enumerators can be registered as an enum or bitfield of a class, with `#[constant(enum)] type Name = MyEnum;`.
+///
+/// Implemented by `#[derive(GodotConvert)]` for enums with an integer `via` type.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be registered as class enum",
+    note = "#[constant(enum)] and #[constant(bitfield)] require an enum with #[derive(GodotConvert)] and #[godot(via = <integer>)]"
+)]
+pub trait ConstantEnum {
+    /// Name and value of each enumerator, in order of declaration.
+    fn enumerators() -> Vec<IntegerConstant>;
+}
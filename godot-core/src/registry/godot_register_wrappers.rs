@@ -81,6 +81,15 @@ fn register_var_or_export_inner(
     }
 }
 
+/// Registers an Inspector category, under which all subsequently registered properties are listed.
+///
+/// Godot has no dedicated API for categories; they are properties without getter and setter, using the `CATEGORY` usage.
+pub fn register_category<C: GodotClass>(category_name: &str) {
+    let info = PropertyInfo::new_category(category_name);
+
+    register_var_or_export_inner(info, C::class_id(), "", "");
+}
+
 pub fn register_group<C: GodotClass>(group_name: &str, prefix: &str) {
     let group_name = GString::from(group_name);
     let prefix = GString::from(prefix);
@@ -22,5 +22,10 @@ mod rpc_config;
 #[cfg(feature = "codegen-full")]
 pub use rpc_config::RpcConfig;
 
+#[cfg(feature = "codegen-full")]
+mod rpc_call;
+#[cfg(feature = "codegen-full")]
+pub use rpc_call::{RpcCall, RpcTarget};
+
 #[doc(hidden)]
 pub mod godot_register_wrappers;
@@ -231,10 +231,11 @@ where
 pub mod export_info_functions {
     use godot_ffi::VariantType;
 
-    use crate::builtin::GString;
+    use crate::builtin::{GString, NodePath, StringName};
+    use crate::classes::Node;
     use crate::global::PropertyHint;
-    use crate::meta::{GodotType, PropertyHintInfo, PropertyInfo};
-    use crate::obj::EngineEnum;
+    use crate::meta::{ClassId, GodotConvert, GodotType, PropertyHintInfo, PropertyInfo};
+    use crate::obj::{EngineEnum, GodotClass, Inherits};
     use crate::registry::property::Export;
     use crate::sys;
 
@@ -505,6 +506,112 @@ pub mod export_info_functions {
         }
     }
 
+    /// For `#[export_tool_button]`. The field must convert to a `Callable`, which is invoked when the button is pressed.
+    ///
+    /// Formats: `"Label"`, `"Label,IconName"`.
+    #[cfg(since_api = "4.4")]
+    pub fn export_tool_button<T>(label: &str, icon: &str) -> PropertyHintInfo
+    where
+        T: crate::meta::GodotConvert<Via = crate::builtin::Callable>,
+    {
+        let hint_string = if icon.is_empty() {
+            label.to_string()
+        } else {
+            format!("{label},{icon}")
+        };
+
+        PropertyHintInfo {
+            hint: PropertyHint::TOOL_BUTTON,
+            hint_string: GString::from(&hint_string),
+        }
+    }
+
+    /// Field types that Godot edits as text, i.e. `GString` and `StringName`.
+    ///
+    /// Statically checks that string-only hints such as `#[export(password)]` are not applied to other types.
+    #[doc(hidden)]
+    pub trait TextExport: Export {}
+
+    impl TextExport for GString {}
+    impl TextExport for StringName {}
+
+    /// For `#[export(custom = (hint = ..., hint_string = ...))]`, which forwards hint and hint string verbatim.
+    pub fn export_custom(hint: PropertyHint, hint_string: impl AsRef<str>) -> PropertyHintInfo {
+        PropertyHintInfo {
+            hint,
+            hint_string: GString::from(hint_string.as_ref()),
+        }
+    }
+
+    /// Hint for a text field whose characters are hidden, like a password input.
+    pub fn export_password<T: TextExport>() -> PropertyHintInfo {
+        PropertyHintInfo {
+            hint: PropertyHint::PASSWORD,
+            hint_string: GString::new(),
+        }
+    }
+
+    /// Hint for a text field holding a locale code, which the editor selects from a list of locales.
+    pub fn export_locale_id<T: TextExport>() -> PropertyHintInfo {
+        PropertyHintInfo {
+            hint: PropertyHint::LOCALE_ID,
+            hint_string: GString::new(),
+        }
+    }
+
+    /// Hint for a text field holding a Godot expression, see the `Expression` class.
+    pub fn export_expression<T: TextExport>() -> PropertyHintInfo {
+        PropertyHintInfo {
+            hint: PropertyHint::EXPRESSION,
+            hint_string: GString::new(),
+        }
+    }
+
+    /// Hint for a text field holding the name of an input action from the project's Input Map.
+    ///
+    /// Formats: `""`, `"show_builtin"`, `"loose_mode"`, `"show_builtin,loose_mode"`.
+    #[cfg(since_api = "4.3")]
+    pub fn export_input_name<T: TextExport>(
+        show_builtin: bool,
+        loose_mode: bool,
+    ) -> PropertyHintInfo {
+        let hint_string = comma_separate_boolean_idents!(show_builtin, loose_mode);
+
+        PropertyHintInfo {
+            hint: PropertyHint::INPUT_NAME,
+            hint_string: GString::from(&hint_string),
+        }
+    }
+
+    /// Hint for a `NodePath` field that may only point to nodes of the given classes.
+    ///
+    /// The macro passes [`node_class_id()`] for each class, which statically verifies that it is a node.
+    ///
+    /// Formats: `"Node2D"`, `"Sprite2D,AnimatedSprite2D"`.
+    pub fn export_node_path_valid_types<T>(classes: &[ClassId]) -> PropertyHintInfo
+    where
+        T: Export + GodotConvert<Via = NodePath>,
+    {
+        let hint_string = classes
+            .iter()
+            .map(|class| class.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        PropertyHintInfo {
+            hint: PropertyHint::NODE_PATH_VALID_TYPES,
+            hint_string: GString::from(&hint_string),
+        }
+    }
+
+    /// Class ID of `N`, which must be `Node` or one of its subclasses.
+    pub fn node_class_id<N>() -> ClassId
+    where
+        N: Inherits<Node>,
+    {
+        N::class_id()
+    }
+
     macro_rules! default_export_funcs {
         (
             $( $function_name:ident => $property_hint:ident, )*
@@ -533,6 +640,7 @@ pub mod export_info_functions {
         export_flags_3d_navigation => LAYERS_3D_NAVIGATION,
         export_multiline => MULTILINE_TEXT,
         export_color_no_alpha => COLOR_NO_ALPHA,
+        export_link => LINK,
     );
 }
 
@@ -606,7 +714,7 @@ mod export_impls {
 
     impl_property_by_godot_convert!(Color);
 
-    // Dictionary: will need to be done manually once they become typed.
+    // Dictionary: typed dictionaries are implemented manually, see Dict<K, V>.
     impl_property_by_godot_convert!(Dictionary);
     impl_property_by_godot_convert!(Variant);
 
@@ -0,0 +1,117 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{StringName, Variant};
+use crate::classes::Node;
+use crate::global::Error;
+use crate::obj::{Gd, WithBaseField, WithUserRpcs};
+
+/// Remote procedure call with typed arguments, whose recipients have not been chosen yet.
+///
+/// Obtained from the typed RPC accessors generated for `#[rpc]` methods, i.e. `self.rpcs()` or [`Gd::rpcs()`]. The call is sent once
+/// [`to_peer()`][Self::to_peer] or [`to_all()`][Self::to_all] is invoked:
+///
+/// ```ignore
+/// #[rpc(any_peer)]
+/// fn take_damage(&mut self, amount: i32);
+///
+/// // Equivalent to `node.rpc_id(peer_id, "take_damage", &[5.to_variant()])`.
+/// self.rpcs().take_damage(5).to_peer(peer_id);
+/// ```
+///
+/// Whether the RPC is allowed, and how it is transferred, is determined by its `#[rpc]` configuration. See also
+/// [Godot docs about RPCs](https://docs.godotengine.org/en/stable/tutorials/networking/high_level_multiplayer.html#remote-procedure-calls).
+///
+/// [`Gd::rpcs()`]: crate::obj::Gd::rpcs
+#[must_use = "the RPC is only sent once to_peer() or to_all() is called"]
+pub struct RpcCall<'c> {
+    target: RpcTarget<'c>,
+    method: StringName,
+    args: Vec<Variant>,
+}
+
+impl RpcCall<'_> {
+    /// Sends the RPC to the peer with the given ID. The server always has ID `1`.
+    ///
+    /// Equivalent to [`Node::rpc_id()`].
+    pub fn to_peer(self, peer_id: i64) -> Error {
+        let Self {
+            mut target,
+            method,
+            args,
+        } = self;
+
+        target.node.rpc_id(peer_id, &method, &args)
+    }
+
+    /// Sends the RPC to all connected peers (and locally, if configured with `call_local`).
+    ///
+    /// Equivalent to [`Node::rpc()`].
+    pub fn to_all(self) -> Error {
+        let Self {
+            mut target,
+            method,
+            args,
+        } = self;
+
+        target.node.rpc(&method, &args)
+    }
+
+    /// Name of the method, as registered in Godot.
+    pub fn method_name(&self) -> &StringName {
+        &self.method
+    }
+
+    /// Arguments of the call, converted to variants.
+    pub fn args(&self) -> &[Variant] {
+        &self.args
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Node on which a typed RPC is sent; stored inside the RPC collection struct generated by `#[godot_api]`.
+#[doc(hidden)]
+pub struct RpcTarget<'c> {
+    node: Gd<Node>,
+
+    // When accessed through `self.rpcs()`, holds a `base_mut()` guard until the call is sent. This allows `call_local` RPCs to re-enter
+    // the same object, which would otherwise fail due to the active `&mut self` borrow.
+    _guard: Option<Box<dyn ErasedGuard + 'c>>,
+}
+
+impl<'c> RpcTarget<'c> {
+    pub fn from_internal<T: WithUserRpcs>(object: &'c mut T) -> Self {
+        let node = object.to_gd().upcast::<Node>();
+        let guard = object.base_mut();
+
+        Self {
+            node,
+            _guard: Some(Box::new(guard)),
+        }
+    }
+
+    pub fn from_external<T: WithUserRpcs>(object: &Gd<T>) -> Self {
+        Self {
+            node: object.clone().upcast::<Node>(),
+            _guard: None,
+        }
+    }
+
+    pub fn call(self, method_name: &str, args: Vec<Variant>) -> RpcCall<'c> {
+        RpcCall {
+            target: self,
+            method: StringName::from(method_name),
+            args,
+        }
+    }
+}
+
+/// Type-erases the `BaseMut<T>` guard, which only needs to be kept alive.
+trait ErasedGuard {}
+
+impl<T> ErasedGuard for T {}
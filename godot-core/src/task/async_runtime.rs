@@ -11,10 +11,16 @@ use std::marker::PhantomData;
 use std::panic::AssertUnwindSafe;
 use std::pin::Pin;
 use std::sync::Arc;
+#[cfg(feature = "experimental-threads")]
+use std::sync::Mutex;
 use std::task::{Context, Poll, Wake, Waker};
 use std::thread::{self, LocalKey, ThreadId};
 
 use crate::builtin::{Callable, Variant};
+#[cfg(feature = "experimental-threads")]
+use crate::classes::WorkerThreadPool;
+#[cfg(feature = "experimental-threads")]
+use crate::obj::Singleton;
 use crate::private::handle_panic;
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -181,6 +187,225 @@ impl TaskHandle {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Worker thread pool
+
+/// Runs a blocking closure on Godot's [`WorkerThreadPool`] and returns a future for its result.
+///
+/// Use this to offload CPU-heavy work such as pathfinding or procedural generation from the main thread. The returned [`BlockingFuture`]
+/// is meant to be awaited inside a task started with [`spawn()`]; it resolves on the main thread once `work` has finished.
+///
+/// `work` runs on a worker thread, so it must be `Send` and must not touch non-thread-safe engine objects (e.g. nodes in the scene tree).
+/// Pass plain data in, and apply the result once the future resolves.
+///
+/// If the future is dropped before `work` starts -- for example because the enclosing task was [canceled][TaskHandle::cancel] --
+/// then `work` is skipped. Work that has already started cannot be interrupted, but its result is discarded.
+///
+/// # Panics
+/// - If called from any other thread than the main thread.
+/// - When awaited, if `work` panicked. The panic is forwarded to the awaiting task.
+///
+/// # Example
+/// ```no_run
+/// # fn find_path(from: i32, to: i32) -> Vec<i32> { vec![from, to] }
+/// godot::task::spawn(async {
+///     let path = godot::task::spawn_blocking(|| find_path(0, 42)).await;
+///     println!("Found path: {path:?}");
+/// });
+/// ```
+///
+/// [`WorkerThreadPool`]: crate::classes::WorkerThreadPool
+#[cfg(feature = "experimental-threads")]
+pub fn spawn_blocking<F, R>(work: F) -> BlockingFuture<R>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    assert!(
+        crate::init::is_main_thread(),
+        "spawn_blocking() can only be used on the main thread"
+    );
+
+    BlockingFuture::start(work)
+}
+
+/// Runs a blocking closure on Godot's [`WorkerThreadPool`] and passes its result to `on_complete` on the main thread.
+///
+/// This is the synchronous counterpart to [`spawn_blocking()`], usable from code that is not itself async (e.g. `ready()`). It starts
+/// a new task, whose [`TaskHandle`] can be used to check progress or to [cancel][TaskHandle::cancel] the work; in the latter case,
+/// `on_complete` is never invoked.
+///
+/// # Panics
+/// If called from any other thread than the main thread.
+///
+/// # Example
+/// ```no_run
+/// # fn generate_chunk(seed: u64) -> Vec<u8> { vec![seed as u8] }
+/// let handle = godot::task::spawn_on_pool(
+///     || generate_chunk(1234),
+///     |chunk| println!("Chunk ready: {} bytes", chunk.len()),
+/// );
+/// ```
+///
+/// [`WorkerThreadPool`]: crate::classes::WorkerThreadPool
+#[cfg(feature = "experimental-threads")]
+pub fn spawn_on_pool<F, R, C>(work: F, on_complete: C) -> TaskHandle
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+    C: FnOnce(R) + 'static,
+{
+    spawn(async move {
+        let result = spawn_blocking(work).await;
+        on_complete(result);
+    })
+}
+
+/// Future returned by [`spawn_blocking()`], resolving to the result of the work on the main thread.
+///
+/// Dropping the future before the work has started prevents the work from running.
+#[cfg(feature = "experimental-threads")]
+pub struct BlockingFuture<R> {
+    state: Arc<Mutex<BlockingState<R>>>,
+    _no_send_sync: PhantomData<*const ()>,
+}
+
+#[cfg(feature = "experimental-threads")]
+impl<R: Send + 'static> BlockingFuture<R> {
+    fn start<F>(work: F) -> Self
+    where
+        F: FnOnce() -> R + Send + 'static,
+    {
+        let state = Arc::new(Mutex::new(BlockingState::new()));
+
+        // Callables can be invoked more than once in principle, so the FnOnce needs to be moved out.
+        let work = Mutex::new(Some(work));
+        let worker_state = state.clone();
+
+        let callable = Callable::from_sync_fn("spawn_blocking", move |_args| {
+            let work = work
+                .lock()
+                .unwrap()
+                .take()
+                .expect("spawn_blocking() work is only run once");
+
+            let canceled = worker_state.lock().unwrap().canceled;
+            if !canceled {
+                let output = std::panic::catch_unwind(AssertUnwindSafe(work));
+                worker_state.lock().unwrap().output = Some(output);
+            }
+
+            // Finish on the main thread, which is the only one allowed to wait for pool tasks and to poll futures.
+            let main_state = worker_state.clone();
+            Callable::from_sync_fn("spawn_blocking::complete", move |_args| {
+                BlockingState::complete(&main_state);
+            })
+            .call_deferred(&[]);
+        });
+
+        let task_id = WorkerThreadPool::singleton().add_task(&callable);
+        state.lock().unwrap().task_id = Some(task_id);
+
+        Self {
+            state,
+            _no_send_sync: PhantomData,
+        }
+    }
+
+    /// Returns whether the work has finished and the future is ready.
+    pub fn is_finished(&self) -> bool {
+        self.state.lock().unwrap().finished
+    }
+}
+
+#[cfg(feature = "experimental-threads")]
+impl<R> Future for BlockingFuture<R> {
+    type Output = R;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<R> {
+        let mut state = self.state.lock().unwrap();
+
+        if !state.finished {
+            state.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+
+        let output = state
+            .output
+            .take()
+            .expect("BlockingFuture polled after completion");
+        drop(state);
+
+        match output {
+            Ok(value) => Poll::Ready(value),
+            Err(panic_payload) => std::panic::resume_unwind(panic_payload),
+        }
+    }
+}
+
+#[cfg(feature = "experimental-threads")]
+impl<R> Drop for BlockingFuture<R> {
+    fn drop(&mut self) {
+        // Never panic in drop; a poisoned mutex means the result is lost anyway.
+        if let Ok(mut state) = self.state.lock() {
+            state.canceled = true;
+        }
+    }
+}
+
+/// State shared between a [`BlockingFuture`] and its `WorkerThreadPool` task.
+#[cfg(feature = "experimental-threads")]
+struct BlockingState<R> {
+    /// ID returned by `WorkerThreadPool::add_task()`, set on the main thread right after submission.
+    task_id: Option<i64>,
+
+    /// Result of the work, or the panic payload. `None` if the work hasn't finished or was skipped.
+    output: Option<thread::Result<R>>,
+
+    /// Whether the pool task has been waited for on the main thread. Only then, the future is ready.
+    finished: bool,
+
+    /// Set when the future is dropped; the work is skipped if it hasn't started yet.
+    canceled: bool,
+
+    waker: Option<Waker>,
+}
+
+#[cfg(feature = "experimental-threads")]
+impl<R> BlockingState<R> {
+    fn new() -> Self {
+        Self {
+            task_id: None,
+            output: None,
+            finished: false,
+            canceled: false,
+            waker: None,
+        }
+    }
+
+    /// Called on the main thread after the pool task has run.
+    fn complete(state: &Mutex<Self>) {
+        let task_id = state
+            .lock()
+            .unwrap()
+            .task_id
+            .expect("task ID is set before the deferred completion runs");
+
+        // Every pool task must be waited for, otherwise Godot does not release it. The task has already run, so this does not block.
+        WorkerThreadPool::singleton().wait_for_task_completion(task_id);
+
+        let waker = {
+            let mut state = state.lock().unwrap();
+            state.finished = true;
+            state.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Async Runtime
 
@@ -200,9 +425,19 @@ pub(crate) fn cleanup() {
     ASYNC_RUNTIME.set(None);
 }
 
-#[cfg(feature = "trace")]
+/// Whether polling the task behind `task_handle` has panicked.
+///
+/// Used by the test runners in `itest` and [`crate::testing`] to report async test failures. Panics are only tracked with the `trace`
+/// feature or after [`enable_panic_tracking()`], and each one is only reported once.
 pub fn has_godot_task_panicked(task_handle: TaskHandle) -> bool {
-    ASYNC_RUNTIME.with_runtime(|rt| rt.panicked_tasks.contains(&task_handle.id))
+    ASYNC_RUNTIME.with_runtime_mut(|rt| rt.panicked_tasks.remove(&task_handle.id))
+}
+
+/// Starts tracking panicking tasks for [`has_godot_task_panicked()`], for the rest of the runtime's lifetime.
+///
+/// Off by default, so that production code does not accumulate an entry for every panicking task.
+pub(crate) fn enable_panic_tracking() {
+    ASYNC_RUNTIME.with_runtime_mut(|rt| rt.track_panics = true);
 }
 
 /// The current state of a future inside the async runtime.
@@ -289,8 +524,10 @@ impl<T> FutureSlot<T> {
 struct AsyncRuntime {
     tasks: Vec<FutureSlot<Pin<Box<dyn Future<Output = ()>>>>>,
     next_task_id: u64,
-    #[cfg(feature = "trace")]
+    /// Tasks whose polling panicked. Entries are removed once queried by [`has_godot_task_panicked()`].
     panicked_tasks: std::collections::HashSet<u64>,
+    /// Whether panics are recorded in `panicked_tasks`, see [`enable_panic_tracking()`].
+    track_panics: bool,
 }
 
 impl AsyncRuntime {
@@ -299,8 +536,8 @@ impl AsyncRuntime {
             // We only create a new async runtime inside a thread_local, which has lazy initialization on first use.
             tasks: Vec::with_capacity(16),
             next_task_id: 0,
-            #[cfg(feature = "trace")]
             panicked_tasks: std::collections::HashSet::default(),
+            track_panics: cfg!(feature = "trace"),
         }
     }
 
@@ -371,12 +608,11 @@ impl AsyncRuntime {
         self.tasks[index].park(future);
     }
 
-    /// Track that a future caused a panic.
-    ///
-    /// This is only available for itest.
-    #[cfg(feature = "trace")]
+    /// Track that a future caused a panic, so test runners can report it.
     fn track_panic(&mut self, task_id: u64) {
-        self.panicked_tasks.insert(task_id);
+        if self.track_panics {
+            self.panicked_tasks.insert(task_id);
+        }
     }
 }
 
@@ -454,9 +690,8 @@ fn poll_future(godot_waker: Arc<GodotWaker>) {
     });
 
     let Ok((poll_result, future)) = panic_result else {
-        // Polling the future caused a panic. The task state has to be cleaned up and we want track the panic if the trace feature is enabled.
+        // Polling the future caused a panic. The task state has to be cleaned up and the panic is tracked for test runners.
         ASYNC_RUNTIME.with_runtime_mut(|rt| {
-            #[cfg(feature = "trace")]
             rt.track_panic(godot_waker.task_id);
             rt.clear_task(godot_waker.runtime_index);
         });
@@ -0,0 +1,246 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use crate::task::{sleep, SignalFuture};
+
+// Futures are boxed, so the combinators are `Unpin` and don't need pin projections. The allocation is negligible compared to the
+// per-frame polling of the engine runtime.
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// join()
+
+/// Waits for two futures concurrently, resolving to both outputs once both have completed.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use godot::task;
+///
+/// task::spawn(async {
+///     let ((), ()) = task::join(
+///         task::sleep(Duration::from_secs(1)),
+///         task::next_physics_frame(),
+///     ).await;
+/// });
+/// ```
+pub fn join<A, B>(a: A, b: B) -> Join<A, B>
+where
+    A: Future,
+    B: Future,
+{
+    Join {
+        a: Box::pin(a),
+        b: Box::pin(b),
+        a_output: None,
+        b_output: None,
+    }
+}
+
+/// Future returned by [`join()`].
+pub struct Join<A: Future, B: Future> {
+    a: Pin<Box<A>>,
+    b: Pin<Box<B>>,
+    a_output: Option<A::Output>,
+    b_output: Option<B::Output>,
+}
+
+// Outputs are never pinned, and the futures are boxed.
+impl<A: Future, B: Future> Unpin for Join<A, B> {}
+
+impl<A: Future, B: Future> Future for Join<A, B> {
+    type Output = (A::Output, B::Output);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        if this.a_output.is_none() {
+            if let Poll::Ready(output) = this.a.as_mut().poll(cx) {
+                this.a_output = Some(output);
+            }
+        }
+
+        if this.b_output.is_none() {
+            if let Poll::Ready(output) = this.b.as_mut().poll(cx) {
+                this.b_output = Some(output);
+            }
+        }
+
+        match (this.a_output.take(), this.b_output.take()) {
+            (Some(a), Some(b)) => Poll::Ready((a, b)),
+            (a, b) => {
+                this.a_output = a;
+                this.b_output = b;
+                Poll::Pending
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// select()
+
+/// Waits for two futures concurrently, resolving to the output of whichever completes first.
+///
+/// The other future is dropped, which cancels it: pending signal futures are disconnected, and `spawn_blocking()` work that has not
+/// started yet is skipped. If both are ready in the same poll, `a` wins.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use godot::task::{self, Either};
+///
+/// task::spawn(async {
+///     match task::select(task::sleep(Duration::from_secs(5)), task::next_frame()).await {
+///         Either::Left(()) => println!("5 seconds passed"),
+///         Either::Right(()) => println!("next frame came first"),
+///     }
+/// });
+/// ```
+pub fn select<A, B>(a: A, b: B) -> Select<A, B>
+where
+    A: Future,
+    B: Future,
+{
+    Select {
+        a: Some(Box::pin(a)),
+        b: Some(Box::pin(b)),
+    }
+}
+
+/// Future returned by [`select()`].
+pub struct Select<A: Future, B: Future> {
+    // Both are set to `None` once one of them completes, so the other one is dropped right away.
+    a: Option<Pin<Box<A>>>,
+    b: Option<Pin<Box<B>>>,
+}
+
+impl<A: Future, B: Future> Future for Select<A, B> {
+    type Output = Either<A::Output, B::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let (Some(a), Some(b)) = (&mut this.a, &mut this.b) else {
+            panic!("Select polled after completion");
+        };
+
+        let output = if let Poll::Ready(output) = a.as_mut().poll(cx) {
+            Either::Left(output)
+        } else if let Poll::Ready(output) = b.as_mut().poll(cx) {
+            Either::Right(output)
+        } else {
+            return Poll::Pending;
+        };
+
+        this.a = None;
+        this.b = None;
+        Poll::Ready(output)
+    }
+}
+
+/// Output of [`select()`], holding the result of the future that completed first.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Either<L, R> {
+    /// The first future completed.
+    Left(L),
+
+    /// The second future completed.
+    Right(R),
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// timeout()
+
+/// Waits for a future, giving up after `duration` has elapsed.
+///
+/// Resolves to `Ok(output)` if `future` completes in time, otherwise to `Err(TimeoutError)`; in the latter case `future` is dropped.
+/// The duration is measured with [`sleep()`], starting when the returned future is first polled (not when `timeout()` is called).
+/// The same precision and pausing rules as for `sleep()` apply.
+///
+/// # Panics
+/// On first poll, in the same cases as [`sleep()`].
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use godot::builtin::Signal;
+/// use godot::classes::Node;
+/// use godot::obj::NewAlloc;
+/// use godot::task;
+///
+/// let node = Node::new_alloc();
+/// let signal = Signal::from_object_signal(&node, "ready");
+///
+/// task::spawn(async move {
+///     match task::timeout(Duration::from_secs(3), signal.to_future::<()>()).await {
+///         Ok(()) => println!("Node is ready."),
+///         Err(e) => println!("{e}"),
+///     }
+/// });
+/// ```
+pub fn timeout<F: Future>(duration: Duration, future: F) -> Timeout<F> {
+    Timeout {
+        future: Some(Box::pin(future)),
+        duration,
+        timer: None,
+    }
+}
+
+/// Future returned by [`timeout()`].
+pub struct Timeout<F: Future> {
+    future: Option<Pin<Box<F>>>,
+    duration: Duration,
+
+    /// Created on first poll, so that the timer doesn't run before the future is awaited.
+    timer: Option<SignalFuture<()>>,
+}
+
+impl<F: Future> Future for Timeout<F> {
+    type Output = Result<F::Output, TimeoutError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let future = this
+            .future
+            .as_mut()
+            .expect("Timeout polled after completion");
+
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            this.future = None;
+            this.timer = None;
+            return Poll::Ready(Ok(output));
+        }
+
+        let duration = this.duration;
+        let timer = this.timer.get_or_insert_with(|| sleep(duration));
+
+        if Pin::new(timer).poll(cx).is_ready() {
+            this.future = None;
+            this.timer = None;
+            return Poll::Ready(Err(TimeoutError));
+        }
+
+        Poll::Pending
+    }
+}
+
+/// Error returned by [`timeout()`] if the future did not complete in time.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct TimeoutError;
+
+impl fmt::Display for TimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "future did not complete before the timeout elapsed")
+    }
+}
+
+impl std::error::Error for TimeoutError {}
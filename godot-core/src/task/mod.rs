@@ -9,16 +9,28 @@
 //!
 //! This module contains:
 //! - Implementations of [`Future`](std::future::Future) for [`Signal`](crate::builtin::Signal) and [`TypedSignal`](crate::registry::signal::TypedSignal).
+//! - Streams of repeated signal emissions via [`SignalStream`].
 //! - A way to [`spawn`] new async tasks by using the engine as the async runtime.
+//! - Awaitable timers and frames ([`sleep`], [`next_frame`], [`next_physics_frame`], [`yield_now`]) and the combinators [`join`],
+//!   [`select`] and [`timeout`].
+//! - Offloading of blocking work to Godot's `WorkerThreadPool` via `spawn_blocking()` and `spawn_on_pool()` (requires `experimental-threads`).
 
 mod async_runtime;
+mod combinators;
 mod futures;
+mod signal_stream;
+mod time;
 
 // Public re-exports
 pub use async_runtime::{spawn, TaskHandle};
+#[cfg(feature = "experimental-threads")]
+pub use async_runtime::{spawn_blocking, spawn_on_pool, BlockingFuture};
+pub use combinators::{join, select, timeout, Either, Join, Select, Timeout, TimeoutError};
 pub use futures::{
     DynamicSend, FallibleSignalFuture, FallibleSignalFutureError, IntoDynamicSend, SignalFuture,
 };
+pub use signal_stream::{SignalStream, SignalStreamNext};
+pub use time::{next_frame, next_physics_frame, sleep, yield_now, YieldNow};
 
 // For use in integration tests.
 #[cfg(feature = "trace")]
@@ -32,7 +44,9 @@ pub use reexport_test::*;
 
 // Crate-local re-exports.
 mod reexport_crate {
-    pub(crate) use super::async_runtime::cleanup;
+    pub(crate) use super::async_runtime::{
+        cleanup, enable_panic_tracking, has_godot_task_panicked,
+    };
     pub(crate) use super::futures::{impl_dynamic_send, ThreadConfined};
 }
 
@@ -0,0 +1,246 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use crate::builtin::{Callable, RustCallable, Signal, Variant};
+use crate::meta::InParamTuple;
+use crate::obj::WithSignals;
+use crate::registry::signal::TypedSignal;
+use crate::sys;
+use crate::task::{DynamicSend, IntoDynamicSend};
+
+/// Asynchronous stream of signal emissions.
+///
+/// Where [`SignalFuture`][crate::task::SignalFuture] resolves once, a `SignalStream` yields the arguments of _every_ emission, in order.
+/// Use [`next()`][Self::next] inside an async task to wait for the next one:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # fn example(area: Gd<Area2D>) {
+/// godot::task::spawn(async move {
+///     let mut bodies = area.signals().body_entered().to_stream();
+///
+///     while let Some((body,)) = bodies.next().await {
+///         godot_print!("Entered: {body}");
+///     }
+///
+///     godot_print!("Area was freed.");
+/// });
+/// # }
+/// ```
+///
+/// Emissions are buffered from the moment the stream is created, so none are lost while the task awaits something else.
+/// The stream ends (yields `None`) once the signal object is freed and all buffered emissions have been consumed.
+///
+/// Dropping the stream disconnects it from the signal. The stream does not keep the signal object alive.
+///
+/// [`poll_next()`][Self::poll_next] has the same signature as `futures::Stream::poll_next()`, so the stream can easily be adapted to
+/// third-party stream combinators.
+///
+/// # Panics
+/// - When yielding an emission whose arguments are `!Send`, if the signal was emitted on a different thread.
+/// - The `Drop` implementation has the same caveats as [`FallibleSignalFuture`][crate::task::FallibleSignalFuture].
+pub struct SignalStream<R: InParamTuple + IntoDynamicSend> {
+    data: Arc<Mutex<SignalStreamData<R::Target>>>,
+    collector: SignalStreamCollector<R>,
+    signal: Signal,
+}
+
+impl<R: InParamTuple + IntoDynamicSend> SignalStream<R> {
+    fn new(signal: Signal) -> Self {
+        sys::strict_assert!(
+            !signal.is_null(),
+            "Failed to create stream for invalid signal:\n\
+            Either the signal object was already freed, or it\n\
+            was not registered in the object before being used.",
+        );
+
+        let data = Arc::new(Mutex::new(SignalStreamData::default()));
+        let collector = SignalStreamCollector { data: data.clone() };
+
+        signal.connect(&Callable::from_custom(collector.clone()));
+
+        Self {
+            data,
+            collector,
+            signal,
+        }
+    }
+
+    /// Returns a future resolving to the next emission, or `None` if the signal object has been freed.
+    pub fn next(&mut self) -> SignalStreamNext<'_, R> {
+        SignalStreamNext { stream: self }
+    }
+
+    /// Polls for the next emission.
+    ///
+    /// Returns `Ready(Some(args))` for a buffered emission, `Ready(None)` once the stream has ended, and `Pending` otherwise.
+    pub fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<R>> {
+        let mut data = self.data.lock().unwrap();
+
+        let Some(value) = data.buffer.pop_front() else {
+            if data.ended {
+                return Poll::Ready(None);
+            }
+
+            data.waker.replace(cx.waker().clone());
+            return Poll::Pending;
+        };
+
+        // Drop the lock before the potential panic, to not poison the mutex.
+        drop(data);
+
+        let Some(value) = DynamicSend::extract_if_safe(value) else {
+            panic!("the streamed signal was not emitted on the main-thread, but contained a non Send argument");
+        };
+
+        Poll::Ready(Some(value))
+    }
+
+    /// Number of emissions buffered, but not yet consumed.
+    pub fn buffered_len(&self) -> usize {
+        self.data.lock().unwrap().buffer.len()
+    }
+}
+
+impl<R: InParamTuple + IntoDynamicSend> Drop for SignalStream<R> {
+    fn drop(&mut self) {
+        // The callable might already be destroyed, this occurs during engine shutdown.
+        if self.signal.is_null() {
+            return;
+        }
+
+        // Same TOCTOU caveat as in FallibleSignalFuture::drop().
+        let gd_callable = Callable::from_custom(self.collector.clone());
+        if !self.signal.is_null() && self.signal.is_connected(&gd_callable) {
+            self.signal.disconnect(&gd_callable);
+        }
+    }
+}
+
+/// Future returned by [`SignalStream::next()`].
+pub struct SignalStreamNext<'s, R: InParamTuple + IntoDynamicSend> {
+    stream: &'s mut SignalStream<R>,
+}
+
+impl<R: InParamTuple + IntoDynamicSend> Future for SignalStreamNext<'_, R> {
+    type Output = Option<R>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.stream).poll_next(cx)
+    }
+}
+
+impl Signal {
+    /// Creates a stream of emissions of this signal.
+    ///
+    /// See [`SignalStream`] for details. Since the `Signal` type does not contain information on the signal argument types, the item
+    /// type has to be inferred from the call to this function.
+    pub fn to_stream<R: InParamTuple + IntoDynamicSend>(&self) -> SignalStream<R> {
+        SignalStream::new(self.clone())
+    }
+}
+
+impl<C: WithSignals, R: InParamTuple + IntoDynamicSend> TypedSignal<'_, C, R> {
+    /// Creates a stream of emissions of this signal.
+    ///
+    /// Each emission is buffered until consumed with [`SignalStream::next()`]. The stream ends when the signal object is freed.
+    pub fn to_stream(&self) -> SignalStream<R> {
+        SignalStream::new(self.to_untyped())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+// Not derived, otherwise an extra bound `T: Default` is required.
+struct SignalStreamData<T> {
+    buffer: VecDeque<T>,
+
+    /// Set once the engine has dropped the connected callable, i.e. the signal object was freed.
+    ended: bool,
+
+    waker: Option<Waker>,
+}
+
+impl<T> Default for SignalStreamData<T> {
+    fn default() -> Self {
+        Self {
+            buffer: VecDeque::new(),
+            ended: false,
+            waker: None,
+        }
+    }
+}
+
+/// Callable connected to the signal, pushing each emission into the stream's buffer.
+struct SignalStreamCollector<R: IntoDynamicSend> {
+    data: Arc<Mutex<SignalStreamData<R::Target>>>,
+}
+
+impl<R: IntoDynamicSend> Clone for SignalStreamCollector<R> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<R: IntoDynamicSend> std::hash::Hash for SignalStreamCollector<R> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(Arc::as_ptr(&self.data) as usize);
+    }
+}
+
+impl<R: IntoDynamicSend> PartialEq for SignalStreamCollector<R> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.data, &other.data)
+    }
+}
+
+impl<R: InParamTuple + IntoDynamicSend> RustCallable for SignalStreamCollector<R> {
+    fn invoke(&mut self, args: &[&Variant]) -> Variant {
+        let waker = {
+            let mut data = self.data.lock().unwrap();
+            data.buffer
+                .push_back(R::from_variant_array(args).into_dynamic_send());
+
+            data.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+
+        Variant::nil()
+    }
+}
+
+impl<R: IntoDynamicSend> Display for SignalStreamCollector<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SignalStreamCollector::<{}>", std::any::type_name::<R>())
+    }
+}
+
+// The engine drops the callable when the signal object is freed (the stream itself keeps another clone until it's dropped).
+// This ends the stream after the remaining buffered emissions.
+impl<R: IntoDynamicSend> Drop for SignalStreamCollector<R> {
+    fn drop(&mut self) {
+        let mut data = self.data.lock().unwrap();
+        data.ended = true;
+
+        if let Some(waker) = data.waker.take() {
+            waker.wake();
+        }
+    }
+}
@@ -0,0 +1,117 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use crate::builtin::Signal;
+use crate::classes::{Engine, SceneTree};
+use crate::obj::{Gd, Singleton};
+use crate::task::SignalFuture;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Public interface
+
+/// Waits for the given duration, using a [`SceneTreeTimer`][crate::classes::SceneTreeTimer].
+///
+/// The timer follows the scene tree: it is paused while the tree is paused and scaled by [`Engine::time_scale`]. Precision is limited to
+/// one frame, i.e. the future resolves at the first process frame after `duration` has elapsed.
+///
+/// Dropping the future (e.g. because the task was [canceled][crate::task::TaskHandle::cancel]) stops waiting; the underlying timer
+/// runs out on its own.
+///
+/// # Panics
+/// - If called from any other thread than the main thread.
+/// - If the main loop is not a [`SceneTree`].
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+///
+/// godot::task::spawn(async {
+///     godot::task::sleep(Duration::from_millis(500)).await;
+///     println!("Half a second later.");
+/// });
+/// ```
+///
+/// [`Engine::time_scale`]: crate::classes::Engine::get_time_scale
+pub fn sleep(duration: Duration) -> SignalFuture<()> {
+    let timer = scene_tree("sleep")
+        .create_timer(duration.as_secs_f64())
+        .expect("SceneTree::create_timer() returned null");
+
+    Signal::from_object_signal(&timer, "timeout").to_future()
+}
+
+/// Waits until the next process frame, i.e. the next emission of [`SceneTree::process_frame`][crate::classes::SceneTree].
+///
+/// # Panics
+/// - If called from any other thread than the main thread.
+/// - If the main loop is not a [`SceneTree`].
+pub fn next_frame() -> SignalFuture<()> {
+    let tree = scene_tree("next_frame");
+
+    Signal::from_object_signal(&tree, "process_frame").to_future()
+}
+
+/// Waits until the next physics frame, i.e. the next emission of [`SceneTree::physics_frame`][crate::classes::SceneTree].
+///
+/// # Panics
+/// - If called from any other thread than the main thread.
+/// - If the main loop is not a [`SceneTree`].
+pub fn next_physics_frame() -> SignalFuture<()> {
+    let tree = scene_tree("next_physics_frame");
+
+    Signal::from_object_signal(&tree, "physics_frame").to_future()
+}
+
+/// Yields control back to the async runtime, resuming the task at the end of the current frame.
+///
+/// Useful to split long-running work into several steps, without waiting a whole frame. Unlike [`next_frame()`], this does not depend on
+/// the scene tree.
+pub fn yield_now() -> YieldNow {
+    YieldNow { yielded: false }
+}
+
+/// Future returned by [`yield_now()`].
+pub struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            return Poll::Ready(());
+        }
+
+        // The async runtime polls woken tasks deferred, so this resumes at the end of the frame.
+        self.yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn scene_tree(function_name: &str) -> Gd<SceneTree> {
+    assert!(
+        crate::init::is_main_thread(),
+        "task::{function_name}() can only be used on the main thread"
+    );
+
+    Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+        .unwrap_or_else(|| {
+            panic!("task::{function_name}() requires the main loop to be a SceneTree")
+        })
+}
@@ -0,0 +1,304 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! `cargo test` side of the harness: launches Godot and collects the results. Does not require a running engine.
+
+use std::fmt;
+use std::path::PathBuf;
+use std::process::Command;
+
+use super::runner::{FOCUS_MARKER, OUTCOME_SEPARATOR, SUMMARY_PREFIX, TEST_PREFIX};
+use super::RUN_TESTS_ARG;
+
+/// Launches a headless Godot process that runs all `#[godot::test]` functions of the extensions loaded by a project.
+///
+/// The project directory must contain a `project.godot` and a `.gdextension` file pointing to your compiled library. Its main scene is
+/// loaded as usual; use [`scene()`][Self::scene] to start a different (e.g. empty) scene instead.
+///
+/// See the [module documentation](super) for an example.
+#[derive(Clone, Debug)]
+pub struct HeadlessRunner {
+    godot_binary: Option<PathBuf>,
+    project_dir: PathBuf,
+    scene: Option<String>,
+    filters: Vec<String>,
+}
+
+impl HeadlessRunner {
+    /// Creates a runner for the Godot project in `project_dir`.
+    pub fn new(project_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            godot_binary: None,
+            project_dir: project_dir.into(),
+            scene: None,
+            filters: vec![],
+        }
+    }
+
+    /// Path to the Godot executable.
+    ///
+    /// If not set, the `GODOT4_BIN` environment variable is used, falling back to `godot4` in the `PATH`.
+    pub fn godot_binary(mut self, path: impl Into<PathBuf>) -> Self {
+        self.godot_binary = Some(path.into());
+        self
+    }
+
+    /// Scene to run instead of the project's main scene, e.g. `"res://tests/Empty.tscn"`.
+    pub fn scene(mut self, scene_path: impl Into<String>) -> Self {
+        self.scene = Some(scene_path.into());
+        self
+    }
+
+    /// Only runs tests whose name contains `pattern`. Can be called multiple times; a test runs if it matches any pattern.
+    pub fn filter(mut self, pattern: impl Into<String>) -> Self {
+        self.filters.push(pattern.into());
+        self
+    }
+
+    /// Runs Godot until all tests have completed, and returns the parsed results.
+    ///
+    /// Godot's output is forwarded to stdout/stderr, so that `cargo test` shows it for failing tests.
+    ///
+    /// # Panics
+    /// If the Godot executable cannot be launched.
+    pub fn run(self) -> TestReport {
+        let godot_binary = self.godot_binary.unwrap_or_else(|| {
+            std::env::var_os("GODOT4_BIN")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from("godot4"))
+        });
+
+        let mut cmd = Command::new(&godot_binary);
+        cmd.arg("--headless").arg("--path").arg(&self.project_dir);
+        if let Some(scene) = &self.scene {
+            cmd.arg(scene);
+        }
+        cmd.arg("--").arg(RUN_TESTS_ARG).args(&self.filters);
+
+        let output = cmd.output().unwrap_or_else(|e| {
+            panic!(
+                "failed to launch Godot executable '{}': {e}\n\
+                Set GODOT4_BIN or call HeadlessRunner::godot_binary().",
+                godot_binary.display()
+            )
+        });
+
+        let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+        let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+        print!("{stdout}");
+        eprint!("{stderr}");
+
+        let mut report = TestReport::parse(&stdout);
+        report.exit_code = output.status.code();
+        report
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Results of a test run started by [`HeadlessRunner`].
+#[derive(Clone, Debug, Default)]
+pub struct TestReport {
+    /// Individual test results, in the order they were run.
+    pub results: Vec<TestResult>,
+
+    /// Exit code of the Godot process, or `None` if it was terminated by a signal.
+    pub exit_code: Option<i32>,
+
+    /// Whether only tests marked with `focus` were run.
+    pub focus_run: bool,
+
+    /// Whether the run completed, i.e. a summary line was printed. `false` typically means that Godot crashed, or the extension was
+    /// not loaded.
+    pub completed: bool,
+}
+
+impl TestReport {
+    fn parse(stdout: &str) -> Self {
+        let mut report = Self::default();
+
+        for line in stdout.lines() {
+            if let Some(summary) = line.strip_prefix(SUMMARY_PREFIX) {
+                report.completed = true;
+                report.focus_run = summary.ends_with(FOCUS_MARKER);
+            } else if let Some(result) = line.strip_prefix(TEST_PREFIX) {
+                let Some((name, outcome)) = result.rsplit_once(OUTCOME_SEPARATOR) else {
+                    continue;
+                };
+
+                if let Some(outcome) = TestOutcome::from_label(outcome) {
+                    report.results.push(TestResult {
+                        name: name.to_string(),
+                        outcome,
+                    });
+                }
+            }
+        }
+
+        report
+    }
+
+    /// Tests that failed.
+    pub fn failed(&self) -> impl Iterator<Item = &TestResult> {
+        self.with_outcome(TestOutcome::Failed)
+    }
+
+    /// Tests that passed.
+    pub fn passed(&self) -> impl Iterator<Item = &TestResult> {
+        self.with_outcome(TestOutcome::Passed)
+    }
+
+    /// Whether the run completed without failed tests, and Godot exited successfully.
+    pub fn is_success(&self) -> bool {
+        self.completed && self.exit_code == Some(0) && self.failed().next().is_none()
+    }
+
+    /// Panics with a summary of failed tests, unless [`is_success()`][Self::is_success] holds.
+    pub fn assert_success(&self) {
+        if self.is_success() {
+            return;
+        }
+
+        if !self.completed {
+            panic!(
+                "Godot test run did not complete (exit code {:?}); is the extension loaded by the project?",
+                self.exit_code
+            );
+        }
+
+        let failed = self
+            .failed()
+            .map(|test| format!("  * {}", test.name))
+            .collect::<Vec<_>>();
+
+        panic!(
+            "{} of {} Godot tests failed (exit code {:?}):\n{}",
+            failed.len(),
+            self.results.len(),
+            self.exit_code,
+            failed.join("\n")
+        );
+    }
+
+    fn with_outcome(&self, outcome: TestOutcome) -> impl Iterator<Item = &TestResult> {
+        self.results
+            .iter()
+            .filter(move |test| test.outcome == outcome)
+    }
+}
+
+/// Result of a single test inside a [`TestReport`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TestResult {
+    pub name: String,
+    pub outcome: TestOutcome,
+}
+
+/// Outcome of a single test.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TestOutcome {
+    Passed,
+    Failed,
+
+    /// Test was marked with `skip`.
+    Ignored,
+}
+
+impl TestOutcome {
+    pub(super) fn from_success(success: bool) -> Self {
+        if success {
+            Self::Passed
+        } else {
+            Self::Failed
+        }
+    }
+
+    pub(super) fn label(self) -> &'static str {
+        match self {
+            Self::Passed => "ok",
+            Self::Failed => "FAILED",
+            Self::Ignored => "ignored",
+        }
+    }
+
+    fn from_label(label: &str) -> Option<Self> {
+        match label {
+            "ok" => Some(Self::Passed),
+            "FAILED" => Some(Self::Failed),
+            "ignored" => Some(Self::Ignored),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TestOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::{TestOutcome, TestReport};
+
+    #[test]
+    fn report_parses_runner_output() {
+        let stdout = "\
+Godot Engine v4.4.stable.official - https://godotengine.org
+
+running 3 tests
+test vector_length ... ok
+some output from a test ... not a result
+test broken ... FAILED
+test later ... ignored
+
+failures:
+    broken (src/lib.rs:12)
+
+test result: FAILED. 1 passed; 1 failed; 1 ignored
+";
+
+        let report = TestReport::parse(stdout);
+        assert!(report.completed);
+        assert!(!report.focus_run);
+
+        let outcomes = report
+            .results
+            .iter()
+            .map(|test| (test.name.as_str(), test.outcome))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            outcomes,
+            [
+                ("vector_length", TestOutcome::Passed),
+                ("broken", TestOutcome::Failed),
+                ("later", TestOutcome::Ignored),
+            ]
+        );
+        assert!(!report.is_success());
+    }
+
+    #[test]
+    fn report_detects_incomplete_run() {
+        let mut report = TestReport::parse("running 1 tests\ntest first ... ok\n");
+        report.exit_code = Some(0);
+
+        assert!(!report.completed);
+        assert!(!report.is_success());
+
+        let mut report = TestReport::parse(
+            "running 1 tests\ntest first ... ok\n\ntest result: ok. 1 passed; 0 failed; 0 ignored (focused run)\n",
+        );
+        report.exit_code = Some(0);
+
+        assert!(report.focus_run);
+        assert!(report.is_success());
+    }
+}
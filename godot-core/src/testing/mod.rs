@@ -0,0 +1,86 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Running Rust tests inside a headless Godot process, driven from `cargo test`.
+//!
+//! See `godot::testing` for an overview.
+
+mod headless;
+mod runner;
+
+pub use headless::{HeadlessRunner, TestOutcome, TestReport, TestResult};
+pub(crate) use runner::on_scene_init;
+#[doc(hidden)]
+pub use runner::{run_async_test, run_sync_test};
+
+use crate::classes::Node;
+use crate::obj::Gd;
+use crate::sys;
+use crate::task::TaskHandle;
+
+/// Command-line user argument (after `--`) that instructs the extension to run its `#[godot::test]` functions.
+pub const RUN_TESTS_ARG: &str = "--godot-rust-test";
+
+/// State available to each test, which can be accessed by adding a `ctx: &TestContext` parameter.
+#[derive(Clone)]
+pub struct TestContext {
+    /// Node inside the scene tree, which is freed after all tests have run. Can be used as a parent for nodes under test.
+    pub scene_tree: Gd<Node>,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Registration (used by proc-macro)
+
+sys::plugin_registry!(pub __GODOT_TEST: TestCase);
+
+/// Test registered with `#[godot::test]`.
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub struct TestCase {
+    pub name: &'static str,
+    pub file: &'static str,
+    pub line: u32,
+    pub skipped: bool,
+    pub focused: bool,
+    pub function: TestFunction,
+}
+
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub enum TestFunction {
+    Sync(fn(&TestContext)),
+    Async(fn(&TestContext) -> TaskHandle),
+}
+
+/// Finds all `#[godot::test]` tests matching the filters, honoring `focus`.
+///
+/// Returns the tests sorted by location, and whether this is a focused run.
+fn collect_tests(filters: &[String]) -> (Vec<TestCase>, bool) {
+    let mut tests: Vec<TestCase> = vec![];
+    let mut is_focus_run = false;
+
+    sys::plugin_foreach!(__GODOT_TEST; |test: &TestCase| {
+        // First time a focused test is encountered, switch to "focused" mode and throw everything away.
+        if !is_focus_run && test.focused {
+            tests.clear();
+            is_focus_run = true;
+        }
+
+        if (!is_focus_run || test.focused) && passes_filter(filters, test.name) {
+            tests.push(*test);
+        }
+    });
+
+    // Sort for deterministic run order.
+    tests.sort_by_key(|test| (test.file, test.line));
+
+    (tests, is_focus_run)
+}
+
+fn passes_filter(filters: &[String], test_name: &str) -> bool {
+    filters.is_empty() || filters.iter().any(|x| test_name.contains(x))
+}
@@ -0,0 +1,230 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Engine-side part of the harness: runs registered tests and prints results in a format understood by [`super::headless`].
+
+use std::panic::UnwindSafe;
+
+use crate::builtin::Callable;
+use crate::classes::object::ConnectFlags;
+use crate::classes::{Engine, Node, Os, SceneTree};
+use crate::obj::{Gd, NewAlloc, Singleton};
+use crate::task::{enable_panic_tracking, has_godot_task_panicked, TaskHandle};
+use crate::testing::{
+    collect_tests, TestCase, TestContext, TestFunction, TestOutcome, RUN_TESTS_ARG,
+};
+
+// Line prefixes shared with the parser in `headless.rs`. They follow libtest's output, so results look familiar.
+pub(super) const TEST_PREFIX: &str = "test ";
+pub(super) const OUTCOME_SEPARATOR: &str = " ... ";
+pub(super) const SUMMARY_PREFIX: &str = "test result: ";
+pub(super) const FOCUS_MARKER: &str = "(focused run)";
+
+/// Called when the `Scene` init level is loaded. Schedules a test run if the engine was started by [`HeadlessRunner`][super::HeadlessRunner].
+pub(crate) fn on_scene_init() {
+    let args = Os::singleton().get_cmdline_user_args();
+    let args: Vec<String> = args.as_slice().iter().map(|arg| arg.to_string()).collect();
+
+    let Some(pos) = args.iter().position(|arg| arg == RUN_TESTS_ARG) else {
+        return;
+    };
+
+    let mut filters: Vec<String> = args[pos + 1..]
+        .iter()
+        .filter(|arg| !arg.starts_with("--"))
+        .cloned()
+        .collect();
+
+    // The scene tree does not exist yet during init; deferred calls are flushed once the main loop runs.
+    let callable = Callable::from_fn("godot_rust_test_start", move |_| {
+        start_test_run(std::mem::take(&mut filters));
+    });
+    callable.call_deferred(&[]);
+}
+
+fn start_test_run(filters: Vec<String>) {
+    let Some(mut tree) = Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+    else {
+        eprintln!("{RUN_TESTS_ARG}: main loop is not a SceneTree, cannot run tests.");
+        return;
+    };
+
+    let (tests, focus_run) = collect_tests(&filters);
+    println!("\nrunning {} tests", tests.len());
+
+    let mut node = Node::new_alloc();
+    node.set_name("GodotRustTests");
+    tree.get_root()
+        .expect("scene tree has a root window")
+        .add_child(&node);
+
+    let run = TestRun {
+        tests: tests.into_iter(),
+        ctx: TestContext { scene_tree: node },
+        tree,
+        focus_run,
+        passed: 0,
+        ignored: 0,
+        failed: vec![],
+    };
+
+    run.run_next();
+}
+
+/// State of an ongoing test run, passed along across frames while async tests are pending.
+struct TestRun {
+    tests: std::vec::IntoIter<TestCase>,
+    ctx: TestContext,
+    tree: Gd<SceneTree>,
+    focus_run: bool,
+    passed: usize,
+    ignored: usize,
+    failed: Vec<String>,
+}
+
+impl TestRun {
+    fn run_next(mut self) {
+        while let Some(test) = self.tests.next() {
+            if test.skipped {
+                self.record(&test, TestOutcome::Ignored);
+                continue;
+            }
+
+            match test.function {
+                TestFunction::Sync(function) => {
+                    let err_context = || format!("test `{}` failed", test.name);
+                    let success = run_sync_test(err_context, || function(&self.ctx));
+                    self.record(&test, TestOutcome::from_success(success));
+                }
+                TestFunction::Async(function) => {
+                    let ctx = self.ctx.clone();
+                    let tree = self.tree.clone();
+
+                    return run_async_test(
+                        || format!("test `{}` failed", test.name),
+                        tree,
+                        || function(&ctx),
+                        move |success| {
+                            self.record(&test, TestOutcome::from_success(success));
+                            self.run_next();
+                        },
+                    );
+                }
+            }
+        }
+
+        self.finish();
+    }
+
+    fn record(&mut self, test: &TestCase, outcome: TestOutcome) {
+        match outcome {
+            TestOutcome::Passed => self.passed += 1,
+            TestOutcome::Failed => self
+                .failed
+                .push(format!("{} ({}:{})", test.name, test.file, test.line)),
+            TestOutcome::Ignored => self.ignored += 1,
+        }
+
+        println!(
+            "{TEST_PREFIX}{}{OUTCOME_SEPARATOR}{}",
+            test.name,
+            outcome.label()
+        );
+    }
+
+    fn finish(self) {
+        let Self {
+            ctx,
+            mut tree,
+            focus_run,
+            passed,
+            ignored,
+            failed,
+            ..
+        } = self;
+
+        ctx.scene_tree.free();
+
+        if !failed.is_empty() {
+            println!("\nfailures:");
+            for test in failed.iter() {
+                println!("    {test}");
+            }
+        }
+
+        let result = if failed.is_empty() { "ok" } else { "FAILED" };
+        let focus = if focus_run {
+            format!(" {FOCUS_MARKER}")
+        } else {
+            String::new()
+        };
+
+        println!(
+            "\n{SUMMARY_PREFIX}{result}. {passed} passed; {} failed; {ignored} ignored{focus}",
+            failed.len()
+        );
+
+        let exit_code = if failed.is_empty() { 0 } else { 1 };
+        tree.quit_ex().exit_code(exit_code).done();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Test execution, shared with the integration tests of godot-rust itself
+
+/// Runs a synchronous test, catching panics. Returns whether the test passed.
+///
+/// `err_context` appears in all panics, but those inside `expect_panic()` are suppressed. So the "failed" message will only appear for
+/// unexpected panics, where tests indeed fail.
+#[doc(hidden)]
+pub fn run_sync_test(
+    err_context: impl Fn() -> String,
+    function: impl FnOnce() + UnwindSafe,
+) -> bool {
+    // Explicit type to prevent tests from returning a value.
+    let result: Result<(), _> = crate::private::handle_panic(err_context, function);
+    result.is_ok()
+}
+
+/// Starts an async test and invokes `on_finished` with whether it passed, once its task has completed.
+///
+/// The task is checked once per frame of `tree`, so other tests only run after this one has finished.
+#[doc(hidden)]
+pub fn run_async_test(
+    err_context: impl Fn() -> String,
+    tree: Gd<SceneTree>,
+    function: impl FnOnce() -> TaskHandle + UnwindSafe,
+    on_finished: impl FnOnce(bool) + 'static,
+) {
+    // Needed to detect panics inside the task; only test processes pay for the tracking.
+    enable_panic_tracking();
+
+    match crate::private::handle_panic(err_context, function) {
+        Ok(task_handle) => await_task(tree, task_handle, on_finished),
+        Err(_) => on_finished(false),
+    }
+}
+
+fn await_task(
+    mut tree: Gd<SceneTree>,
+    task_handle: TaskHandle,
+    on_finished: impl FnOnce(bool) + 'static,
+) {
+    if !task_handle.is_pending() {
+        return on_finished(!has_godot_task_panicked(task_handle));
+    }
+
+    let mut state = Some((tree.clone(), task_handle, on_finished));
+    let callable = Callable::from_fn("godot_rust_test_await", move |_| {
+        let (tree, task_handle, on_finished) = state.take().expect("callable is only invoked once");
+        await_task(tree, task_handle, on_finished);
+    });
+
+    tree.connect_flags("process_frame", &callable, ConnectFlags::ONE_SHOT);
+}
@@ -0,0 +1,227 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::GString;
+use crate::classes::DirAccess;
+use crate::global::Error;
+use crate::meta::{arg_into_ref, AsArg};
+use crate::obj::Gd;
+
+/// Open a directory to list or modify its contents.
+///
+/// This is the directory counterpart to [`GFile`](super::GFile), wrapping a [`DirAccess`] pointer. It works with all paths supported by
+/// Godot, including `res://` inside exported `.pck` packs, and `user://`.
+///
+/// Directory contents are listed with [`entries()`](Self::entries), which returns a Rust iterator. By default, hidden entries are
+/// skipped, as are the navigational entries `.` and `..`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use godot::tools::GDir;
+///
+/// fn list_levels() -> std::io::Result<Vec<String>> {
+///     let mut dir = GDir::open("res://levels")?;
+///
+///     let levels = dir
+///         .entries()?
+///         .filter(|entry| entry.is_file() && entry.name().to_string().ends_with(".tscn"))
+///         .map(|entry| entry.path().to_string())
+///         .collect();
+///
+///     Ok(levels)
+/// }
+/// ```
+///
+/// ## See also
+///
+/// - [`DirAccess`] class in Rust.
+/// - [Godot documentation](https://docs.godotengine.org/en/stable/classes/class_diraccess.html) for `DirAccess`.
+pub struct GDir {
+    da: Gd<DirAccess>,
+}
+
+impl GDir {
+    /// Open a directory.
+    ///
+    /// Opens the directory located at `path`, creating new [`GDir`] object.
+    pub fn open(path: impl AsArg<GString>) -> std::io::Result<Self> {
+        arg_into_ref!(path);
+
+        let da = DirAccess::open(path).ok_or_else(|| {
+            std::io::Error::other(format!(
+                "can't open directory {path}; GodotError: {:?}",
+                DirAccess::get_open_error()
+            ))
+        })?;
+
+        Ok(Self { da })
+    }
+
+    /// Retrieve inner pointer to the [`DirAccess`].
+    pub fn into_inner(self) -> Gd<DirAccess> {
+        self.da
+    }
+
+    /// Returns an iterator over the entries of the current directory.
+    ///
+    /// Entries are returned in the order provided by the file system, which is not necessarily sorted. The directory must not be changed
+    /// while iterating, which is ensured by the exclusive borrow.
+    ///
+    /// Underlying Godot methods:
+    /// [`DirAccess::list_dir_begin`](https://docs.godotengine.org/en/stable/classes/class_diraccess.html#class-diraccess-method-list-dir-begin),
+    /// [`DirAccess::get_next`](https://docs.godotengine.org/en/stable/classes/class_diraccess.html#class-diraccess-method-get-next).
+    #[doc(alias = "list_dir_begin")]
+    pub fn entries(&mut self) -> std::io::Result<GDirEntries<'_>> {
+        let error = self.da.list_dir_begin();
+        check_error(error, "can't list directory", &self.path())?;
+
+        Ok(GDirEntries {
+            dir_path: self.path(),
+            da: &mut self.da,
+        })
+    }
+
+    /// Get path of the current directory.
+    #[doc(alias = "get_current_dir")]
+    pub fn path(&self) -> GString {
+        self.da.get_current_dir()
+    }
+
+    /// Changes the current directory to `path`, which can be relative to the current one.
+    #[doc(alias = "change_dir")]
+    pub fn change_dir(&mut self, path: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(path);
+
+        let error = self.da.change_dir(path);
+        check_error(error, "can't change directory to", path)
+    }
+
+    /// Checks whether a file exists at `path`, which can be relative to the current directory.
+    pub fn file_exists(&mut self, path: impl AsArg<GString>) -> bool {
+        arg_into_ref!(path);
+        self.da.file_exists(path)
+    }
+
+    /// Checks whether a directory exists at `path`, which can be relative to the current directory.
+    pub fn dir_exists(&mut self, path: impl AsArg<GString>) -> bool {
+        arg_into_ref!(path);
+        self.da.dir_exists(path)
+    }
+
+    /// Creates the directory at `path`, including all missing parent directories.
+    #[doc(alias = "make_dir_recursive")]
+    pub fn create_dir_all(&mut self, path: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(path);
+
+        let error = self.da.make_dir_recursive(path);
+        check_error(error, "can't create directory", path)
+    }
+
+    /// Removes the file or empty directory at `path`.
+    #[doc(alias = "remove")]
+    pub fn remove(&mut self, path: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(path);
+
+        let error = self.da.remove(path);
+        check_error(error, "can't remove", path)
+    }
+
+    /// Whether hidden entries are listed by [`entries()`](Self::entries). Default is `false`.
+    #[doc(alias = "set_include_hidden")]
+    pub fn set_include_hidden(&mut self, include: bool) {
+        self.da.set_include_hidden(include);
+    }
+
+    /// Check whether hidden entries are listed by [`entries()`](Self::entries).
+    #[doc(alias = "get_include_hidden")]
+    pub fn include_hidden(&self) -> bool {
+        self.da.get_include_hidden()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Iterator over the entries of a directory, returned by [`GDir::entries()`].
+pub struct GDirEntries<'a> {
+    da: &'a mut Gd<DirAccess>,
+    dir_path: GString,
+}
+
+impl Iterator for GDirEntries<'_> {
+    type Item = GDirEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let name = self.da.get_next();
+        if name.is_empty() {
+            return None;
+        }
+
+        Some(GDirEntry {
+            is_dir: self.da.current_is_dir(),
+            path: join_path(&self.dir_path, &name),
+            name,
+        })
+    }
+}
+
+impl Drop for GDirEntries<'_> {
+    fn drop(&mut self) {
+        self.da.list_dir_end();
+    }
+}
+
+/// Entry of a directory listing, see [`GDir::entries()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GDirEntry {
+    name: GString,
+    path: GString,
+    is_dir: bool,
+}
+
+impl GDirEntry {
+    /// File or directory name, without path.
+    pub fn name(&self) -> &GString {
+        &self.name
+    }
+
+    /// Full path of the entry, e.g. `res://levels/forest.tscn`.
+    pub fn path(&self) -> &GString {
+        &self.path
+    }
+
+    /// Whether the entry is a directory.
+    pub fn is_dir(&self) -> bool {
+        self.is_dir
+    }
+
+    /// Whether the entry is a file.
+    pub fn is_file(&self) -> bool {
+        !self.is_dir
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+fn check_error(error: Error, message: &str, path: &GString) -> std::io::Result<()> {
+    if error == Error::OK {
+        return Ok(());
+    }
+
+    Err(std::io::Error::other(format!(
+        "{message} {path}; GodotError: {error:?}"
+    )))
+}
+
+fn join_path(dir: &GString, name: &GString) -> GString {
+    let dir = dir.to_string();
+    if dir.ends_with('/') {
+        GString::from(format!("{dir}{name}").as_str())
+    } else {
+        GString::from(format!("{dir}/{name}").as_str())
+    }
+}
@@ -30,6 +30,10 @@ use crate::obj::Gd;
 /// - Implements useful Rust traits, namely: [`Read`], [`BufRead`], [`Write`], [`Seek`], allowing better file processing
 ///   and integrating it with various tools in the Rust ecosystem (e.g. serialization).
 ///
+/// Reads through [`Read`] and [`BufRead`] are buffered internally, so wrapping a `GFile` in a [`BufReader`](std::io::BufReader) is not
+/// necessary. Typed methods like [`read_u32()`](Self::read_u32), writes, seeking and [`position()`](Self::position) take the buffer into
+/// account, so all of them can be mixed freely.
+///
 /// Files by default are always opened with little-endian, as most files are saved as such. To switch to big-endian, use
 /// [`GFile::set_big_endian()`].
 ///
@@ -89,13 +93,14 @@ use crate::obj::Gd;
 pub struct GFile {
     fa: Gd<FileAccess>,
     buffer: Vec<u8>,
-    last_buffer_size: usize,
+    buffer_pos: usize,
+    buffer_len: usize,
     write_buffer: PackedByteArray,
     file_length: Option<u64>,
 }
 
 impl GFile {
-    // For now - only used internally in BufRead implementation. If needed, its setting could be exposed in some way.
+    // Size of the read buffer used by `Read` and `BufRead`. If needed, its setting could be exposed in some way.
     const BUFFER_SIZE: usize = 4096;
 
     /// Open a file.
@@ -198,7 +203,8 @@ impl GFile {
     /// pointing to it. Its state is retained: both [`ModeFlags`] with which it was opened and current internal cursor position.
     ///
     /// See also [`try_from_unique`](Self::try_from_unique) for the opposite operation.
-    pub fn into_inner(self) -> Gd<FileAccess> {
+    pub fn into_inner(mut self) -> Gd<FileAccess> {
+        self.discard_read_buffer();
         self.fa
     }
 
@@ -256,6 +262,7 @@ impl GFile {
     /// [`FileAccess::get_8`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-get-8).
     #[doc(alias = "get_8")]
     pub fn read_u8(&mut self) -> std::io::Result<u8> {
+        self.discard_read_buffer();
         let val = self.fa.get_8();
         self.check_error()?;
         Ok(val)
@@ -267,6 +274,7 @@ impl GFile {
     /// [`FileAccess::get_16`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-get-16).
     #[doc(alias = "get_16")]
     pub fn read_u16(&mut self) -> std::io::Result<u16> {
+        self.discard_read_buffer();
         let val = self.fa.get_16();
         self.check_error()?;
         Ok(val)
@@ -278,6 +286,7 @@ impl GFile {
     /// [`FileAccess::get_32`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-get-32).
     #[doc(alias = "get_32")]
     pub fn read_u32(&mut self) -> std::io::Result<u32> {
+        self.discard_read_buffer();
         let val = self.fa.get_32();
         self.check_error()?;
         Ok(val)
@@ -289,6 +298,7 @@ impl GFile {
     /// [`FileAccess::get_64`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-get-64).
     #[doc(alias = "get_64")]
     pub fn read_u64(&mut self) -> std::io::Result<u64> {
+        self.discard_read_buffer();
         let val = self.fa.get_64();
         self.check_error()?;
         Ok(val)
@@ -304,6 +314,7 @@ impl GFile {
     /// - [Godot `FileAccess::get_pascal_string`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-get-pascal-string)
     #[doc(alias = "get_pascal_string")]
     pub fn read_pascal_string(&mut self) -> std::io::Result<GString> {
+        self.discard_read_buffer();
         let val = self.fa.get_pascal_string();
         self.check_error()?;
         Ok(val)
@@ -318,6 +329,7 @@ impl GFile {
     /// [`FileAccess::get_line`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-get-line).
     #[doc(alias = "get_line")]
     pub fn read_gstring_line(&mut self) -> std::io::Result<GString> {
+        self.discard_read_buffer();
         let val = self.fa.get_line();
         self.check_error()?;
         Ok(val)
@@ -336,6 +348,7 @@ impl GFile {
     #[doc(alias = "get_as_text")]
     #[cfg(before_api = "4.6")]
     pub fn read_as_gstring_entire(&mut self, skip_cr: bool) -> std::io::Result<GString> {
+        self.discard_read_buffer();
         let val = self.fa.get_as_text_ex().skip_cr(skip_cr).done();
         self.check_error()?;
         Ok(val)
@@ -351,6 +364,7 @@ impl GFile {
     #[doc(alias = "get_as_text")]
     #[cfg(since_api = "4.6")]
     pub fn read_as_gstring_entire(&mut self) -> std::io::Result<GString> {
+        self.discard_read_buffer();
         let val = self.fa.get_as_text();
         self.check_error()?;
         Ok(val)
@@ -367,6 +381,7 @@ impl GFile {
         &mut self,
         delim: impl AsArg<GString>,
     ) -> std::io::Result<PackedStringArray> {
+        self.discard_read_buffer();
         arg_into_ref!(delim);
 
         // FIXME: pass by-ref
@@ -381,6 +396,7 @@ impl GFile {
     /// [`FileAccess::get_float`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-get-float).
     #[doc(alias = "get_float")]
     pub fn read_f32(&mut self) -> std::io::Result<f32> {
+        self.discard_read_buffer();
         let val = self.fa.get_float();
         self.check_error()?;
         Ok(val)
@@ -392,6 +408,7 @@ impl GFile {
     /// [`FileAccess::get_double`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-get-double).
     #[doc(alias = "get_double")]
     pub fn read_f64(&mut self) -> std::io::Result<f64> {
+        self.discard_read_buffer();
         let val = self.fa.get_double();
         self.check_error()?;
         Ok(val)
@@ -414,6 +431,7 @@ impl GFile {
     /// </div>
     #[doc(alias = "get_real")]
     pub fn read_real(&mut self) -> std::io::Result<real> {
+        self.discard_read_buffer();
         #[cfg(feature = "double-precision")]
         let val = self.fa.get_double();
 
@@ -437,17 +455,72 @@ impl GFile {
     /// [`FileAccess::get_var`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-get-var).
     #[doc(alias = "get_var")]
     pub fn read_variant(&mut self, allow_objects: bool) -> std::io::Result<Variant> {
+        self.discard_read_buffer();
         let val = self.fa.get_var_ex().allow_objects(allow_objects).done();
         self.check_error()?;
         Ok(val)
     }
 
+    /// Reads all remaining bytes, from the current position to the end of the file.
+    ///
+    /// The remaining content is fetched from Godot in one piece and copied once into the returned vector, which is considerably faster than
+    /// reading in chunks -- especially for files inside `.pck` packs. [`Read::read_to_end()`] uses this method, too.
+    ///
+    /// Underlying Godot method:
+    /// [`FileAccess::get_buffer`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-get-buffer).
+    pub fn read_all_bytes(&mut self) -> std::io::Result<Vec<u8>> {
+        let remaining = self
+            .check_file_length()
+            .saturating_sub(self.fa.get_position()) as usize;
+
+        // Bytes already in the read buffer come first; the file cursor is located after them.
+        let mut bytes = Vec::with_capacity(self.buffered_len() + remaining);
+        bytes.extend_from_slice(&self.buffer[self.buffer_pos..self.buffer_len]);
+        self.buffer_pos = 0;
+        self.buffer_len = 0;
+
+        if remaining > 0 {
+            let gd_buffer = self.fa.get_buffer(remaining as i64);
+            self.check_error()?;
+            bytes.extend_from_slice(gd_buffer.as_slice());
+        }
+
+        Ok(bytes)
+    }
+
+    /// Reads the entire file at `path` on a worker thread.
+    ///
+    /// Opens the file in [`ModeFlags::READ`] and returns its content via [`read_all_bytes()`](Self::read_all_bytes). The returned future
+    /// needs to be awaited in a task started with [`task::spawn()`](crate::task::spawn); see [`task::spawn_blocking()`] for details.
+    ///
+    /// ```no_run
+    /// use godot::tools::GFile;
+    ///
+    /// godot::task::spawn(async {
+    ///     let bytes = GFile::read_file_async("res://levels/huge.bin").await;
+    ///     println!("Loaded {} bytes", bytes.expect("read level").len());
+    /// });
+    /// ```
+    ///
+    /// [`task::spawn_blocking()`]: crate::task::spawn_blocking
+    #[cfg(feature = "experimental-threads")]
+    pub fn read_file_async(
+        path: impl AsArg<GString>,
+    ) -> crate::task::BlockingFuture<std::io::Result<Vec<u8>>> {
+        arg_into_ref!(path);
+
+        // Godot strings cannot be sent to other threads.
+        let path = path.to_string();
+        crate::task::spawn_blocking(move || GFile::open(&path, ModeFlags::READ)?.read_all_bytes())
+    }
+
     /// Writes [`u8`] as the next byte in the file.
     ///
     /// Underlying Godot method:
     /// [`FileAccess::store_8`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-store-8).
     #[doc(alias = "store_8")]
     pub fn write_u8(&mut self, value: u8) -> std::io::Result<()> {
+        self.discard_read_buffer();
         self.fa.store_8(value);
         self.clear_file_length();
         self.check_error()?;
@@ -460,6 +533,7 @@ impl GFile {
     /// [`FileAccess::store_16`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-store-16).
     #[doc(alias = "store_16")]
     pub fn write_u16(&mut self, value: u16) -> std::io::Result<()> {
+        self.discard_read_buffer();
         self.fa.store_16(value);
         self.clear_file_length();
         self.check_error()?;
@@ -472,6 +546,7 @@ impl GFile {
     /// [`FileAccess::store_32`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-store-32).
     #[doc(alias = "store_32")]
     pub fn write_u32(&mut self, value: u32) -> std::io::Result<()> {
+        self.discard_read_buffer();
         self.fa.store_32(value);
         self.clear_file_length();
         self.check_error()?;
@@ -484,6 +559,7 @@ impl GFile {
     /// [`FileAccess::store_64`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-store-64).
     #[doc(alias = "store_64")]
     pub fn write_u64(&mut self, value: u64) -> std::io::Result<()> {
+        self.discard_read_buffer();
         self.fa.store_64(value);
         self.clear_file_length();
         self.check_error()?;
@@ -496,6 +572,7 @@ impl GFile {
     /// [`FileAccess::store_float`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-store-float).
     #[doc(alias = "store_float")]
     pub fn write_f32(&mut self, value: f32) -> std::io::Result<()> {
+        self.discard_read_buffer();
         self.fa.store_float(value);
         self.clear_file_length();
         self.check_error()?;
@@ -508,6 +585,7 @@ impl GFile {
     /// [`FileAccess::store_double`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-store-double).
     #[doc(alias = "store_double")]
     pub fn write_f64(&mut self, value: f64) -> std::io::Result<()> {
+        self.discard_read_buffer();
         self.fa.store_double(value);
         self.clear_file_length();
         self.check_error()?;
@@ -531,6 +609,7 @@ impl GFile {
     /// </div>
     #[doc(alias = "store_real")]
     pub fn write_real(&mut self, value: real) -> std::io::Result<()> {
+        self.discard_read_buffer();
         // FileAccess::store_real() does not accept an actual real_t; work around this.
 
         #[cfg(feature = "double-precision")]
@@ -552,6 +631,7 @@ impl GFile {
     /// [`FileAccess::store_string`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-store-string).
     #[doc(alias = "store_string")]
     pub fn write_gstring(&mut self, value: impl AsArg<GString>) -> std::io::Result<()> {
+        self.discard_read_buffer();
         arg_into_ref!(value);
 
         self.fa.store_string(value);
@@ -572,6 +652,7 @@ impl GFile {
     /// - [Godot `FileAccess::store_pascal_string`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-store-pascal-string)
     #[doc(alias = "store_pascal_string")]
     pub fn write_pascal_string(&mut self, value: impl AsArg<GString>) -> std::io::Result<()> {
+        self.discard_read_buffer();
         arg_into_ref!(value);
 
         self.fa.store_pascal_string(value);
@@ -586,6 +667,7 @@ impl GFile {
     /// [`FileAccess::store_line`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-store-line).
     #[doc(alias = "store_line")]
     pub fn write_gstring_line(&mut self, value: impl AsArg<GString>) -> std::io::Result<()> {
+        self.discard_read_buffer();
         arg_into_ref!(value);
 
         self.fa.store_line(value);
@@ -606,6 +688,7 @@ impl GFile {
         values: &PackedStringArray,
         delim: impl AsArg<GString>,
     ) -> std::io::Result<()> {
+        self.discard_read_buffer();
         arg_into_ref!(delim);
 
         self.fa.store_csv_line_ex(values).delim(delim).done();
@@ -624,6 +707,7 @@ impl GFile {
     /// [`FileAccess::store_var`](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html#class-fileaccess-method-store-var).
     #[doc(alias = "store_var")]
     pub fn write_variant(&mut self, value: Variant, full_objects: bool) -> std::io::Result<()> {
+        self.discard_read_buffer();
         self.fa
             .store_var_ex(&value)
             .full_objects(full_objects)
@@ -662,7 +746,7 @@ impl GFile {
     /// Returns the current cursor position.
     #[doc(alias = "get_position")]
     pub fn position(&self) -> u64 {
-        self.fa.get_position()
+        self.fa.get_position() - self.buffered_len() as u64
     }
 
     /// Get file length in bytes.
@@ -673,12 +757,53 @@ impl GFile {
 
     /// Checks if the file cursor has read past the end of the file.
     pub fn eof_reached(&self) -> bool {
-        self.fa.eof_reached()
+        self.buffered_len() == 0 && self.fa.eof_reached()
     }
 
     // ----------------------------------------------------------------------------------------------------------------------------------------------
     // Private methods.
 
+    // Number of bytes that have been read from the `FileAccess` into the read buffer, but not yet consumed.
+    fn buffered_len(&self) -> usize {
+        self.buffer_len - self.buffer_pos
+    }
+
+    // Moves the `FileAccess` cursor back to the logical position, and empties the read buffer. Must be called before any operation that
+    // uses the `FileAccess` cursor directly, so that buffered reads can be mixed with typed reads, writes and seeking.
+    fn discard_read_buffer(&mut self) {
+        let buffered = self.buffered_len();
+        if buffered > 0 {
+            let position = self.fa.get_position() - buffered as u64;
+            self.fa.seek(position);
+        }
+
+        self.buffer_pos = 0;
+        self.buffer_len = 0;
+    }
+
+    // Reads directly from the `FileAccess`, bypassing the read buffer (which must be empty).
+    fn read_unbuffered(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let length = self.check_file_length();
+        let position = self.fa.get_position();
+        if position >= length {
+            return Ok(0);
+        }
+
+        let remaining_bytes = (length - position) as usize;
+        let bytes_to_read = cmp::min(buf.len(), remaining_bytes);
+        if bytes_to_read == 0 {
+            return Ok(0);
+        }
+
+        let gd_buffer = self.fa.get_buffer(bytes_to_read as i64);
+        let bytes_read = gd_buffer.len();
+        buf[0..bytes_read].copy_from_slice(gd_buffer.as_slice());
+
+        self.check_error()?;
+
+        Ok(bytes_read)
+    }
+
     // Error handling utility function.
     fn check_error(&self) -> Result<(), std::io::Error> {
         let error = self.fa.get_error();
@@ -712,7 +837,8 @@ impl GFile {
         Self {
             fa,
             buffer: vec![0; Self::BUFFER_SIZE],
-            last_buffer_size: 0,
+            buffer_pos: 0,
+            buffer_len: 0,
             write_buffer: PackedByteArray::new(),
             file_length,
         }
@@ -738,23 +864,28 @@ impl GFile {
 
 impl Read for GFile {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        let length = self.check_file_length();
-        let position = self.fa.get_position();
-        if position >= length {
-            return Ok(0);
+        // Large reads bypass the buffer, avoiding an extra copy.
+        if self.buffered_len() == 0 && buf.len() >= Self::BUFFER_SIZE {
+            return self.read_unbuffered(buf);
         }
 
-        let remaining_bytes = (length - position) as usize;
-        let bytes_to_read = cmp::min(buf.len(), remaining_bytes);
-        if bytes_to_read == 0 {
-            return Ok(0);
-        }
+        let available = self.fill_buf()?;
+        let bytes_read = cmp::min(available.len(), buf.len());
+        buf[0..bytes_read].copy_from_slice(&available[0..bytes_read]);
+        self.consume(bytes_read);
 
-        let gd_buffer = self.fa.get_buffer(bytes_to_read as i64);
-        let bytes_read = gd_buffer.len();
-        buf[0..bytes_read].copy_from_slice(gd_buffer.as_slice());
+        Ok(bytes_read)
+    }
 
-        self.check_error()?;
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> std::io::Result<usize> {
+        let bytes = self.read_all_bytes()?;
+        let bytes_read = bytes.len();
+
+        if buf.is_empty() {
+            *buf = bytes;
+        } else {
+            buf.extend_from_slice(&bytes);
+        }
 
         Ok(bytes_read)
     }
@@ -762,6 +893,7 @@ impl Read for GFile {
 
 impl Write for GFile {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.discard_read_buffer();
         self.pack_into_write_buffer(buf);
         self.fa
             .store_buffer(&self.write_buffer.subarray(0..buf.len()));
@@ -780,6 +912,17 @@ impl Write for GFile {
 
 impl Seek for GFile {
     fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        // Relative seeks within the read buffer only move the buffer cursor, keeping the buffered bytes.
+        if let SeekFrom::Current(offset) = pos {
+            let buffer_pos = self.buffer_pos as i64 + offset;
+            if self.buffer_len > 0 && (0..=self.buffer_len as i64).contains(&buffer_pos) {
+                self.buffer_pos = buffer_pos as usize;
+                return Ok(self.position());
+            }
+        }
+
+        self.discard_read_buffer();
+
         match pos {
             SeekFrom::Start(position) => {
                 self.fa.seek(position);
@@ -812,33 +955,38 @@ impl Seek for GFile {
             }
         }
     }
+
+    fn stream_position(&mut self) -> std::io::Result<u64> {
+        Ok(self.position())
+    }
 }
 
 impl BufRead for GFile {
     fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
-        // We need to determine number of remaining bytes - otherwise the `FileAccess::get_buffer return in an error`.
-        let remaining_bytes = self.check_file_length() - self.fa.get_position();
-        let buffer_read_size = cmp::min(remaining_bytes as usize, Self::BUFFER_SIZE);
-
-        // We need to keep the amount of last read side to be able to adjust cursor position in `consume`.
-        self.last_buffer_size = buffer_read_size;
-        self.buffer = vec![0; Self::BUFFER_SIZE];
-
-        let gd_buffer = self.fa.get_buffer(buffer_read_size as i64);
-        self.check_error()?;
-
-        let read_buffer = &mut self.buffer[0..gd_buffer.len()];
+        if self.buffered_len() == 0 {
+            // We need to determine number of remaining bytes - otherwise `FileAccess::get_buffer()` returns an error.
+            let remaining_bytes = self
+                .check_file_length()
+                .saturating_sub(self.fa.get_position());
+            let buffer_read_size = cmp::min(remaining_bytes, Self::BUFFER_SIZE as u64) as usize;
+
+            self.buffer_pos = 0;
+            self.buffer_len = 0;
+
+            if buffer_read_size > 0 {
+                let gd_buffer = self.fa.get_buffer(buffer_read_size as i64);
+                self.check_error()?;
 
-        read_buffer.copy_from_slice(gd_buffer.as_slice());
+                let bytes_read = gd_buffer.len();
+                self.buffer[0..bytes_read].copy_from_slice(gd_buffer.as_slice());
+                self.buffer_len = bytes_read;
+            }
+        }
 
-        Ok(read_buffer)
+        Ok(&self.buffer[self.buffer_pos..self.buffer_len])
     }
 
     fn consume(&mut self, amt: usize) {
-        // Cursor is being moved by `FileAccess::get_buffer()` call, so we need to adjust it.
-        let offset = (self.last_buffer_size - amt) as i64;
-        let pos = SeekFrom::Current(-offset);
-
-        self.seek(pos).expect("failed to consume bytes during read");
+        self.buffer_pos = cmp::min(self.buffer_pos + amt, self.buffer_len);
     }
 }
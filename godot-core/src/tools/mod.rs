@@ -11,14 +11,27 @@
 //! or better integrated with Rust.
 
 mod autoload;
+mod gdir;
 mod gfile;
 mod save_load;
+mod scene_builder;
 mod translate;
+mod translation_catalog;
+
+pub mod resource_format;
+
+// MultiplayerPeerExtension and its enums are only available with `codegen-full`.
+#[cfg(feature = "codegen-full")]
+pub mod multiplayer_peer;
 
 pub use autoload::*;
+pub use gdir::*;
 pub use gfile::*;
+pub use resource_format::ResourceFormat;
 pub use save_load::*;
+pub use scene_builder::*;
 pub use translate::*;
+pub use translation_catalog::*;
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
@@ -0,0 +1,650 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Custom network transports for Godot's high-level multiplayer.
+//!
+//! Godot's [`MultiplayerPeerExtension`] exchanges packets through raw pointers, whose buffers must stay alive until the engine is done
+//! with them. The [`CustomMultiplayerPeer`] trait offers a safe interface instead: outgoing packets are passed as `&[u8]`, incoming
+//! packets are queued as owned [`Packet`]s in a [`MultiplayerPeerState`], which also stores the settings Godot assigns to the peer.
+//!
+//! The [`multiplayer_peer!`] macro implements [`IMultiplayerPeerExtension`][crate::classes::IMultiplayerPeerExtension] in terms of
+//! the trait. Other interface methods, such as `init`, are passed to the macro:
+//!
+//! ```no_run
+//! use godot::classes::multiplayer_peer::ConnectionStatus;
+//! use godot::classes::MultiplayerPeerExtension;
+//! use godot::global::Error;
+//! use godot::prelude::*;
+//! use godot::tools::multiplayer_peer::{
+//!     multiplayer_peer, CustomMultiplayerPeer, LoopbackTransport, MultiplayerPeerState, OutgoingPacket,
+//! };
+//!
+//! #[derive(GodotClass)]
+//! #[class(base = MultiplayerPeerExtension)]
+//! struct LoopbackPeer {
+//!     transport: LoopbackTransport,
+//!     state: MultiplayerPeerState,
+//!     base: Base<MultiplayerPeerExtension>,
+//! }
+//!
+//! impl CustomMultiplayerPeer for LoopbackPeer {
+//!     fn peer_state(&self) -> &MultiplayerPeerState { &self.state }
+//!     fn peer_state_mut(&mut self) -> &mut MultiplayerPeerState { &mut self.state }
+//!     fn unique_id(&self) -> i32 { self.transport.unique_id() }
+//!     fn connection_status(&self) -> ConnectionStatus { self.transport.connection_status() }
+//!
+//!     fn send_packet(&mut self, packet: OutgoingPacket) -> Result<(), Error> {
+//!         self.transport.send(packet)
+//!     }
+//!
+//!     fn poll(&mut self) {
+//!         for event in self.transport.poll(&mut self.state) {
+//!             self.emit_peer_event(event);
+//!         }
+//!     }
+//!
+//!     fn close(&mut self) {
+//!         self.transport.close();
+//!         self.state.clear_incoming();
+//!     }
+//! }
+//!
+//! multiplayer_peer!(LoopbackPeer {
+//!     fn init(base: Base<MultiplayerPeerExtension>) -> Self {
+//!         // Server side of an in-process connection; the client side would be handed to another peer.
+//!         let (transport, _client) = LoopbackTransport::pair(2);
+//!
+//!         Self { transport, state: MultiplayerPeerState::new(), base }
+//!     }
+//! });
+//! ```
+//!
+//! An instance is then assigned with [`MultiplayerApi::set_multiplayer_peer()`][crate::classes::MultiplayerApi::set_multiplayer_peer].
+//!
+//! [`MultiplayerPeerExtension`]: crate::classes::MultiplayerPeerExtension
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::classes::multiplayer_peer::{ConnectionStatus, TransferMode};
+use crate::classes::MultiplayerPeerExtension;
+use crate::global::Error;
+use crate::meta::ToGodot;
+pub use crate::multiplayer_peer;
+use crate::obj::{GodotClass, WithBaseField};
+
+/// Largest packet accepted by default, matching Godot's ENet implementation.
+const DEFAULT_MAX_PACKET_SIZE: i32 = 1 << 24;
+
+/// Network transport for Godot's high-level multiplayer, implemented in Rust.
+///
+/// The engine interface is generated by [`multiplayer_peer!`]. See the [module documentation](self) for an example.
+///
+/// To notify Godot about connecting and disconnecting peers, call [`emit_peer_event()`][Self::emit_peer_event], typically
+/// during [`poll()`][Self::poll].
+pub trait CustomMultiplayerPeer:
+    GodotClass<Base = MultiplayerPeerExtension> + WithBaseField
+{
+    /// Incoming packets and transfer settings, stored in a field of the class.
+    fn peer_state(&self) -> &MultiplayerPeerState;
+
+    /// Mutable access to the state returned by [`peer_state()`][Self::peer_state].
+    fn peer_state_mut(&mut self) -> &mut MultiplayerPeerState;
+
+    /// ID of this peer. The server always has ID `1`; clients use a positive ID unique within the session.
+    fn unique_id(&self) -> i32;
+
+    /// Current state of the connection.
+    fn connection_status(&self) -> ConnectionStatus;
+
+    /// Sends a packet to the peer(s) designated by [`OutgoingPacket::target_peer`].
+    ///
+    /// The data is only borrowed for the duration of the call; copy it if it must be sent later.
+    fn send_packet(&mut self, packet: OutgoingPacket<'_>) -> Result<(), Error>;
+
+    /// Called by Godot once per frame (or whenever the multiplayer API is polled).
+    ///
+    /// Should receive pending data, and queue it with [`MultiplayerPeerState::push_incoming()`].
+    fn poll(&mut self);
+
+    /// Closes all connections. Godot may reuse the peer afterwards.
+    fn close(&mut self);
+
+    /// Disconnects the given peer. With `force`, no notification is sent to the remote side.
+    ///
+    /// Does nothing by default.
+    fn disconnect_peer(&mut self, peer_id: i32, force: bool) {
+        let _ = (peer_id, force);
+    }
+
+    /// Size in bytes of the largest packet that can be sent.
+    fn max_packet_size(&self) -> i32 {
+        DEFAULT_MAX_PACKET_SIZE
+    }
+
+    /// Whether this peer acts as the server. By default, this is the case for the peer with ID `1`.
+    fn is_server(&self) -> bool {
+        self.unique_id() == 1
+    }
+
+    /// Whether this peer relays packets between clients, so they can target each other directly. Defaults to `false`.
+    fn is_server_relay_supported(&self) -> bool {
+        false
+    }
+
+    /// Emits the `peer_connected` or `peer_disconnected` signal, on which the multiplayer API tracks remote peers.
+    ///
+    /// The signal is emitted through [`base_mut()`][WithBaseField::base_mut], so handlers may call back into this object.
+    fn emit_peer_event(&mut self, event: PeerEvent) {
+        let (signal, peer_id) = match event {
+            PeerEvent::Connected(id) => ("peer_connected", id),
+            PeerEvent::Disconnected(id) => ("peer_disconnected", id),
+        };
+
+        let peer_id = i64::from(peer_id).to_variant();
+        self.base_mut().emit_signal(signal, &[peer_id]);
+    }
+}
+
+/// Change in the set of peers connected to a [`CustomMultiplayerPeer`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PeerEvent {
+    /// Peer with the given ID has connected.
+    Connected(i32),
+
+    /// Peer with the given ID has disconnected.
+    Disconnected(i32),
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Packet received by a [`CustomMultiplayerPeer`], waiting to be read by Godot.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Packet {
+    /// Payload, in the format produced by Godot's multiplayer API.
+    pub data: Vec<u8>,
+
+    /// ID of the peer that sent the packet.
+    pub sender: i32,
+
+    /// Channel on which the packet was sent.
+    pub channel: i32,
+
+    /// Reliability with which the packet was sent.
+    pub transfer_mode: TransferMode,
+}
+
+/// Packet that Godot wants to send through a [`CustomMultiplayerPeer`].
+#[derive(Copy, Clone, Debug)]
+pub struct OutgoingPacket<'a> {
+    /// Payload; only valid during [`CustomMultiplayerPeer::send_packet()`].
+    pub data: &'a [u8],
+
+    /// Recipient: ID of a specific peer, `0` for all peers, or `-id` for all peers except `id`.
+    pub target_peer: i32,
+
+    /// Channel on which to send the packet.
+    pub channel: i32,
+
+    /// Requested reliability.
+    pub transfer_mode: TransferMode,
+}
+
+impl OutgoingPacket<'_> {
+    /// Whether the peer with the given ID is among the recipients.
+    pub fn is_for(&self, peer_id: i32) -> bool {
+        match self.target_peer {
+            0 => true,
+            target if target < 0 => -target != peer_id,
+            target => target == peer_id,
+        }
+    }
+
+    /// Converts into a [`Packet`] as received by another peer, copying the data.
+    pub fn to_received(&self, sender: i32) -> Packet {
+        Packet {
+            data: self.data.to_vec(),
+            sender,
+            channel: self.channel,
+            transfer_mode: self.transfer_mode,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Per-peer state managed on behalf of a [`CustomMultiplayerPeer`]: the queue of incoming packets, and the transfer settings of
+/// outgoing ones.
+#[derive(Debug)]
+pub struct MultiplayerPeerState {
+    incoming: VecDeque<Packet>,
+
+    // Packet most recently handed out to Godot. Its buffer must stay alive until Godot requests the next packet.
+    current: Option<Packet>,
+
+    target_peer: i32,
+    transfer_channel: i32,
+    transfer_mode: TransferMode,
+    refuse_new_connections: bool,
+}
+
+impl MultiplayerPeerState {
+    pub fn new() -> Self {
+        Self {
+            incoming: VecDeque::new(),
+            current: None,
+            target_peer: 0,
+            transfer_channel: 0,
+            transfer_mode: TransferMode::RELIABLE,
+            refuse_new_connections: false,
+        }
+    }
+
+    /// Queues a received packet, to be read by Godot.
+    pub fn push_incoming(&mut self, packet: Packet) {
+        self.incoming.push_back(packet);
+    }
+
+    /// Number of packets that have not been read by Godot yet.
+    pub fn incoming_count(&self) -> usize {
+        self.incoming.len()
+    }
+
+    /// Discards all packets that have not been read yet, e.g. when the connection is closed.
+    pub fn clear_incoming(&mut self) {
+        self.incoming.clear();
+    }
+
+    /// Whether Godot asked to refuse new connections.
+    pub fn is_refusing_new_connections(&self) -> bool {
+        self.refuse_new_connections
+    }
+
+    fn next_packet(&self) -> Option<&Packet> {
+        self.incoming.front()
+    }
+}
+
+impl Default for MultiplayerPeerState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// In-process transport connecting two peers without sockets, e.g. to test multiplayer code.
+///
+/// Created in pairs with [`LoopbackTransport::pair()`]; each half is stored in a [`CustomMultiplayerPeer`] class and delegated to.
+/// Packets sent by one half are queued for the other, and received in its next [`poll()`][Self::poll]. The first poll announces the
+/// remote peer as connected; after one half is closed, the other reports the remote peer as disconnected.
+#[derive(Debug)]
+pub struct LoopbackTransport {
+    unique_id: i32,
+    remote_id: i32,
+    inbox: Arc<Mutex<LoopbackQueue>>,
+    outbox: Arc<Mutex<LoopbackQueue>>,
+    status: ConnectionStatus,
+    announced: bool,
+}
+
+impl LoopbackTransport {
+    /// Creates two connected transports: a server with ID `1`, and a client with `client_id`.
+    ///
+    /// # Panics
+    /// If `client_id` is not greater than `1`.
+    pub fn pair(client_id: i32) -> (Self, Self) {
+        assert!(
+            client_id > 1,
+            "client ID must be greater than 1 (the server ID); got {client_id}"
+        );
+
+        let to_server = Arc::new(Mutex::new(LoopbackQueue::default()));
+        let to_client = Arc::new(Mutex::new(LoopbackQueue::default()));
+
+        let server = Self::new(1, client_id, to_server.clone(), to_client.clone());
+        let client = Self::new(client_id, 1, to_client, to_server);
+        (server, client)
+    }
+
+    fn new(
+        unique_id: i32,
+        remote_id: i32,
+        inbox: Arc<Mutex<LoopbackQueue>>,
+        outbox: Arc<Mutex<LoopbackQueue>>,
+    ) -> Self {
+        Self {
+            unique_id,
+            remote_id,
+            inbox,
+            outbox,
+            status: ConnectionStatus::CONNECTED,
+            announced: false,
+        }
+    }
+
+    /// ID of this side of the connection.
+    pub fn unique_id(&self) -> i32 {
+        self.unique_id
+    }
+
+    /// ID of the other side of the connection.
+    pub fn remote_id(&self) -> i32 {
+        self.remote_id
+    }
+
+    pub fn connection_status(&self) -> ConnectionStatus {
+        self.status
+    }
+
+    /// Queues the packet for the remote side, if it is among the recipients.
+    ///
+    /// Returns [`Error::ERR_CONNECTION_ERROR`] if the connection is closed.
+    pub fn send(&mut self, packet: OutgoingPacket<'_>) -> Result<(), Error> {
+        if self.status != ConnectionStatus::CONNECTED {
+            return Err(Error::ERR_CONNECTION_ERROR);
+        }
+
+        if packet.is_for(self.remote_id) {
+            let received = packet.to_received(self.unique_id);
+            lock(&self.outbox).packets.push_back(received);
+        }
+
+        Ok(())
+    }
+
+    /// Moves packets received from the remote side into `state`, and returns changes in the connection.
+    pub fn poll(&mut self, state: &mut MultiplayerPeerState) -> Vec<PeerEvent> {
+        let mut events = vec![];
+        if self.status != ConnectionStatus::CONNECTED {
+            return events;
+        }
+
+        if !self.announced {
+            self.announced = true;
+            events.push(PeerEvent::Connected(self.remote_id));
+        }
+
+        let mut inbox = lock(&self.inbox);
+        for packet in inbox.packets.drain(..) {
+            state.push_incoming(packet);
+        }
+
+        if inbox.closed {
+            self.status = ConnectionStatus::DISCONNECTED;
+            events.push(PeerEvent::Disconnected(self.remote_id));
+        }
+
+        events
+    }
+
+    /// Closes this side of the connection; the remote side notices in its next poll.
+    pub fn close(&mut self) {
+        self.status = ConnectionStatus::DISCONNECTED;
+        lock(&self.outbox).closed = true;
+    }
+}
+
+#[derive(Debug, Default)]
+struct LoopbackQueue {
+    packets: VecDeque<Packet>,
+    closed: bool,
+}
+
+fn lock(queue: &Mutex<LoopbackQueue>) -> MutexGuard<'_, LoopbackQueue> {
+    // A poisoned queue still holds consistent data; packets are only pushed or drained.
+    queue.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Implements [`IMultiplayerPeerExtension`][crate::classes::IMultiplayerPeerExtension] for a class implementing
+/// [`CustomMultiplayerPeer`].
+///
+/// Packets are exchanged through the raw-pointer virtual functions: the buffer of each packet read by Godot is kept alive in the
+/// [`MultiplayerPeerState`] until the next one is requested. See the [module documentation](crate::tools::multiplayer_peer).
+///
+/// Further interface methods that are not part of the multiplayer API, such as `init`, `to_string` or `on_notification`, can be passed in
+/// braces. They are added to the generated `impl` block:
+///
+/// ```ignore
+/// multiplayer_peer!(MyPeer {
+///     fn to_string(&self) -> GString {
+///         format!("MyPeer(id={})", self.unique_id()).into()
+///     }
+/// });
+/// ```
+#[macro_export]
+macro_rules! multiplayer_peer {
+    ($Peer:ty { $($user_methods:tt)* }) => {
+        #[::godot::register::godot_api]
+        impl ::godot::classes::IMultiplayerPeerExtension for $Peer {
+            $($user_methods)*
+
+            unsafe fn get_packet_rawptr(
+                &mut self,
+                r_buffer: *mut *const u8,
+                r_buffer_size: *mut i32,
+            ) -> ::godot::global::Error {
+                // SAFETY: Godot passes valid out-pointers.
+                unsafe {
+                    ::godot::tools::multiplayer_peer::__private::get_packet(
+                        self,
+                        r_buffer,
+                        r_buffer_size,
+                    )
+                }
+            }
+            unsafe fn put_packet_rawptr(
+                &mut self,
+                p_buffer: *const u8,
+                p_buffer_size: i32,
+            ) -> ::godot::global::Error {
+                // SAFETY: Godot passes a buffer valid for `p_buffer_size` bytes.
+                unsafe {
+                    ::godot::tools::multiplayer_peer::__private::put_packet(
+                        self,
+                        p_buffer,
+                        p_buffer_size,
+                    )
+                }
+            }
+            fn get_available_packet_count(&self) -> i32 {
+                ::godot::tools::multiplayer_peer::__private::available_packet_count(self)
+            }
+            fn get_max_packet_size(&self) -> i32 {
+                ::godot::tools::multiplayer_peer::CustomMultiplayerPeer::max_packet_size(self)
+            }
+            fn get_packet_channel(&self) -> i32 {
+                ::godot::tools::multiplayer_peer::__private::packet_channel(self)
+            }
+            fn get_packet_mode(&self) -> ::godot::classes::multiplayer_peer::TransferMode {
+                ::godot::tools::multiplayer_peer::__private::packet_mode(self)
+            }
+            fn get_packet_peer(&self) -> i32 {
+                ::godot::tools::multiplayer_peer::__private::packet_peer(self)
+            }
+            fn set_transfer_channel(&mut self, channel: i32) {
+                ::godot::tools::multiplayer_peer::__private::set_transfer_channel(self, channel)
+            }
+            fn get_transfer_channel(&self) -> i32 {
+                ::godot::tools::multiplayer_peer::__private::transfer_channel(self)
+            }
+            fn set_transfer_mode(
+                &mut self,
+                mode: ::godot::classes::multiplayer_peer::TransferMode,
+            ) {
+                ::godot::tools::multiplayer_peer::__private::set_transfer_mode(self, mode)
+            }
+            fn get_transfer_mode(&self) -> ::godot::classes::multiplayer_peer::TransferMode {
+                ::godot::tools::multiplayer_peer::__private::transfer_mode(self)
+            }
+            fn set_target_peer(&mut self, peer: i32) {
+                ::godot::tools::multiplayer_peer::__private::set_target_peer(self, peer)
+            }
+            fn is_server(&self) -> bool {
+                ::godot::tools::multiplayer_peer::CustomMultiplayerPeer::is_server(self)
+            }
+            fn poll(&mut self) {
+                ::godot::tools::multiplayer_peer::CustomMultiplayerPeer::poll(self)
+            }
+            fn close(&mut self) {
+                ::godot::tools::multiplayer_peer::CustomMultiplayerPeer::close(self)
+            }
+            fn disconnect_peer(&mut self, peer: i32, force: bool) {
+                ::godot::tools::multiplayer_peer::CustomMultiplayerPeer::disconnect_peer(
+                    self, peer, force,
+                )
+            }
+            fn get_unique_id(&self) -> i32 {
+                ::godot::tools::multiplayer_peer::CustomMultiplayerPeer::unique_id(self)
+            }
+            fn set_refuse_new_connections(&mut self, enable: bool) {
+                ::godot::tools::multiplayer_peer::__private::set_refuse_new_connections(self, enable)
+            }
+            fn is_refusing_new_connections(&self) -> bool {
+                ::godot::tools::multiplayer_peer::__private::state(self)
+                    .is_refusing_new_connections()
+            }
+            fn is_server_relay_supported(&self) -> bool {
+                ::godot::tools::multiplayer_peer::CustomMultiplayerPeer::is_server_relay_supported(
+                    self,
+                )
+            }
+            fn get_connection_status(
+                &self,
+            ) -> ::godot::classes::multiplayer_peer::ConnectionStatus {
+                ::godot::tools::multiplayer_peer::CustomMultiplayerPeer::connection_status(self)
+            }
+        }
+    };
+    ($Peer:ty) => {
+        ::godot::tools::multiplayer_peer::multiplayer_peer!($Peer {});
+    };
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use super::*;
+
+    pub fn state<P: CustomMultiplayerPeer>(peer: &P) -> &MultiplayerPeerState {
+        peer.peer_state()
+    }
+
+    pub fn transfer_channel<P: CustomMultiplayerPeer>(peer: &P) -> i32 {
+        peer.peer_state().transfer_channel
+    }
+
+    pub fn set_transfer_channel<P: CustomMultiplayerPeer>(peer: &mut P, channel: i32) {
+        peer.peer_state_mut().transfer_channel = channel;
+    }
+
+    pub fn transfer_mode<P: CustomMultiplayerPeer>(peer: &P) -> TransferMode {
+        peer.peer_state().transfer_mode
+    }
+
+    pub fn set_transfer_mode<P: CustomMultiplayerPeer>(peer: &mut P, mode: TransferMode) {
+        peer.peer_state_mut().transfer_mode = mode;
+    }
+
+    pub fn set_target_peer<P: CustomMultiplayerPeer>(peer: &mut P, target_peer: i32) {
+        peer.peer_state_mut().target_peer = target_peer;
+    }
+
+    pub fn set_refuse_new_connections<P: CustomMultiplayerPeer>(peer: &mut P, enable: bool) {
+        peer.peer_state_mut().refuse_new_connections = enable;
+    }
+
+    pub fn available_packet_count<P: CustomMultiplayerPeer>(peer: &P) -> i32 {
+        i32::try_from(peer.peer_state().incoming_count()).unwrap_or(i32::MAX)
+    }
+
+    // Godot queries sender, channel and mode of the next packet *before* reading it with `get_packet()`.
+
+    pub fn packet_peer<P: CustomMultiplayerPeer>(peer: &P) -> i32 {
+        peer.peer_state().next_packet().map_or(0, |p| p.sender)
+    }
+
+    pub fn packet_channel<P: CustomMultiplayerPeer>(peer: &P) -> i32 {
+        peer.peer_state().next_packet().map_or(0, |p| p.channel)
+    }
+
+    pub fn packet_mode<P: CustomMultiplayerPeer>(peer: &P) -> TransferMode {
+        peer.peer_state()
+            .next_packet()
+            .map_or(TransferMode::RELIABLE, |p| p.transfer_mode)
+    }
+
+    /// # Safety
+    /// `r_buffer` and `r_buffer_size` must be valid for writes.
+    pub unsafe fn get_packet<P: CustomMultiplayerPeer>(
+        peer: &mut P,
+        r_buffer: *mut *const u8,
+        r_buffer_size: *mut i32,
+    ) -> Error {
+        let state = peer.peer_state_mut();
+        let Some(next) = state.incoming.front() else {
+            return Error::ERR_UNAVAILABLE;
+        };
+
+        let Ok(size) = i32::try_from(next.data.len()) else {
+            // Godot cannot represent the size; discard the packet so that the following ones can still be read.
+            let len = next.data.len();
+            state.incoming.pop_front();
+            crate::godot_error!(
+                "multiplayer peer: dropped incoming packet of {len} bytes (too large)"
+            );
+            return Error::ERR_OUT_OF_MEMORY;
+        };
+
+        let packet = state.incoming.pop_front().expect("front packet exists");
+
+        // Replaces (and frees) the previous packet, which Godot no longer references.
+        let packet = state.current.insert(packet);
+
+        // SAFETY: upheld by caller.
+        unsafe {
+            *r_buffer = packet.data.as_ptr();
+            *r_buffer_size = size;
+        }
+
+        Error::OK
+    }
+
+    /// # Safety
+    /// `p_buffer` must be valid for reads of `p_buffer_size` bytes, unless the size is zero.
+    pub unsafe fn put_packet<P: CustomMultiplayerPeer>(
+        peer: &mut P,
+        p_buffer: *const u8,
+        p_buffer_size: i32,
+    ) -> Error {
+        let data: &[u8] = match usize::try_from(p_buffer_size) {
+            Ok(size) if size > 0 && !p_buffer.is_null() => {
+                // SAFETY: upheld by caller.
+                unsafe { std::slice::from_raw_parts(p_buffer, size) }
+            }
+            _ => &[],
+        };
+
+        if p_buffer_size > peer.max_packet_size() {
+            return Error::ERR_OUT_OF_MEMORY;
+        }
+
+        let state = peer.peer_state();
+        let packet = OutgoingPacket {
+            data,
+            target_peer: state.target_peer,
+            channel: state.transfer_channel,
+            transfer_mode: state.transfer_mode,
+        };
+
+        match peer.send_packet(packet) {
+            Ok(()) => Error::OK,
+            Err(err) => err,
+        }
+    }
+}
@@ -0,0 +1,352 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Custom file formats for Rust-defined resources.
+//!
+//! Godot loads and saves resources through [`ResourceFormatLoader`] and [`ResourceFormatSaver`] objects, which need to be implemented and
+//! registered with the [`ResourceLoader`] and [`ResourceSaver`] singletons. This module reduces that to:
+//!
+//! 1. An implementation of [`ResourceFormat`], which converts between your resource class and bytes.
+//! 2. One invocation of [`resource_format!`], which generates the loader and saver classes.
+//!
+//! The loader and saver are registered automatically when the extension's `Scene` level is initialized, and removed again on shutdown.
+//! Afterwards, [`load()`][crate::tools::load] and [`save()`][crate::tools::save] (as well as the editor and GDScript) handle files with the
+//! format's extensions.
+//!
+//! # Example
+//! ```no_run
+//! use godot::global::Error;
+//! use godot::prelude::*;
+//! use godot::tools::resource_format::{resource_format, ResourceFormat};
+//!
+//! #[derive(GodotClass)]
+//! #[class(init, base=Resource)]
+//! struct Dialogue {
+//!     lines: Vec<String>,
+//! }
+//!
+//! struct DialogueFormat;
+//!
+//! impl ResourceFormat for DialogueFormat {
+//!     type Resource = Dialogue;
+//!
+//!     fn extensions() -> &'static [&'static str] {
+//!         &["dialogue"]
+//!     }
+//!
+//!     fn load(bytes: &[u8]) -> Result<Gd<Dialogue>, Error> {
+//!         let text = std::str::from_utf8(bytes).map_err(|_| Error::ERR_FILE_CORRUPT)?;
+//!         let lines = text.lines().map(String::from).collect();
+//!
+//!         Ok(Gd::from_object(Dialogue { lines }))
+//!     }
+//!
+//!     fn save(dialogue: &Dialogue) -> Result<Vec<u8>, Error> {
+//!         Ok(dialogue.lines.join("\n").into_bytes())
+//!     }
+//! }
+//!
+//! resource_format!(format: DialogueFormat, loader: DialogueLoader, saver: DialogueSaver);
+//! ```
+//!
+//! # Threads
+//! Godot may invoke loaders from other threads, e.g. for [`ResourceLoader::load_threaded_request()`] or editor imports. This requires the
+//! `experimental-threads` feature.
+
+use std::any::TypeId;
+use std::io::{Read, Write};
+
+use crate::builtin::{GString, PackedStringArray, StringName, Variant};
+use crate::classes::file_access::ModeFlags;
+use crate::classes::{
+    ClassDb, Resource, ResourceFormatLoader, ResourceFormatSaver, ResourceLoader, ResourceSaver,
+};
+use crate::global::Error;
+use crate::meta::ToGodot;
+use crate::obj::{bounds, Bounds, Gd, GodotClass, Inherits, InstanceId, Singleton};
+use crate::private::__GODOT_RESOURCE_FORMAT_REGISTRY;
+pub use crate::resource_format;
+use crate::sys;
+use crate::tools::GFile;
+
+/// On-disk format for a Rust-defined resource class.
+///
+/// Implement this trait and invoke [`resource_format!`] to make Godot load and save files with the given extensions. See the
+/// [module docs][self] for an example.
+///
+/// Only [`load()`][Self::load] and [`save()`][Self::save], which operate on the whole file content, are required. For large files, you can
+/// override [`load_from_file()`][Self::load_from_file] and [`save_to_file()`][Self::save_to_file] to stream from/to the [`GFile`] instead.
+///
+/// Errors are reported to Godot, which prints them and returns `null` from `load()` or the error from `save()`.
+pub trait ResourceFormat: 'static {
+    /// Resource class that is loaded and saved.
+    type Resource: GodotClass + Inherits<Resource> + Bounds<Declarer = bounds::DeclUser>;
+
+    /// File extensions handled by this format, without leading dot, e.g. `&["dialogue"]`.
+    fn extensions() -> &'static [&'static str];
+
+    /// Creates the resource from the content of a file.
+    fn load(bytes: &[u8]) -> Result<Gd<Self::Resource>, Error>;
+
+    /// Serializes the resource to the content of a file.
+    fn save(resource: &Self::Resource) -> Result<Vec<u8>, Error>;
+
+    /// Creates the resource from an opened file.
+    ///
+    /// By default, reads the entire file and calls [`load()`][Self::load].
+    fn load_from_file(file: &mut GFile) -> Result<Gd<Self::Resource>, Error> {
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)
+            .map_err(|_| Error::ERR_FILE_CANT_READ)?;
+
+        Self::load(&bytes)
+    }
+
+    /// Writes the resource to an opened file, which has been truncated.
+    ///
+    /// By default, calls [`save()`][Self::save] and writes the result.
+    fn save_to_file(resource: &Self::Resource, file: &mut GFile) -> Result<(), Error> {
+        let bytes = Self::save(resource)?;
+        file.write_all(&bytes)
+            .map_err(|_| Error::ERR_FILE_CANT_WRITE)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Registration
+
+struct RegisteredFormat {
+    loader: InstanceId,
+    saver: InstanceId,
+}
+
+static REGISTERED_FORMATS: sys::Global<Vec<RegisteredFormat>> = sys::Global::default();
+
+/// Adds the loaders and savers of all [`resource_format!`] invocations. Called after classes of the `Scene` level are registered.
+pub(crate) fn register_all() {
+    let mut plugins = Vec::new();
+    sys::plugin_foreach!(__GODOT_RESOURCE_FORMAT_REGISTRY; |plugin: &__private::ResourceFormatPlugin| {
+        plugins.push((plugin.type_id, plugin.create));
+    });
+
+    let mut registered = REGISTERED_FORMATS.lock();
+    let mut seen = Vec::new();
+    for (type_id, create) in plugins {
+        // The same format may be declared multiple times; register it only once.
+        if seen.contains(&type_id) {
+            continue;
+        }
+        seen.push(type_id);
+
+        let (loader, saver) = create();
+        ResourceLoader::singleton().add_resource_format_loader(&loader);
+        ResourceSaver::singleton().add_resource_format_saver(&saver);
+
+        registered.push(RegisteredFormat {
+            loader: loader.instance_id(),
+            saver: saver.instance_id(),
+        });
+    }
+}
+
+/// Removes all loaders and savers added by [`register_all()`]. Called before classes of the `Scene` level are unregistered.
+pub(crate) fn unregister_all() {
+    let registered = std::mem::take(&mut *REGISTERED_FORMATS.lock());
+
+    for format in registered {
+        if let Ok(loader) = Gd::<ResourceFormatLoader>::try_from_instance_id(format.loader) {
+            ResourceLoader::singleton().remove_resource_format_loader(&loader);
+        }
+
+        if let Ok(saver) = Gd::<ResourceFormatSaver>::try_from_instance_id(format.saver) {
+            ResourceSaver::singleton().remove_resource_format_saver(&saver);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of generated code
+
+#[doc(hidden)]
+pub mod __private {
+    use super::*;
+
+    pub struct ResourceFormatPlugin {
+        pub(super) type_id: TypeId,
+        pub(super) create: fn() -> (Gd<ResourceFormatLoader>, Gd<ResourceFormatSaver>),
+    }
+
+    impl ResourceFormatPlugin {
+        pub fn new<F: ResourceFormat>(
+            create: fn() -> (Gd<ResourceFormatLoader>, Gd<ResourceFormatSaver>),
+        ) -> Self {
+            Self {
+                type_id: TypeId::of::<F>(),
+                create,
+            }
+        }
+    }
+
+    pub fn recognized_extensions<F: ResourceFormat>() -> PackedStringArray {
+        F::extensions()
+            .iter()
+            .map(|&ext| GString::from(ext))
+            .collect()
+    }
+
+    pub fn handles_type<F: ResourceFormat>(type_: &StringName) -> bool {
+        let class_name = F::Resource::class_id().to_string_name();
+        ClassDb::singleton().is_parent_class(&class_name, type_)
+    }
+
+    pub fn resource_type<F: ResourceFormat>(path: &GString) -> GString {
+        if has_extension::<F>(path) {
+            F::Resource::class_id().to_gstring()
+        } else {
+            GString::new()
+        }
+    }
+
+    pub fn load<F: ResourceFormat>(path: &GString) -> Variant {
+        let result = GFile::open(path, ModeFlags::READ)
+            .map_err(|_| Error::ERR_FILE_CANT_OPEN)
+            .and_then(|mut file| F::load_from_file(&mut file));
+
+        match result {
+            Ok(resource) => resource.to_variant(),
+            // Godot interprets an integer as error code.
+            Err(error) => error.to_variant(),
+        }
+    }
+
+    pub fn save<F: ResourceFormat>(resource: Option<Gd<Resource>>, path: &GString) -> Error {
+        let Some(Ok(resource)) = resource.map(|r| r.try_cast::<F::Resource>()) else {
+            return Error::ERR_INVALID_PARAMETER;
+        };
+
+        let result = GFile::open(path, ModeFlags::WRITE)
+            .map_err(|_| Error::ERR_FILE_CANT_OPEN)
+            .and_then(|mut file| F::save_to_file(&resource.bind(), &mut file));
+
+        match result {
+            Ok(()) => Error::OK,
+            Err(error) => error,
+        }
+    }
+
+    pub fn recognize<F: ResourceFormat>(resource: Option<Gd<Resource>>) -> bool {
+        resource.is_some_and(|r| r.try_cast::<F::Resource>().is_ok())
+    }
+
+    pub fn saver_extensions<F: ResourceFormat>(
+        resource: Option<Gd<Resource>>,
+    ) -> PackedStringArray {
+        if recognize::<F>(resource) {
+            recognized_extensions::<F>()
+        } else {
+            PackedStringArray::new()
+        }
+    }
+
+    fn has_extension<F: ResourceFormat>(path: &GString) -> bool {
+        let path = path.to_string();
+        path.rsplit_once('.').is_some_and(|(_, ext)| {
+            F::extensions()
+                .iter()
+                .any(|known| ext.eq_ignore_ascii_case(known))
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Macro
+
+/// Generates the resource loader and saver classes for a [`ResourceFormat`].
+///
+/// Takes the type implementing `ResourceFormat`, and names for the two generated classes. These are registered as internal tool classes
+/// (hidden from the editor's class list); their names only need to be unique. See the [module docs][crate::tools::resource_format] for an
+/// example.
+///
+/// ```ignore
+/// resource_format!(format: DialogueFormat, loader: DialogueLoader, saver: DialogueSaver);
+/// ```
+#[macro_export]
+macro_rules! resource_format {
+    (
+        format: $Format:ty,
+        loader: $Loader:ident,
+        saver: $Saver:ident $(,)?
+    ) => {
+        #[derive(::godot::register::GodotClass)]
+        #[class(init, base = ResourceFormatLoader, tool, internal)]
+        struct $Loader {
+            base: ::godot::obj::Base<::godot::classes::ResourceFormatLoader>,
+        }
+
+        #[::godot::register::godot_api]
+        impl ::godot::classes::IResourceFormatLoader for $Loader {
+            fn get_recognized_extensions(&self) -> ::godot::builtin::PackedStringArray {
+                ::godot::tools::resource_format::__private::recognized_extensions::<$Format>()
+            }
+            fn handles_type(&self, type_: ::godot::builtin::StringName) -> bool {
+                ::godot::tools::resource_format::__private::handles_type::<$Format>(&type_)
+            }
+            fn get_resource_type(&self, path: ::godot::builtin::GString) -> ::godot::builtin::GString {
+                ::godot::tools::resource_format::__private::resource_type::<$Format>(&path)
+            }
+            fn exists(&self, path: ::godot::builtin::GString) -> bool {
+                ::godot::classes::FileAccess::file_exists(&path)
+            }
+            fn load(
+                &self,
+                path: ::godot::builtin::GString,
+                _original_path: ::godot::builtin::GString,
+                _use_sub_threads: bool,
+                _cache_mode: i32,
+            ) -> ::godot::builtin::Variant {
+                ::godot::tools::resource_format::__private::load::<$Format>(&path)
+            }
+        }
+
+        #[derive(::godot::register::GodotClass)]
+        #[class(init, base = ResourceFormatSaver, tool, internal)]
+        struct $Saver {
+            base: ::godot::obj::Base<::godot::classes::ResourceFormatSaver>,
+        }
+
+        #[::godot::register::godot_api]
+        impl ::godot::classes::IResourceFormatSaver for $Saver {
+            fn save(
+                &mut self,
+                resource: Option<::godot::obj::Gd<::godot::classes::Resource>>,
+                path: ::godot::builtin::GString,
+                _flags: u32,
+            ) -> ::godot::global::Error {
+                ::godot::tools::resource_format::__private::save::<$Format>(resource, &path)
+            }
+            fn recognize(&self, resource: Option<::godot::obj::Gd<::godot::classes::Resource>>) -> bool {
+                ::godot::tools::resource_format::__private::recognize::<$Format>(resource)
+            }
+            fn get_recognized_extensions(
+                &self,
+                resource: Option<::godot::obj::Gd<::godot::classes::Resource>>,
+            ) -> ::godot::builtin::PackedStringArray {
+                ::godot::tools::resource_format::__private::saver_extensions::<$Format>(resource)
+            }
+        }
+
+        ::godot::sys::plugin_add!(
+            ::godot::private::__GODOT_RESOURCE_FORMAT_REGISTRY;
+            ::godot::tools::resource_format::__private::ResourceFormatPlugin::new::<$Format>(|| {
+                let loader = <$Loader as ::godot::obj::NewGd>::new_gd();
+                let saver = <$Saver as ::godot::obj::NewGd>::new_gd();
+
+                (loader.upcast(), saver.upcast())
+            })
+        );
+    };
+}
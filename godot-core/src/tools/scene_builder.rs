@@ -0,0 +1,210 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::StringName;
+use crate::classes::{Node, Object, PackedScene};
+use crate::global::Error;
+use crate::meta::{arg_into_ref, AsArg, ToGodot};
+use crate::obj::{Gd, Inherits, NewAlloc, NewGd, WithSignals};
+
+/// Builds a hierarchy of nodes in Rust, declaratively.
+///
+/// Each `NodeBuilder<T>` holds a newly allocated node of type `T`, which can be configured through the fluent API: [`name()`](Self::name),
+/// [`property()`](Self::property) and [`with()`](Self::with) for arbitrary typed calls, [`signals()`](Self::signals) for typed signal
+/// connections, and [`child()`](Self::child) to attach other builders as children.
+///
+/// [`build()`](Self::build) returns the root node. All descendants have their owner set to the root, unless marked with
+/// [`without_owner()`](Self::without_owner). This is required for nodes to be saved, e.g. through [`pack()`](Self::pack) and
+/// [`save()`](super::save()).
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::{Button, Label, VBoxContainer};
+/// use godot::prelude::*;
+/// use godot::tools::NodeBuilder;
+///
+/// fn main_menu() -> Gd<VBoxContainer> {
+///     let status = NodeBuilder::<Label>::new().name("Status");
+///     let mut status_label = status.node().clone();
+///
+///     NodeBuilder::<VBoxContainer>::new()
+///         .name("MainMenu")
+///         .child(status)
+///         .child(
+///             NodeBuilder::<Button>::new()
+///                 .name("Start")
+///                 .with(|button| button.set_text("Start game"))
+///                 .signals(|mut signals| {
+///                     signals.pressed().connect(move || status_label.set_text("Loading..."));
+///                 }),
+///         )
+///         .build()
+/// }
+/// ```
+///
+/// A builder owns manually-managed nodes. If it is dropped without calling `build()` or `pack()`, the node and its descendants are freed.
+#[must_use = "the builder holds newly allocated nodes; call build() or pack()"]
+pub struct NodeBuilder<T: Inherits<Node>> {
+    /// `None` once consumed by `build()` or `child()`.
+    node: Option<Gd<T>>,
+    has_owner: bool,
+
+    /// Descendants whose owner is set to the root on `build()`.
+    owned_descendants: Vec<Gd<Node>>,
+}
+
+impl<T> NodeBuilder<T>
+where
+    T: Inherits<Node> + NewAlloc,
+{
+    /// Allocates a new node of type `T` to build upon.
+    #[allow(clippy::new_without_default)] // Default would hide the allocation.
+    pub fn new() -> Self {
+        Self::from_node(T::new_alloc())
+    }
+}
+
+impl<T: Inherits<Node>> NodeBuilder<T> {
+    /// Builds upon an existing node, e.g. one with custom initialization or instantiated from a scene.
+    ///
+    /// The builder takes ownership of `node`: if it is dropped without calling `build()` or `pack()`, `node` is freed together with its
+    /// children, even those that were added before it was passed to the builder.
+    pub fn from_node(node: Gd<T>) -> Self {
+        Self {
+            node: Some(node),
+            has_owner: true,
+            owned_descendants: Vec::new(),
+        }
+    }
+
+    /// Returns the node being built, e.g. to capture it in signal connections of other nodes.
+    pub fn node(&self) -> &Gd<T> {
+        self.node.as_ref().expect("NodeBuilder already consumed")
+    }
+
+    /// Sets the node's name.
+    pub fn name(mut self, name: impl AsArg<StringName>) -> Self {
+        arg_into_ref!(name);
+
+        self.node_mut().upcast_mut::<Node>().set_name(name);
+        self
+    }
+
+    /// Sets a property by name, e.g. one that is only known dynamically or defined in a script.
+    ///
+    /// Prefer [`with()`](Self::with) and the typed setters where possible.
+    pub fn property(mut self, property: impl AsArg<StringName>, value: impl ToGodot) -> Self {
+        arg_into_ref!(property);
+
+        self.node_mut()
+            .upcast_mut::<Object>()
+            .set(property, &value.to_variant());
+        self
+    }
+
+    /// Configures the node through its typed API.
+    ///
+    /// For user-defined classes, use `node.bind_mut()` inside the closure to access the Rust fields.
+    pub fn with(mut self, configure: impl FnOnce(&mut Gd<T>)) -> Self {
+        configure(self.node_mut());
+        self
+    }
+
+    /// Connects typed signals of the node.
+    ///
+    /// The closure receives the same signal collection as [`Gd::signals()`].
+    pub fn signals(self, connect: impl FnOnce(T::SignalCollection<'_, T>)) -> Self
+    where
+        T: WithSignals,
+    {
+        connect(self.node().signals());
+        self
+    }
+
+    /// Adds the node built by `child` as the last child of this node.
+    pub fn child<C: Inherits<Node>>(mut self, mut child: NodeBuilder<C>) -> Self {
+        let child_node = child.take_node().upcast::<Node>();
+        self.node_mut().upcast_mut::<Node>().add_child(&child_node);
+
+        // Without owner, the entire subtree is excluded from saving.
+        if child.has_owner {
+            self.owned_descendants.push(child_node);
+            self.owned_descendants.append(&mut child.owned_descendants);
+        }
+
+        self
+    }
+
+    /// Adds multiple children of the same type, in order.
+    pub fn children<C: Inherits<Node>>(
+        self,
+        children: impl IntoIterator<Item = NodeBuilder<C>>,
+    ) -> Self {
+        children
+            .into_iter()
+            .fold(self, |builder, child| builder.child(child))
+    }
+
+    /// Excludes this node and its descendants from owner assignment, so they are not saved as part of the scene.
+    ///
+    /// Useful for helper nodes that are recreated at runtime. Has no effect on the root node.
+    pub fn without_owner(mut self) -> Self {
+        self.has_owner = false;
+        self
+    }
+
+    /// Finishes the hierarchy, assigning the owner of all descendants, and returns the root node.
+    pub fn build(mut self) -> Gd<T> {
+        let node = self.take_node();
+        let root = node.clone().upcast::<Node>();
+        for mut descendant in self.owned_descendants.drain(..) {
+            descendant.set_owner(&root);
+        }
+
+        node
+    }
+
+    /// Builds the hierarchy and packs it into a new [`PackedScene`], which can then be saved with [`save()`](super::save()).
+    ///
+    /// The built nodes are freed afterward; instantiate the returned scene to obtain new copies.
+    ///
+    /// Underlying Godot method:
+    /// [`PackedScene::pack`](https://docs.godotengine.org/en/stable/classes/class_packedscene.html#class-packedscene-method-pack).
+    pub fn pack(self) -> Result<Gd<PackedScene>, Error> {
+        let root = self.build().upcast::<Node>();
+
+        let mut scene = PackedScene::new_gd();
+        let error = scene.pack(&root);
+        root.free();
+
+        if error == Error::OK {
+            Ok(scene)
+        } else {
+            Err(error)
+        }
+    }
+
+    fn node_mut(&mut self) -> &mut Gd<T> {
+        self.node.as_mut().expect("NodeBuilder already consumed")
+    }
+
+    /// Takes the node out of the builder, so that it is no longer freed on drop.
+    fn take_node(&mut self) -> Gd<T> {
+        self.node.take().expect("NodeBuilder already consumed")
+    }
+}
+
+impl<T: Inherits<Node>> Drop for NodeBuilder<T> {
+    fn drop(&mut self) {
+        // Children are freed together with the node. Skip nodes that have already been freed by the user, to not panic in drop.
+        if let Some(node) = self.node.take() {
+            if node.is_instance_valid() {
+                node.upcast::<Node>().free();
+            }
+        }
+    }
+}
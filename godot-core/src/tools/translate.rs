@@ -34,17 +34,14 @@ pub use crate::{tr, tr_n};
 /// ```
 /// The methods are called from the [`Engine`](crate::classes::Engine) singleton.
 ///
+/// Every invocation is recorded in a translation catalog, which can be written to a `.pot` file with
+/// [`export_pot()`](crate::tools::export_pot). Messages with placeholders are written as `rust-format` entries, keeping the placeholders.
+///
 /// See also: [Translation contexts](https://docs.godotengine.org/en/stable/tutorials/i18n/internationalizing_games.html#translation-contexts)
 /// in Godot.
 #[macro_export]
 macro_rules! tr {
-    ($fmt:literal $(, $($args:tt)*)?) => {{
-        let msg = format!($fmt $(, $($args)*)?);
-
-        <$crate::classes::Engine as $crate::obj::Singleton>::singleton().tr(&msg)
-    }};
-
-    ($context:expr; $fmt:literal $(, $($args:tt)*)?) => {{
+    (@context $context:expr; $fmt:literal $(, $($args:tt)*)?) => {{
         let msg = format!($fmt $(, $($args)*)?);
         let context = format!("{}", $context);
 
@@ -53,6 +50,31 @@ macro_rules! tr {
             .context(&context)
             .done()
     }};
+
+    ($fmt:literal $(, $($args:tt)*)?) => {{
+        $crate::__tr_register!(None, $fmt, None);
+        let msg = format!($fmt $(, $($args)*)?);
+
+        <$crate::classes::Engine as $crate::obj::Singleton>::singleton().tr(&msg)
+    }};
+
+    ($context:literal; $fmt:literal $(, $($args:tt)*)?) => {{
+        $crate::__tr_register!(
+            Some($crate::tools::TranslationContext::Literal(concat!($context))),
+            $fmt,
+            None,
+        );
+        $crate::tr!(@context $context; $fmt $(, $($args)*)?)
+    }};
+
+    ($context:expr; $fmt:literal $(, $($args:tt)*)?) => {{
+        $crate::__tr_register!(
+            Some($crate::tools::TranslationContext::Expression(stringify!($context))),
+            $fmt,
+            None,
+        );
+        $crate::tr!(@context $context; $fmt $(, $($args)*)?)
+    }};
 }
 
 /// A convenience macro for using the [`Object::tr_n()`](crate::classes::Object::tr_n()) and
@@ -82,27 +104,67 @@ macro_rules! tr {
 /// ```
 /// The methods are called from the [`Engine`](crate::classes::Engine) singleton.
 ///
+/// Like [`tr!`], every invocation is recorded in the translation catalog; see [`export_pot()`](crate::tools::export_pot).
+///
 /// See also: [Translation contexts](https://docs.godotengine.org/en/stable/tutorials/i18n/internationalizing_games.html#translation-contexts)
 /// in Godot.
 #[macro_export]
 macro_rules! tr_n {
-    ($n:expr; $singular:literal, $plural:literal $(, $($args:tt)*)?) => {
+    (@context $n:expr, $context:expr; $singular:literal, $plural:literal $(, $($args:tt)*)?) => {
         <$crate::classes::Engine as $crate::obj::Singleton>::singleton()
-            .tr_n(
+            .tr_n_ex(
                 &format!($singular$(, $($args)*)?),
                 &format!($plural$(, $($args)*)?),
                 $n,
             )
+            .context(&format!("{}", $context))
+            .done()
     };
 
-    ($n:expr, $context:expr; $singular:literal, $plural:literal $(, $($args:tt)*)?) => {
+    ($n:expr; $singular:literal, $plural:literal $(, $($args:tt)*)?) => {{
+        $crate::__tr_register!(None, $singular, Some(concat!($plural)));
+
         <$crate::classes::Engine as $crate::obj::Singleton>::singleton()
-            .tr_n_ex(
+            .tr_n(
                 &format!($singular$(, $($args)*)?),
                 &format!($plural$(, $($args)*)?),
                 $n,
             )
-            .context(&format!("{}", $context))
-            .done()
+    }};
+
+    ($n:expr, $context:literal; $singular:literal, $plural:literal $(, $($args:tt)*)?) => {{
+        $crate::__tr_register!(
+            Some($crate::tools::TranslationContext::Literal(concat!($context))),
+            $singular,
+            Some(concat!($plural)),
+        );
+        $crate::tr_n!(@context $n, $context; $singular, $plural $(, $($args)*)?)
+    }};
+
+    ($n:expr, $context:expr; $singular:literal, $plural:literal $(, $($args:tt)*)?) => {{
+        $crate::__tr_register!(
+            Some($crate::tools::TranslationContext::Expression(stringify!($context))),
+            $singular,
+            Some(concat!($plural)),
+        );
+        $crate::tr_n!(@context $n, $context; $singular, $plural $(, $($args)*)?)
+    }};
+}
+
+/// Adds a message to the translation catalog, once per call site. Used by [`tr!`] and [`tr_n!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tr_register {
+    ($context:expr, $msgid:literal, $plural:expr $(,)?) => {
+        $crate::sys::plugin_add!(
+            $crate::private::__GODOT_TRANSLATION_REGISTRY;
+            $crate::tools::TranslationMessage {
+                msgid: concat!($msgid),
+                plural: $plural,
+                context: $context,
+                file: file!(),
+                line: line!(),
+            }
+        );
     };
 }
@@ -0,0 +1,298 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Collection of [`tr!`](crate::tools::tr) and [`tr_n!`](crate::tools::tr_n) messages into gettext templates.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+
+use crate::private::__GODOT_TRANSLATION_REGISTRY;
+use crate::sys;
+
+/// Message of a [`tr!`](crate::tools::tr) or [`tr_n!`](crate::tools::tr_n) invocation, as recorded in the translation catalog.
+///
+/// Registered automatically by the macros; see [`translation_messages()`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct TranslationMessage {
+    /// Format string of the message (singular form for `tr_n!`), as written in the source.
+    pub msgid: &'static str,
+
+    /// Plural format string; only set for `tr_n!`.
+    pub plural: Option<&'static str>,
+
+    /// Translation context, if one was specified.
+    pub context: Option<TranslationContext>,
+
+    /// Source file of the invocation, as returned by [`file!()`].
+    pub file: &'static str,
+
+    /// Line of the invocation in `file`.
+    pub line: u32,
+}
+
+impl TranslationMessage {
+    /// Whether the message contains format placeholders such as `{}` or `{name}`, in the singular or plural form.
+    ///
+    /// Such messages are written to [`pot_catalog()`] with the `rust-format` flag, so that translation tools check that translations keep
+    /// the placeholders.
+    pub fn is_parameterized(&self) -> bool {
+        has_placeholders(self.msgid) || self.plural.is_some_and(has_placeholders)
+    }
+}
+
+/// Translation context of a [`TranslationMessage`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum TranslationContext {
+    /// Context given as a literal, e.g. `tr!("menu"; "Open")`. Written as `msgctxt`.
+    Literal(&'static str),
+
+    /// Context computed at runtime, e.g. `tr!(ctx; "Open")`; holds the source code of the expression.
+    ///
+    /// Since the value is not known at compile time, the message is written without `msgctxt`, and the expression is added as a comment
+    /// for translators.
+    Expression(&'static str),
+}
+
+/// All messages of [`tr!`](crate::tools::tr) and [`tr_n!`](crate::tools::tr_n) invocations in this extension, sorted by source location.
+///
+/// Each invocation is registered once, when the library is loaded, regardless of whether it is ever executed.
+pub fn translation_messages() -> Vec<TranslationMessage> {
+    let mut messages = Vec::new();
+    sys::plugin_foreach!(__GODOT_TRANSLATION_REGISTRY; |message: &TranslationMessage| {
+        messages.push(*message);
+    });
+
+    messages.sort_by_key(|message| (message.file, message.line, message.msgid));
+    messages
+}
+
+/// Generates a gettext template (`.pot`) from all [`translation_messages()`].
+///
+/// Invocations with the same message and context are merged into one entry, listing all source references. The template can be opened in
+/// translation editors such as Poedit; the resulting `.po` files are imported by Godot and loaded into the [`TranslationServer`].
+///
+/// Messages with format placeholders are flagged as `rust-format`, see [`TranslationMessage::is_parameterized()`].
+///
+/// [`TranslationServer`]: crate::classes::TranslationServer
+pub fn pot_catalog() -> String {
+    write_pot(&translation_messages())
+}
+
+/// Writes the result of [`pot_catalog()`] to the file at `path`, replacing an existing file.
+///
+/// This does not need a running engine, so it can also be called from a regular `#[test]` in the extension crate:
+/// ```no_run
+/// #[test]
+/// fn export_translation_template() {
+///     godot::tools::export_pot("../godot/translations/messages.pot").unwrap();
+/// }
+/// ```
+pub fn export_pot(path: impl AsRef<Path>) -> io::Result<()> {
+    std::fs::write(path, pot_catalog())
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// POT generation
+
+/// One `msgid` entry, possibly referenced from multiple source locations.
+struct PotEntry {
+    msgctxt: Option<&'static str>,
+    msgid: &'static str,
+    plural: Option<&'static str>,
+    is_parameterized: bool,
+    references: Vec<String>,
+    runtime_contexts: Vec<&'static str>,
+}
+
+fn write_pot(messages: &[TranslationMessage]) -> String {
+    let mut entries: Vec<PotEntry> = Vec::new();
+    let mut index_by_key = HashMap::new();
+
+    for message in messages {
+        let msgctxt = match message.context {
+            Some(TranslationContext::Literal(context)) => Some(context),
+            _ => None,
+        };
+
+        let index = *index_by_key
+            .entry((msgctxt, message.msgid))
+            .or_insert_with(|| {
+                entries.push(PotEntry {
+                    msgctxt,
+                    msgid: message.msgid,
+                    plural: None,
+                    is_parameterized: false,
+                    references: Vec::new(),
+                    runtime_contexts: Vec::new(),
+                });
+                entries.len() - 1
+            });
+
+        // Plural forms may come from any invocation with the same msgid.
+        let entry = &mut entries[index];
+        entry.plural = entry.plural.or(message.plural);
+        entry.is_parameterized |= message.is_parameterized();
+        entry
+            .references
+            .push(format!("{}:{}", message.file, message.line));
+
+        if let Some(TranslationContext::Expression(expr)) = message.context {
+            if !entry.runtime_contexts.contains(&expr) {
+                entry.runtime_contexts.push(expr);
+            }
+        }
+    }
+
+    let mut out = String::from(
+        "# Translation template, generated by godot-rust from tr!/tr_n! invocations.\n\
+        #\n\
+        #, fuzzy\n\
+        msgid \"\"\n\
+        msgstr \"\"\n\
+        \"MIME-Version: 1.0\\n\"\n\
+        \"Content-Type: text/plain; charset=UTF-8\\n\"\n\
+        \"Content-Transfer-Encoding: 8-bit\\n\"\n",
+    );
+
+    for entry in entries {
+        out.push('\n');
+
+        for expr in &entry.runtime_contexts {
+            let _ = writeln!(out, "#. Context determined at runtime: {expr}");
+        }
+        let _ = writeln!(out, "#: {}", entry.references.join(" "));
+
+        // Format strings are kept as written, including `{{` escapes, like xgettext does for Rust.
+        let to_msgid = |text: &str| {
+            if entry.is_parameterized {
+                text.to_string()
+            } else {
+                unescape_braces(text)
+            }
+        };
+
+        if entry.is_parameterized {
+            out.push_str("#, rust-format\n");
+        }
+        if let Some(context) = entry.msgctxt {
+            let _ = writeln!(out, "msgctxt {}", quote_po(context));
+        }
+        let _ = writeln!(out, "msgid {}", quote_po(&to_msgid(entry.msgid)));
+
+        match entry.plural {
+            Some(plural) => {
+                let _ = writeln!(out, "msgid_plural {}", quote_po(&to_msgid(plural)));
+                out.push_str("msgstr[0] \"\"\nmsgstr[1] \"\"\n");
+            }
+            None => out.push_str("msgstr \"\"\n"),
+        }
+    }
+
+    out
+}
+
+/// Whether `text` contains a `format!` placeholder, i.e. a `{` that is not escaped as `{{`.
+fn has_placeholders(text: &str) -> bool {
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '{' && chars.next() != Some('{') {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Replaces `{{` and `}}` with single braces, as `format!` does. Only used for messages without placeholders.
+fn unescape_braces(text: &str) -> String {
+    text.replace("{{", "{").replace("}}", "}")
+}
+
+/// Quotes and escapes a string for use in a PO file.
+fn quote_po(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() + 2);
+    result.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            '\r' => result.push_str("\\r"),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(
+        msgid: &'static str,
+        plural: Option<&'static str>,
+        context: Option<TranslationContext>,
+        line: u32,
+    ) -> TranslationMessage {
+        TranslationMessage {
+            msgid,
+            plural,
+            context,
+            file: "src/lib.rs",
+            line,
+        }
+    }
+
+    #[test]
+    fn pot_entries() {
+        let pot = write_pot(&[
+            message("Open", None, None, 3),
+            message("Open", None, Some(TranslationContext::Literal("door")), 5),
+            message("Open", None, None, 8),
+            message("{n} apple", Some("{n} apples"), None, 10),
+            message("Apple", Some("{} apples"), None, 11),
+            message("{{braces}}", None, None, 11),
+            message(
+                "Say \"hi\"\n",
+                None,
+                Some(TranslationContext::Expression("ctx")),
+                12,
+            ),
+        ]);
+
+        assert!(pot.starts_with("# Translation template"));
+        assert!(pot.contains("\"Content-Type: text/plain; charset=UTF-8\\n\"\n"));
+        assert!(pot.contains("\n#: src/lib.rs:3 src/lib.rs:8\nmsgid \"Open\"\nmsgstr \"\"\n"));
+        assert!(pot.contains("\n#: src/lib.rs:5\nmsgctxt \"door\"\nmsgid \"Open\"\nmsgstr \"\"\n"));
+        assert!(pot.contains("\n#: src/lib.rs:11\nmsgid \"{braces}\"\nmsgstr \"\"\n"));
+        assert!(pot.contains(
+            "\n#: src/lib.rs:10\n#, rust-format\nmsgid \"{n} apple\"\nmsgid_plural \"{n} apples\"\nmsgstr[0] \"\"\nmsgstr[1] \"\"\n"
+        ));
+        assert!(pot.contains("#, rust-format\nmsgid \"Apple\"\nmsgid_plural \"{} apples\"\n"));
+        assert!(pot.contains(
+            "\n#. Context determined at runtime: ctx\n#: src/lib.rs:12\nmsgid \"Say \\\"hi\\\"\\n\"\n"
+        ));
+    }
+
+    #[test]
+    fn placeholders() {
+        assert!(has_placeholders("{}"));
+        assert!(has_placeholders("Hello {name}!"));
+        assert!(has_placeholders("{{literal}} and {0}"));
+        assert!(has_placeholders("{"));
+
+        assert!(!has_placeholders("Hello"));
+        assert!(!has_placeholders("{{literal}}"));
+        assert!(!has_placeholders("closing }} only"));
+    }
+}
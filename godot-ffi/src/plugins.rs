@@ -48,6 +48,8 @@ macro_rules! plugin_execute_pre_main {
             static __init: extern "C" fn() = {
                 #[cfg_attr(target_os = "android", link_section = ".text.startup")]
                 #[cfg_attr(target_os = "linux", link_section = ".text.startup")]
+                // Registration of `#[deprecated]` classes and members is not a use of them.
+                #[allow(deprecated)]
                 extern "C" fn __inner_init() {
                     $body
                 }
@@ -13,10 +13,29 @@ use crate::{util, ParseResult};
 
 pub struct ConstDefinition {
     pub raw_constant: venial::Constant,
+
+    /// Set for `#[constant(enum = Name)]` and `#[constant(bitfield = Name)]`.
+    pub group: Option<ConstGroup>,
+}
+
+/// Enum or bitfield that a constant belongs to.
+pub struct ConstGroup {
+    pub name: Ident,
+    pub is_bitfield: bool,
+}
+
+/// Rust enum registered as enum or bitfield of the class, via `#[constant(enum)] type Name = MyEnum;`.
+pub struct ConstEnumDefinition {
+    /// Name of the enum in the class (the name of the type alias).
+    pub name: Ident,
+    /// The Rust enum, implementing `ConstantEnum`.
+    pub enum_ty: venial::TypeExpr,
+    pub is_bitfield: bool,
 }
 
 pub fn make_constant_registration(
     consts: Vec<ConstDefinition>,
+    constant_enums: &[ConstEnumDefinition],
     class_name: &Ident,
     class_name_obj: &TokenStream,
 ) -> ParseResult<TokenStream> {
@@ -24,8 +43,14 @@ pub fn make_constant_registration(
     let mut integer_constant_names = Vec::new();
     let mut integer_constant_values = Vec::new();
 
-    for constant in consts.iter() {
-        let constant = &constant.raw_constant;
+    // Enums and bitfields, in order of first occurrence: (group, [(cfg_attrs, name, value)]).
+    let mut groups: Vec<(
+        &ConstGroup,
+        Vec<(Vec<&venial::Attribute>, String, TokenStream)>,
+    )> = Vec::new();
+
+    for constant_def in consts.iter() {
+        let constant = &constant_def.raw_constant;
         if constant.initializer.is_none() {
             return bail!(constant, "exported const should have initializer");
         };
@@ -38,15 +63,93 @@ pub fn make_constant_registration(
             .into_iter()
             .collect::<Vec<_>>();
 
+        let value = quote! { #class_name::#name };
+
+        if let Some(group) = &constant_def.group {
+            let entry = (cfg_attrs, name.to_string(), value);
+
+            match groups.iter_mut().find(|(g, _)| g.name == group.name) {
+                Some((existing, entries)) => {
+                    if existing.is_bitfield != group.is_bitfield {
+                        return bail!(
+                            &group.name,
+                            "`{}` is used both as `enum` and `bitfield`",
+                            group.name
+                        );
+                    }
+                    entries.push(entry);
+                }
+                None => groups.push((group, vec![entry])),
+            }
+            continue;
+        }
+
         // Transport #[cfg] attributes to the FFI glue, to ensure constants which were conditionally removed
         // from compilation don't cause errors.
         integer_constant_cfg_attrs.push(cfg_attrs);
         integer_constant_names.push(constant.name.to_string());
-        integer_constant_values.push(quote! { #class_name::#name });
+        integer_constant_values.push(value);
     }
 
-    let tokens = if !integer_constant_names.is_empty() {
+    if integer_constant_names.is_empty() && groups.is_empty() && constant_enums.is_empty() {
+        return Ok(TokenStream::new());
+    }
+
+    let group_registrations = groups.into_iter().map(|(group, entries)| {
+        let group_name = group.name.to_string();
+        let (kind, list_field) = if group.is_bitfield {
+            (quote! { Bitfield }, quote! { flags })
+        } else {
+            (quote! { Enum }, quote! { enumerators })
+        };
+
+        let pushes = entries.into_iter().map(|(cfg_attrs, name, value)| {
+            quote! {
+                #(#cfg_attrs)*
+                constants.push(IntegerConstant::new(#name, #value));
+            }
+        });
+
         quote! {
+            {
+                let mut constants = Vec::new();
+                #( #pushes )*
+
+                ExportConstant::new(
+                    #class_name_obj,
+                    ConstantKind::#kind {
+                        name: StringName::from(#group_name),
+                        #list_field: constants,
+                    }
+                ).register();
+            }
+        }
+    });
+
+    let enum_registrations = constant_enums.iter().map(|def| {
+        let enum_name = def.name.to_string();
+        let enum_ty = &def.enum_ty;
+        let (kind, list_field) = if def.is_bitfield {
+            (quote! { Bitfield }, quote! { flags })
+        } else {
+            (quote! { Enum }, quote! { enumerators })
+        };
+
+        quote! {
+            ExportConstant::new(
+                #class_name_obj,
+                ConstantKind::#kind {
+                    name: StringName::from(#enum_name),
+                    #list_field: <#enum_ty as ConstantEnum>::enumerators(),
+                }
+            ).register();
+        }
+    });
+
+    let tokens = quote! {
+        // `#[deprecated]` on a #[constant] or enum documents it as deprecated in Godot; registering it is not a use.
+        #[allow(deprecated)]
+        {
             use ::godot::register::private::constant::*;
             use ::godot::meta::ClassId;
             use ::godot::builtin::StringName;
@@ -63,9 +166,10 @@ pub fn make_constant_registration(
                     )
                 ).register();
             )*
+
+            #( #group_registrations )*
+            #( #enum_registrations )*
         }
-    } else {
-        TokenStream::new()
     };
 
     Ok(tokens)
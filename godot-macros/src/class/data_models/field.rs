@@ -8,7 +8,7 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens};
 
-use crate::class::data_models::group_export::FieldGroup;
+use crate::class::data_models::group_export::{FieldCategory, FieldGroup};
 use crate::class::{FieldExport, FieldVar};
 use crate::util::{error, KvParser};
 
@@ -18,6 +18,7 @@ pub struct Field {
     pub default_val: Option<FieldDefault>,
     pub var: Option<FieldVar>,
     pub export: Option<FieldExport>,
+    pub category: Option<FieldCategory>,
     pub group: Option<FieldGroup>,
     pub subgroup: Option<FieldGroup>,
     pub is_onready: bool,
@@ -36,6 +37,7 @@ impl Field {
             default_val: None,
             var: None,
             export: None,
+            category: None,
             group: None,
             subgroup: None,
             is_onready: false,
@@ -7,10 +7,10 @@
 
 use std::collections::{HashMap, HashSet};
 
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Delimiter, Ident, Span, TokenStream};
 use quote::quote;
 
-use crate::util::{bail, ident, KvParser, ListParser};
+use crate::util::{bail, ident, require_api_version, KvParser, ListParser};
 use crate::ParseResult;
 
 pub struct FieldExport {
@@ -25,11 +25,38 @@ impl FieldExport {
         Ok(Self { export_type, span })
     }
 
+    /// Parse an `#[export_tool_button(...)]` attribute.
+    ///
+    /// Possible keys:
+    /// - `label = expr` (required)
+    /// - `icon = expr`
+    /// - `method = ident`
+    ///
+    /// Returns the export, as well as the `#[func]` bound by `method`, if present.
+    pub(crate) fn new_tool_button(parser: &mut KvParser) -> ParseResult<(Self, Option<Ident>)> {
+        let span = parser.span();
+        let label = parser.handle_expr_required("label")?;
+        let icon = parser.handle_expr("icon")?;
+        let method = parser.handle_ident("method")?;
+
+        let export = Self {
+            export_type: ExportType::ToolButton { label, icon },
+            span,
+        };
+
+        Ok((export, method))
+    }
+
     pub fn to_export_hint(&self) -> Option<TokenStream> {
         self.export_type.to_export_hint()
     }
 
-    pub fn to_export_usage(&self) -> Option<Ident> {
+    /// Whether the field type must implement `Export`. Tool buttons hold a `Callable`, which can only be registered via `Var`.
+    pub fn requires_export_trait(&self) -> bool {
+        !matches!(self.export_type, ExportType::ToolButton { .. })
+    }
+
+    pub fn to_export_usage(&self) -> Option<Vec<Ident>> {
         self.export_type.to_export_usage()
     }
 }
@@ -154,6 +181,80 @@ pub enum ExportType {
     /// ### Property hints
     /// - `COLOR_NO_ALPHA`
     ColorNoAlpha,
+
+    /// ### GDScript annotations
+    /// - none
+    ///
+    /// ### Property hints
+    /// - `PASSWORD`
+    Password,
+
+    /// ### GDScript annotations
+    /// - none
+    ///
+    /// ### Property hints
+    /// - `LOCALE_ID`
+    LocaleId,
+
+    /// ### GDScript annotations
+    /// - none
+    ///
+    /// ### Property hints
+    /// - `EXPRESSION`
+    Expression,
+
+    /// ### GDScript annotations
+    /// - none
+    ///
+    /// ### Property hints
+    /// - `LINK`
+    Link,
+
+    /// ### GDScript annotations
+    /// - none
+    ///
+    /// ### Property hints
+    /// - `INPUT_NAME`
+    InputName {
+        show_builtin: bool,
+        loose_mode: bool,
+    },
+
+    /// ### GDScript annotations
+    /// - `@export_node_path`
+    ///
+    /// ### Property hints
+    /// - `NODE_PATH_VALID_TYPES`
+    NodePathValidTypes { classes: Vec<TokenStream> },
+
+    /// ### GDScript annotations
+    /// - `@export_custom`
+    ///
+    /// ### Property hints
+    /// - any
+    ///
+    /// ### Property usage
+    /// - any (`DEFAULT` if omitted)
+    Custom {
+        hint: Ident,
+        hint_string: Option<TokenStream>,
+        usage: Option<Vec<Ident>>,
+    },
+
+    /// ### GDScript annotations
+    /// - `@export_tool_button`
+    ///
+    /// ### Property hints
+    /// - `TOOL_BUTTON`
+    ///
+    /// ### Property usage
+    /// - `EDITOR`
+    ///
+    /// Declared with the separate `#[export_tool_button]` attribute, as the field holds a `Callable` rather than a value.
+    ToolButton {
+        label: TokenStream,
+        icon: Option<TokenStream>,
+    },
 }
 
 impl ExportType {
@@ -292,6 +393,35 @@ impl ExportType {
             return Ok(Self::ColorNoAlpha);
         }
 
+        if parser.handle_alone("password")? {
+            return Ok(Self::Password);
+        }
+
+        if parser.handle_alone("locale_id")? {
+            return Ok(Self::LocaleId);
+        }
+
+        if parser.handle_alone("expression")? {
+            return Ok(Self::Expression);
+        }
+
+        if parser.handle_alone("link")? {
+            return Ok(Self::Link);
+        }
+
+        if let Some(list_parser) = parser.handle_list("input_name")? {
+            require_api_version!("4.3", parser.span(), "#[export(input_name)]")?;
+            return Self::new_input_name(list_parser);
+        }
+
+        if let Some(list_parser) = parser.handle_list("node_path_valid_types")? {
+            return Self::new_node_path_valid_types(list_parser, parser.span());
+        }
+
+        if let Some(list_parser) = parser.handle_list("custom")? {
+            return Self::new_custom(list_parser, parser.span());
+        }
+
         Ok(Self::Default)
     }
 
@@ -414,6 +544,88 @@ impl ExportType {
 
         Ok(Self::Flags { bits })
     }
+
+    fn new_input_name(mut parser: ListParser) -> ParseResult<Self> {
+        const ALLOWED_OPTIONS: [&str; 2] = ["show_builtin", "loose_mode"];
+
+        let mut options = HashSet::new();
+
+        while let Some(option) = parser.next_allowed_ident(&ALLOWED_OPTIONS[..])? {
+            options.insert(option.to_string());
+        }
+
+        parser.finish()?;
+
+        Ok(Self::InputName {
+            show_builtin: options.contains("show_builtin"),
+            loose_mode: options.contains("loose_mode"),
+        })
+    }
+
+    fn new_node_path_valid_types(mut parser: ListParser, span: Span) -> ParseResult<Self> {
+        let mut classes = Vec::new();
+
+        while parser.peek().is_some() {
+            classes.push(parser.next_expr()?);
+        }
+
+        parser.finish()?;
+
+        if classes.is_empty() {
+            return bail!(
+                span,
+                "#[export(node_path_valid_types = (...))]: expected at least one node class"
+            );
+        }
+
+        Ok(Self::NodePathValidTypes { classes })
+    }
+
+    fn new_custom(mut parser: ListParser, span: Span) -> ParseResult<Self> {
+        const KV_OPTIONS: [&str; 3] = ["hint", "hint_string", "usage"];
+
+        let mut kvs = HashMap::new();
+
+        while let Some((key, value)) = parser.next_allowed_key_optional_value(&[], &KV_OPTIONS)? {
+            let value = value.expect("only key-value options are allowed");
+            if kvs.insert(key.to_string(), value).is_some() {
+                return bail!(&key, "duplicate key `{key}`");
+            }
+        }
+
+        parser.finish()?;
+
+        let Some(hint) = kvs.remove("hint") else {
+            return bail!(
+                span,
+                "#[export(custom = (...))]: missing required key `hint = IDENT`"
+            );
+        };
+        let hint = hint.ident()?;
+
+        let hint_string = kvs.remove("hint_string").map(|kv| kv.expr()).transpose()?;
+
+        let usage = match kvs.remove("usage") {
+            Some(kv) => {
+                let mut flags_parser = ListParser::new_from_tree(kv.single()?, Delimiter::Bracket)?;
+                let mut flags = Vec::new();
+
+                while let Some(flag) = flags_parser.next_ident()? {
+                    flags.push(flag);
+                }
+
+                flags_parser.finish()?;
+                Some(flags)
+            }
+            None => None,
+        };
+
+        Ok(Self::Custom {
+            hint,
+            hint_string,
+            usage,
+        })
+    }
 }
 
 macro_rules! quote_export_func {
@@ -538,13 +750,49 @@ impl ExportType {
             },
 
             Self::ColorNoAlpha => quote_export_func! { export_color_no_alpha() },
+
+            Self::Password => quote_export_func! { export_password<T>() },
+
+            Self::LocaleId => quote_export_func! { export_locale_id<T>() },
+
+            Self::Expression => quote_export_func! { export_expression<T>() },
+
+            Self::Link => quote_export_func! { export_link() },
+
+            Self::InputName {
+                show_builtin,
+                loose_mode,
+            } => quote_export_func! { export_input_name<T>(#show_builtin, #loose_mode) },
+
+            Self::NodePathValidTypes { classes } => quote_export_func! {
+                export_node_path_valid_types<T>(&[#(
+                    ::godot::register::property::export_info_functions::node_class_id::<#classes>()
+                ),*])
+            },
+
+            Self::Custom {
+                hint, hint_string, ..
+            } => {
+                let hint_string = hint_string.clone().unwrap_or(quote! { "" });
+                quote_export_func! {
+                    export_custom(::godot::global::PropertyHint::#hint, #hint_string)
+                }
+            }
+
+            Self::ToolButton { label, icon } => {
+                let icon = icon.clone().unwrap_or(quote! { "" });
+                quote_export_func! { export_tool_button<T>(#label, #icon) }
+            }
         }
     }
 
-    /// Returns a `PropertyUsageFlags` identifier if this export type has a _usage_.
-    pub fn to_export_usage(&self) -> Option<Ident> {
+    /// Returns `PropertyUsageFlags` identifiers if this export type has a _usage_.
+    pub fn to_export_usage(&self) -> Option<Vec<Ident>> {
         match self {
-            Self::Storage => Some(ident("STORAGE")),
+            Self::Storage => Some(vec![ident("STORAGE")]),
+            // Only shown in the inspector; the button has no state to save.
+            Self::ToolButton { .. } => Some(vec![ident("EDITOR")]),
+            Self::Custom { usage, .. } => usage.clone(),
             _ => None,
         }
     }
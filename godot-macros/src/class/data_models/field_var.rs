@@ -110,6 +110,11 @@ pub enum GetterSetter {
 
     /// Getter/setter is handwritten by the user, and here is its identifier.
     Custom(Ident),
+
+    /// Getter is autogenerated and returns a `Callable` bound to the given `#[func]` of the same object.
+    ///
+    /// Used by `#[export_tool_button(method = ...)]`.
+    MethodCallable(Ident),
 }
 
 impl GetterSetter {
@@ -147,6 +152,15 @@ impl GetterSetter {
             GetterSetter::Custom(function_name) => {
                 Some(GetterSetterImpl::from_custom_impl(function_name))
             }
+            GetterSetter::MethodCallable(method_name) => {
+                debug_assert_eq!(kind, GetSet::Get, "method callables are getter-only");
+                Some(GetterSetterImpl::from_method_callable(
+                    class_name,
+                    field,
+                    rename,
+                    method_name,
+                ))
+            }
         }
     }
 
@@ -218,6 +232,41 @@ impl GetterSetterImpl {
             }
         }
 
+        Self::from_signature_and_body(class_name, function_name, signature, function_body)
+    }
+
+    fn from_method_callable(
+        class_name: &Ident,
+        field: &Field,
+        rename: &Option<Ident>,
+        method_name: &Ident,
+    ) -> Self {
+        let var_name = rename.as_ref().unwrap_or(&field.name);
+        let function_name = format_ident!("{}{var_name}", GetSet::Get.prefix());
+
+        // Referring to the funcs collection constant ensures at compile time that `method_name` is a #[func], and respects renames.
+        let funcs_collection = util::format_funcs_collection_struct(class_name);
+        let method_constant = util::format_funcs_collection_constant(class_name, method_name);
+
+        let signature = quote! {
+            fn #function_name(&self) -> ::godot::builtin::Callable
+        };
+        let function_body = quote! {
+            ::godot::builtin::Callable::from_object_method(
+                &::godot::obj::WithBaseField::to_gd(self),
+                #funcs_collection::#method_constant,
+            )
+        };
+
+        Self::from_signature_and_body(class_name, function_name, signature, function_body)
+    }
+
+    fn from_signature_and_body(
+        class_name: &Ident,
+        function_name: Ident,
+        signature: TokenStream,
+        function_body: TokenStream,
+    ) -> Self {
         let function_impl = quote! {
             pub #signature {
                 #function_body
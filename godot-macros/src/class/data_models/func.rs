@@ -104,7 +104,7 @@ pub fn make_method_registration(
 ) -> ParseResult<TokenStream> {
     let signature_info = &func_definition.signature_info;
     let sig_params = signature_info.params_type();
-    let sig_ret = &signature_info.return_type;
+    let sig_ret = signature_info.registered_return_type();
 
     let is_script_virtual = func_definition.is_script_virtual;
     let method_flags = match make_method_flags(signature_info.receiver_type, is_script_virtual) {
@@ -154,6 +154,8 @@ pub fn make_method_registration(
 
     let registration = quote! {
         #(#cfg_attrs)*
+        // `#[deprecated]` on a #[func] documents it as deprecated in Godot; registering it is not a use.
+        #[allow(deprecated)]
         {
             use ::godot::obj::GodotClass;
             use ::godot::register::private::method::ClassMethodInfo;
@@ -266,6 +268,9 @@ pub struct SignatureInfo {
 
     /// Default value expressions `EXPR` from `#[opt(default = EXPR)]`, for all optional parameters.
     pub optional_param_default_exprs: Vec<TokenStream>,
+
+    /// Whether the Rust function is `async fn`. Godot then sees a method returning a handle object, see [`Self::registered_return_type`].
+    pub is_async: bool,
 }
 
 impl SignatureInfo {
@@ -279,6 +284,27 @@ impl SignatureInfo {
             return_type: quote! { () },
             modified_param_types: vec![],
             optional_param_default_exprs: vec![],
+            is_async: false,
+        }
+    }
+
+    /// Return type of the method as registered with Godot.
+    ///
+    /// Same as the Rust return type, except for `async fn`, which returns a `RefCounted` emitting `completed(result)`.
+    pub fn registered_return_type(&self) -> TokenStream {
+        if self.is_async {
+            quote! { ::godot::obj::Gd<::godot::classes::RefCounted> }
+        } else {
+            self.return_type.clone()
+        }
+    }
+
+    /// Wraps the invocation of the Rust function, spawning the returned future for `async fn`.
+    fn wrap_call(&self, method_call: TokenStream) -> TokenStream {
+        if self.is_async {
+            quote! { ::godot::private::spawn_async_func(#method_call) }
+        } else {
+            method_call
         }
     }
 
@@ -410,6 +436,10 @@ fn make_forwarding_closure(
                 TokenStream::new()
             };
 
+            let method_call = signature_info.wrap_call(quote! {
+                #class_name::#method_name(::godot::private::Storage::get_gd(storage), #(#params),*)
+            });
+
             quote! {
                 // Identifiers need to share the span to avoid proc macro hygiene issues
                 // similar to https://github.com/godot-rust/gdext/pull/1397.
@@ -421,7 +451,7 @@ fn make_forwarding_closure(
                         unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
 
                     #before_method_call
-                    #class_name::#method_name(::godot::private::Storage::get_gd(storage), #(#params),*)
+                    #method_call
                 }
             }
         }
@@ -430,10 +460,14 @@ fn make_forwarding_closure(
             //
             // Identifiers need to share the span to avoid proc macro hygiene issues
             // similar to https://github.com/godot-rust/gdext/pull/1397.
+            let method_call = signature_info.wrap_call(quote! {
+                #class_name::#method_name(#(#params),*)
+            });
+
             quote! {
                 |_, #param_ident| {
                     let #params_tuple = #param_ident;
-                    #class_name::#method_name(#(#params),*)
+                    #method_call
                 }
             }
         }
@@ -469,6 +503,7 @@ pub(crate) fn into_signature_info(
     has_gd_self: bool,
 ) -> SignatureInfo {
     let method_name = signature.name.clone();
+    let is_async = signature.qualifiers.tk_async.is_some();
     let mut receiver_type = if has_gd_self {
         ReceiverType::GdSelf
     } else {
@@ -538,6 +573,7 @@ pub(crate) fn into_signature_info(
         return_type,
         modified_param_types,
         optional_param_default_exprs: vec![], // Assigned outside, if relevant.
+        is_async,
     }
 }
 
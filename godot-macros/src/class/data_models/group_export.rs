@@ -37,3 +37,23 @@ impl FieldGroup {
         Ok(Self { name, prefix })
     }
 }
+
+/// Specifies a category which starts with a given field.
+///
+/// Categories are one level above groups: a category ends any open group and subgroup, and lasts until the next category.
+pub struct FieldCategory {
+    pub(crate) name: Literal,
+}
+
+impl FieldCategory {
+    pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
+        let Some(name) = parser.handle_literal("name", "String")? else {
+            return bail!(
+                parser.span(),
+                "missing required argument: `name = \"...\"`."
+            );
+        };
+
+        Ok(Self { name })
+    }
+}
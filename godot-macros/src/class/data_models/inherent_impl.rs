@@ -12,8 +12,8 @@ use quote::{format_ident, quote, ToTokens};
 use crate::class::data_models::func;
 use crate::class::{
     into_signature_info, make_constant_registration, make_method_registration,
-    make_signal_registrations, ConstDefinition, FuncDefinition, RpcAttr, RpcMode, SignalDefinition,
-    SignatureInfo, TransferMode,
+    make_signal_registrations, ConstDefinition, ConstEnumDefinition, ConstGroup, FuncDefinition,
+    ReceiverType, RpcAttr, RpcMode, SignalDefinition, SignatureInfo, TransferMode,
 };
 use crate::util::{
     bail, c_str, format_funcs_collection_struct, ident, make_funcs_collection_constants,
@@ -25,7 +25,7 @@ use crate::{handle_mutually_exclusive_keys, util, ParseResult};
 enum ItemAttrType {
     Func(FuncAttr, Option<RpcAttr>),
     Signal(SignalAttr, venial::AttributeValue),
-    Const(#[allow(dead_code)] venial::AttributeValue),
+    Const(ConstAttr),
 }
 
 struct ItemAttr {
@@ -38,7 +38,7 @@ enum AttrParseResult {
     Rpc(RpcAttr),
     FuncRpc(FuncAttr, RpcAttr),
     Signal(SignalAttr, venial::AttributeValue),
-    Constant(#[allow(dead_code)] venial::AttributeValue),
+    Constant(ConstAttr),
 }
 
 impl AttrParseResult {
@@ -66,6 +66,18 @@ struct SignalAttr {
     pub no_builder: bool,
 }
 
+/// `#[constant]` with optional `enum`/`bitfield` key.
+struct ConstAttr {
+    /// Either `enum = Name` or `bitfield = Name` on a constant, or `enum`/`bitfield` without value on a type alias.
+    group: Option<(Ident, Option<Ident>)>,
+}
+
+impl ConstAttr {
+    fn is_bitfield(&self) -> bool {
+        matches!(&self.group, Some((key, _)) if key == "bitfield")
+    }
+}
+
 pub(crate) struct InherentImplAttr {
     /// For implementation reasons, there can be a single 'primary' impl block and 0 or more 'secondary' impl blocks.
     /// For now, this is controlled by a key in the 'godot_api' attribute.
@@ -89,10 +101,16 @@ pub fn transform_inherent_impl(
 
     // Can add extra functions to the end of the impl block.
     let (funcs, signals) = process_godot_fns(&class_name, &mut impl_block, meta.secondary)?;
-    let consts = process_godot_constants(&mut impl_block)?;
+    let (consts, constant_enums) = process_godot_constants(&mut impl_block)?;
 
-    let inherent_impl_docs =
-        crate::docs::make_trait_docs_registration(&funcs, &consts, &signals, &class_name, &prv);
+    let inherent_impl_docs = crate::docs::make_trait_docs_registration(
+        &funcs,
+        &consts,
+        &constant_enums,
+        &signals,
+        &class_name,
+        &prv,
+    );
 
     // Container struct holding names of all registered #[func]s.
     // The struct is declared by #[derive(GodotClass)].
@@ -115,12 +133,18 @@ pub fn transform_inherent_impl(
     #[cfg(not(feature = "codegen-full"))]
     let rpc_registrations = TokenStream::new();
 
+    #[cfg(feature = "codegen-full")]
+    let rpc_accessors = crate::class::make_rpc_accessors(&class_name, &funcs);
+    #[cfg(not(feature = "codegen-full"))]
+    let rpc_accessors = TokenStream::new();
+
     let method_registrations: Vec<TokenStream> = funcs
         .into_iter()
         .map(|func_def| make_method_registration(&class_name, func_def, None))
         .collect::<ParseResult<Vec<TokenStream>>>()?;
 
-    let constant_registration = make_constant_registration(consts, &class_name, &class_name_obj)?;
+    let constant_registration =
+        make_constant_registration(consts, &constant_enums, &class_name, &class_name_obj)?;
 
     let method_storage_name = format_ident!("__registration_methods_{class_name}");
     let constants_storage_name = format_ident!("__registration_constants_{class_name}");
@@ -190,6 +214,7 @@ pub fn transform_inherent_impl(
                 #( #func_name_constants )*
             }
             #signal_symbol_types
+            #rpc_accessors
             #inherent_impl_docs
         };
 
@@ -247,9 +272,11 @@ fn process_godot_fns(
             continue;
         };
 
+        // `async` is allowed for #[func] and validated below.
         if function.qualifiers.tk_default.is_some()
             || function.qualifiers.tk_const.is_some()
-            || function.qualifiers.tk_async.is_some()
+            || (function.qualifiers.tk_async.is_some()
+                && !matches!(attr.ty, ItemAttrType::Func(..)))
             || function.qualifiers.tk_unsafe.is_some()
             || function.qualifiers.tk_extern.is_some()
             || function.qualifiers.extern_abi.is_some()
@@ -294,6 +321,10 @@ fn process_godot_fns(
                 let mut signature_info =
                     into_signature_info(signature.clone(), class_name, gd_self_parameter.is_some());
 
+                if signature_info.is_async {
+                    validate_async_func(function, &signature_info, &func)?;
+                }
+
                 // Default value expressions from `#[opt(default = EXPR)]`; None for required parameters.
                 let all_param_maybe_defaults = parse_default_expressions(&mut function.params)?;
                 signature_info.optional_param_default_exprs =
@@ -383,38 +414,134 @@ fn process_godot_fns(
     Ok((func_definitions, signal_definitions))
 }
 
-fn process_godot_constants(decl: &mut venial::Impl) -> ParseResult<Vec<ConstDefinition>> {
+/// Async functions run across multiple frames, so they cannot hold a borrow of the instance.
+fn validate_async_func(
+    function: &venial::Function,
+    signature_info: &SignatureInfo,
+    func: &FuncAttr,
+) -> ParseResult<()> {
+    if matches!(
+        signature_info.receiver_type,
+        ReceiverType::Ref | ReceiverType::Mut
+    ) {
+        return bail!(
+            &function.name,
+            "async #[func] cannot borrow `self` across await points; \
+            use #[func(gd_self)] with a `this: Gd<Self>` parameter, or a static function"
+        );
+    }
+
+    if func.is_virtual {
+        return bail!(&function.name, "async #[func] cannot be virtual");
+    }
+
+    Ok(())
+}
+
+fn process_godot_constants(
+    decl: &mut venial::Impl,
+) -> ParseResult<(Vec<ConstDefinition>, Vec<ConstEnumDefinition>)> {
     let mut constant_signatures = vec![];
+    let mut constant_enums = vec![];
+    let mut removed_indexes = vec![];
 
-    for item in decl.body_items.iter_mut() {
-        let venial::ImplMember::AssocConstant(constant) = item else {
-            continue;
-        };
+    for (index, item) in decl.body_items.iter_mut().enumerate() {
+        match item {
+            venial::ImplMember::AssocConstant(constant) => {
+                let Some(attr) = parse_attributes(constant)? else {
+                    continue;
+                };
 
-        if let Some(attr) = parse_attributes(constant)? {
-            match attr.ty {
-                ItemAttrType::Func(_, _) => {
-                    return bail!(constant, "#[func] and #[rpc] can only be used on functions")
-                }
-                ItemAttrType::Signal(_, _) => {
-                    return bail!(constant, "#[signal] can only be used on functions")
-                }
-                ItemAttrType::Const(_) => {
-                    if constant.initializer.is_none() {
-                        return bail!(constant, "exported constant must have initializer");
+                match attr.ty {
+                    ItemAttrType::Func(_, _) => {
+                        return bail!(constant, "#[func] and #[rpc] can only be used on functions")
                     }
+                    ItemAttrType::Signal(_, _) => {
+                        return bail!(constant, "#[signal] can only be used on functions")
+                    }
+                    ItemAttrType::Const(const_attr) => {
+                        if constant.initializer.is_none() {
+                            return bail!(constant, "exported constant must have initializer");
+                        }
+
+                        let is_bitfield = const_attr.is_bitfield();
+                        let group = match const_attr.group {
+                            None => None,
+                            Some((_, Some(name))) => Some(ConstGroup { name, is_bitfield }),
+                            Some((key, None)) => {
+                                return bail!(
+                                    key,
+                                    "#[constant({key})] on a constant requires a name: `#[constant({key} = Name)]`"
+                                )
+                            }
+                        };
+
+                        let definition = ConstDefinition {
+                            raw_constant: constant.clone(),
+                            group,
+                        };
+
+                        constant_signatures.push(definition);
+                    }
+                }
+            }
 
-                    let definition = ConstDefinition {
-                        raw_constant: constant.clone(),
-                    };
+            venial::ImplMember::AssocType(alias) => {
+                let span = util::span_of(alias);
+                let Some(attr) = parse_attributes_inner(&mut alias.attributes, span)? else {
+                    continue;
+                };
 
-                    constant_signatures.push(definition);
+                let ItemAttrType::Const(const_attr) = attr.ty else {
+                    return bail!(
+                        alias,
+                        "type aliases in #[godot_api] only support #[constant(enum)] and #[constant(bitfield)]"
+                    );
+                };
+
+                let is_bitfield = const_attr.is_bitfield();
+                match const_attr.group {
+                    Some((_, None)) => {}
+                    Some((key, Some(_))) => {
+                        return bail!(
+                            key,
+                            "#[constant({key})] on a type alias takes the enum name from the alias: `#[constant({key})] type Name = MyEnum;`"
+                        )
+                    }
+                    None => {
+                        return bail!(
+                            alias,
+                            "type aliases require #[constant(enum)] or #[constant(bitfield)]"
+                        )
+                    }
                 }
+
+                let Some(enum_ty) = alias.initializer_ty.clone() else {
+                    return bail!(
+                        alias,
+                        "#[constant] type alias must have a type: `type Name = MyEnum;`"
+                    );
+                };
+
+                constant_enums.push(ConstEnumDefinition {
+                    name: alias.name.clone(),
+                    enum_ty,
+                    is_bitfield,
+                });
+
+                // Inherent associated types are unstable; the alias only exists for registration.
+                removed_indexes.push(index);
             }
+
+            _ => {}
         }
     }
 
-    Ok(constant_signatures)
+    for index in removed_indexes.into_iter().rev() {
+        decl.body_items.remove(index);
+    }
+
+    Ok((constant_signatures, constant_enums))
 }
 
 /// Replaces the body of `function` with custom code that performs virtual dispatch.
@@ -677,11 +804,31 @@ fn parse_constant_attr(
     attributes: &[venial::Attribute],
     attr: &venial::Attribute,
 ) -> ParseResult<AttrParseResult> {
-    // Ensure no keys are present.
-    let parser = KvParser::parse(attributes, "constant")?.unwrap();
+    let mut parser = KvParser::parse(attributes, "constant")?.unwrap();
+
+    let enum_entry = parser.handle_any_entry("enum");
+    let bitfield_entry = parser.handle_any_entry("bitfield");
     parser.finish()?;
 
-    Ok(AttrParseResult::Constant(attr.value.clone()))
+    let entry = match (enum_entry, bitfield_entry) {
+        (None, None) => None,
+        (Some(entry), None) | (None, Some(entry)) => Some(entry),
+        (Some(_), Some(_)) => {
+            return bail!(
+                attr,
+                "#[constant]: keys `enum` and `bitfield` are mutually exclusive"
+            );
+        }
+    };
+
+    // `enum = Name` groups a constant; `enum` alone registers a type alias.
+    let group = match entry {
+        None => None,
+        Some((key, None)) => Some((key, None)),
+        Some((key, Some(value))) => Some((key, Some(value.ident()?))),
+    };
+
+    Ok(AttrParseResult::Constant(ConstAttr { group }))
 }
 
 /// Parses `#[opt(default = ...)]` parameter attributes and validates that optional parameters only appear at the end.
@@ -11,7 +11,7 @@ use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
 use crate::class::data_models::fields::Fields;
-use crate::class::data_models::group_export::FieldGroup;
+use crate::class::data_models::group_export::{FieldCategory, FieldGroup};
 use crate::class::{Field, FieldVar, GetSet, GetterSetterImpl, UsageFlags};
 use crate::util::{format_funcs_collection_constant, format_funcs_collection_struct, ident};
 
@@ -52,6 +52,7 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             ty: field_type,
             var,
             export,
+            category,
             group,
             subgroup,
             ..
@@ -61,7 +62,7 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
         let var = match (export, var) {
             (Some(export), None) => {
                 let usage_flags = if let Some(usage) = export.to_export_usage() {
-                    UsageFlags::Custom(vec![usage])
+                    UsageFlags::Custom(usage)
                 } else {
                     UsageFlags::InferredExport
                 };
@@ -75,6 +76,7 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             _ => continue,
         };
 
+        make_category_registration(category, &mut export_tokens, class_name);
         make_groups_registrations(group, subgroup, &mut export_tokens, class_name);
         let FieldVar {
             rename,
@@ -92,11 +94,18 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
 
         if let Some(export) = export {
             if usage_flags.is_inferred() {
-                usage_flags = UsageFlags::InferredExport;
+                usage_flags = match export.to_export_usage() {
+                    Some(usage) => UsageFlags::Custom(usage),
+                    None => UsageFlags::InferredExport,
+                };
             }
 
             export_hint = export.to_export_hint();
-            registration_fn = quote! { register_export };
+            registration_fn = if export.requires_export_trait() {
+                quote! { register_export }
+            } else {
+                quote! { register_var }
+            };
         } else {
             export_hint = None;
             registration_fn = quote! { register_var };
@@ -204,7 +213,9 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
     // This is the name of the container struct, which is declared by #[derive(GodotClass)].
     let class_functions_name = format_funcs_collection_struct(class_name);
 
+    // `#[deprecated]` on a #[var] or #[export] field documents it as deprecated in Godot; accessing it from the generated code is not a use.
     quote! {
+        #[allow(deprecated)]
         impl #class_name {
             #(#getter_setter_impls)*
             #phantom_var_dummy_use_fn
@@ -215,6 +226,7 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
         }
 
         impl ::godot::obj::cap::ImplementsGodotExports for #class_name {
+            #[allow(deprecated)]
             fn __register_exports() {
                 #(
                     {
@@ -249,6 +261,23 @@ fn make_getter_setter(
     quote! { #funcs_collection::#constant }
 }
 
+/// Generates the registration for a declared category and pushes it to export tokens.
+///
+/// Categories must be registered before groups, so that a group starting at the same field belongs to the new category.
+fn make_category_registration(
+    category: &Option<FieldCategory>,
+    export_tokens: &mut Vec<TokenStream>,
+    class_name: &Ident,
+) {
+    let Some(FieldCategory { name }) = category else {
+        return;
+    };
+
+    export_tokens.push(quote! {
+        ::godot::register::private::register_category::<#class_name>(#name);
+    });
+}
+
 /// Generates registrations for declared group and subgroup and pushes them to export tokens.
 ///
 /// Groups must be registered before subgroups (otherwise the ordering is broken).
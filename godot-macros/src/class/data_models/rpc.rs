@@ -6,9 +6,10 @@
  */
 
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 
 use crate::class::FuncDefinition;
+use crate::util;
 
 /// Possible ways the user can specify RPC configuration.
 pub enum RpcAttr {
@@ -161,3 +162,73 @@ fn make_rpc_registration(func_def: &FuncDefinition) -> Option<TokenStream> {
 
     Some(registration)
 }
+
+/// Generates the typed RPC API: a collection struct with one method per `#[rpc]`, accessible via `self.rpcs()` and `Gd::rpcs()`.
+pub fn make_rpc_accessors(class_name: &Ident, funcs: &[FuncDefinition]) -> TokenStream {
+    let accessor_methods = funcs
+        .iter()
+        .filter(|func_def| func_def.rpc_info.is_some())
+        .map(make_rpc_accessor_method)
+        .collect::<Vec<TokenStream>>();
+
+    // No `WithUserRpcs` impl without RPCs, for the same reasons as in `make_rpc_registrations_fn()`.
+    if accessor_methods.is_empty() {
+        return TokenStream::new();
+    }
+
+    let collection_struct_name = format_ident!("__godot_Rpcs_{}", class_name);
+
+    // Same visibility as the class, to avoid "leak private type" errors in the `WithUserRpcs` impl. See also signal collection.
+    let visibility_macro = util::format_class_visibility_macro(class_name);
+
+    quote! {
+        #visibility_macro! {
+            #[allow(non_camel_case_types)]
+            #[doc(hidden)] // Only on struct, not methods, to allow completion in IDEs.
+            struct #collection_struct_name<'c> {
+                #[doc(hidden)]
+                __target: ::godot::register::private::RpcTarget<'c>,
+            }
+        }
+
+        impl<'c> #collection_struct_name<'c> {
+            #( #accessor_methods )*
+        }
+
+        impl ::godot::obj::WithUserRpcs for #class_name {
+            type RpcCollection<'c> = #collection_struct_name<'c>;
+
+            fn rpcs(&mut self) -> Self::RpcCollection<'_> {
+                #collection_struct_name {
+                    __target: ::godot::register::private::RpcTarget::from_internal(self),
+                }
+            }
+
+            #[doc(hidden)]
+            fn __rpcs_from_external(external: &::godot::obj::Gd<Self>) -> Self::RpcCollection<'_> {
+                #collection_struct_name {
+                    __target: ::godot::register::private::RpcTarget::from_external(external),
+                }
+            }
+        }
+    }
+}
+
+fn make_rpc_accessor_method(func_def: &FuncDefinition) -> TokenStream {
+    let method_name = func_def.rust_ident();
+    let godot_name = func_def.godot_name();
+    let param_idents = &func_def.signature_info.param_idents;
+    let param_types = &func_def.signature_info.param_types;
+
+    quote! {
+        pub fn #method_name(self, #( #param_idents: impl ::godot::meta::AsArg<#param_types> ),*) -> ::godot::register::RpcCall<'c> {
+            use ::godot::meta::AsArg;
+            #(
+                ::godot::meta::arg_into_owned!(infer #param_idents);
+            )*
+
+            let args = vec![ #( ::godot::meta::ToGodot::to_variant(&#param_idents) ),* ];
+            self.__target.call(#godot_name, args)
+        }
+    }
+}
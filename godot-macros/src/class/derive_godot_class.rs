@@ -10,13 +10,15 @@ use quote::{format_ident, quote, quote_spanned};
 use venial::Error;
 
 use crate::class::data_models::fields::{named_fields, Fields};
-use crate::class::data_models::group_export::FieldGroup;
+use crate::class::data_models::group_export::{FieldCategory, FieldGroup};
+use crate::class::scene_nodes::{make_scene_nodes, SceneAttr};
 use crate::class::{
     make_property_impl, make_virtual_callback, BeforeKind, Field, FieldCond, FieldDefault,
-    FieldExport, FieldVar, GetterSetter, SignatureInfo,
+    FieldExport, FieldVar, GetterSetter, SignatureInfo, UsageFlags,
 };
 use crate::util::{
-    bail, error, format_funcs_collection_struct, ident, path_ends_with_complex, KvParser,
+    bail, error, format_funcs_collection_struct, ident, path_ends_with_complex,
+    require_api_version, KvParser,
 };
 use crate::{handle_mutually_exclusive_keys, util, ParseResult};
 
@@ -40,6 +42,22 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     let mut struct_cfg = parse_struct_attributes(class)?;
     let mut fields = parse_fields(named_fields, struct_cfg.init_strategy)?;
 
+    // Tool buttons invoke methods on the instance in the editor, which only exists for tool classes.
+    if !struct_cfg.is_tool {
+        let tool_button = fields
+            .all_fields
+            .iter()
+            .filter_map(|field| field.export.as_ref())
+            .find(|export| !export.requires_export_trait());
+
+        if let Some(export) = tool_button {
+            return bail!(
+                export.span,
+                "#[export_tool_button] requires the class to be declared with #[class(tool)]"
+            );
+        }
+    }
+
     if struct_cfg.is_editor_plugin() {
         modifiers.push(quote! { with_editor_plugin })
     }
@@ -160,6 +178,19 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     let base_field_macro = make_base_field_macro(class_name, fields.base_field.is_some());
     let deny_manual_init_macro = make_deny_manual_init_macro(class_name, struct_cfg.init_strategy);
 
+    let scene_nodes = match &struct_cfg.scene {
+        Some(scene) => {
+            if fields.base_field.is_none() {
+                return bail!(
+                    scene.span,
+                    "#[class(scene)] requires a `Base<T>` field, to access the class' own node"
+                );
+            }
+            make_scene_nodes(class_name, class.vis_marker.as_ref(), scene)?
+        }
+        None => TokenStream::new(),
+    };
+
     Ok(quote! {
         impl ::godot::obj::GodotClass for #class_name {
             type Base = #base_class;
@@ -192,6 +223,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         #visibility_macro
         #base_field_macro
         #deny_manual_init_macro
+        #scene_nodes
         #( #deprecations )*
         #( #errors )*
 
@@ -303,6 +335,7 @@ struct ClassAttributes {
     is_tool: bool,
     is_internal: bool,
     rename: Option<Ident>,
+    scene: Option<SceneAttr>,
     deprecations: Vec<TokenStream>,
 }
 
@@ -333,6 +366,8 @@ fn make_godot_init_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
 
     quote! {
         impl ::godot::obj::cap::GodotDefault for #class_name {
+            // Deprecated fields still need to be initialized.
+            #[allow(deprecated)]
             fn __godot_user_init(base: ::godot::obj::Base<<#class_name as ::godot::obj::GodotClass>::Base>) -> Self {
                 Self {
                     #( #rest_init )*
@@ -490,6 +525,7 @@ fn make_user_class_impl(
             }
 
             #[doc(hidden)]
+            #[allow(deprecated)] // OnReady and OnEditor fields may be deprecated.
             fn __before_ready(&mut self) {
                 #oneditor_panic_inits
                 #rpc_registrations
@@ -510,6 +546,7 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
     let mut is_tool = false;
     let mut is_internal = false;
     let mut rename: Option<Ident> = None;
+    let mut scene = None;
     let mut deprecations = vec![];
 
     // #[class] attribute on struct
@@ -542,6 +579,20 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
         // #[class(rename = NewName)]
         rename = parser.handle_ident("rename")?;
 
+        // #[class(scene = "res://path.tscn", scene_accessor = name)]
+        let scene_literal = parser.handle_literal("scene", "string")?;
+        let scene_accessor = parser.handle_ident("scene_accessor")?;
+        match (scene_literal, scene_accessor) {
+            (Some(literal), accessor) => scene = Some(SceneAttr::from_literal(&literal, accessor)?),
+            (None, Some(accessor)) => {
+                return bail!(
+                    accessor,
+                    "#[class(scene_accessor)] requires #[class(scene = \"...\")]"
+                );
+            }
+            (None, None) => {}
+        }
+
         // #[class(internal)]
         // Named "internal" following Godot terminology: https://github.com/godotengine/godot-cpp/blob/master/include/godot_cpp/core/class_db.hpp#L327
         if parser.handle_alone("internal")? {
@@ -583,6 +634,7 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
         is_tool,
         is_internal,
         rename,
+        scene,
         deprecations,
     })
 }
@@ -689,6 +741,13 @@ fn parse_fields(
             parser.finish()?;
         }
 
+        // #[export_category(name = ...)]
+        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export_category")? {
+            let category = FieldCategory::new_from_kv(&mut parser)?;
+            field.category = Some(category);
+            parser.finish()?;
+        }
+
         // #[export_group(name = ..., prefix = ...)]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export_group")? {
             let group = FieldGroup::new_from_kv(&mut parser)?;
@@ -713,6 +772,41 @@ fn parse_fields(
             parser.finish()?;
         }
 
+        // #[export_tool_button(label = ..., icon = ..., method = ...)]
+        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export_tool_button")? {
+            require_api_version!("4.4", parser.span(), "#[export_tool_button]")?;
+
+            if field.export.is_some() || field.var.is_some() {
+                return bail!(
+                    parser.span(),
+                    "#[export_tool_button] cannot be combined with #[export] or #[var]"
+                );
+            }
+
+            let (export, method) = FieldExport::new_tool_button(&mut parser)?;
+
+            let getter = match method {
+                Some(method) if field.is_phantomvar => GetterSetter::MethodCallable(method),
+                Some(method) => {
+                    return bail!(
+                        method,
+                        "#[export_tool_button(method = ...)] requires field type `PhantomVar<Callable>`"
+                    );
+                }
+                None => GetterSetter::Generated,
+            };
+
+            field.var = Some(FieldVar {
+                getter,
+                setter: GetterSetter::Omitted,
+                usage_flags: UsageFlags::Custom(export.to_export_usage().into_iter().collect()),
+                span: export.span,
+                ..Default::default()
+            });
+            field.export = Some(export);
+            parser.finish()?;
+        }
+
         // #[hint] to override type inference (must be at the end).
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "hint")? {
             if let Some(override_base) = handle_opposite_keys(&mut parser, "base", "hint")? {
@@ -814,7 +908,7 @@ fn validate_phantomvar_field(field: &Field, errors: &mut Vec<Error>) {
                 "PhantomVar<T> stores no data, so it cannot use an autogenerated getter"
             ));
         }
-        GetterSetter::Custom(_) => {}
+        GetterSetter::Custom(_) | GetterSetter::MethodCallable(_) => {}
     }
 
     // The setter may either be custom or omitted.
@@ -826,7 +920,7 @@ fn validate_phantomvar_field(field: &Field, errors: &mut Vec<Error>) {
                 "PhantomVar<T> stores no data, so it cannot use an autogenerated setter"
             ));
         }
-        GetterSetter::Custom(_) => {}
+        GetterSetter::Custom(_) | GetterSetter::MethodCallable(_) => {}
     }
 }
 
@@ -8,6 +8,7 @@
 mod derive_godot_class;
 mod godot_api;
 mod godot_dyn;
+mod scene_nodes;
 
 mod data_models {
     pub mod constant;
@@ -0,0 +1,763 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Typed node handles for `#[class(scene = "res://...")]`, generated from the text scene format (`.tscn`).
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::util::{bail, ident};
+use crate::ParseResult;
+
+/// Maximum nesting of inherited or instanced scenes that are followed to determine node types.
+const MAX_SCENE_DEPTH: usize = 8;
+
+/// Value of `#[class(scene = "...")]`, with the optional `scene_accessor = name`.
+pub struct SceneAttr {
+    pub path: String,
+    pub span: Span,
+
+    /// Name of the generated method returning the nodes; `nodes` by default.
+    pub accessor: Ident,
+}
+
+impl SceneAttr {
+    pub fn from_literal(literal: &Literal, accessor: Option<Ident>) -> ParseResult<Self> {
+        let repr = literal.to_string();
+        match repr.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+            Some(content) if !content.contains('\\') => Ok(Self {
+                path: content.to_string(),
+                span: literal.span(),
+                accessor: accessor.unwrap_or_else(|| ident("nodes")),
+            }),
+            _ => bail!(
+                literal,
+                "expected plain string literal, e.g. #[class(scene = \"res://player.tscn\")]"
+            ),
+        }
+    }
+}
+
+/// Generates the `<Class>Nodes` struct and the `nodes()` accessor (or the name given by `scene_accessor`) on the class.
+pub fn make_scene_nodes(
+    class_name: &proc_macro2::Ident,
+    vis_marker: Option<&venial::VisMarker>,
+    scene: &SceneAttr,
+) -> ParseResult<TokenStream> {
+    let mut loader = SceneLoader::default();
+    let nodes = loader
+        .resolve(&scene.path)
+        .and_then(|file| loader.load_nodes(&file, 0));
+
+    let nodes = match nodes {
+        Ok(nodes) => nodes,
+        Err(message) => return bail!(scene.span, "#[class(scene)]: {message}"),
+    };
+
+    let children = nodes
+        .into_iter()
+        .filter(|node| !node.path.is_empty())
+        .collect::<Vec<_>>();
+
+    let field_names = match assign_field_names(&children) {
+        Ok(names) => names,
+        Err(message) => return bail!(scene.span, "#[class(scene)]: {message}"),
+    };
+
+    let nodes_struct = format_ident!("{class_name}Nodes");
+    let module = format_ident!("__godot_scene_nodes_{class_name}");
+
+    let mut field_decls = Vec::new();
+    let mut field_inits = Vec::new();
+    for (node, field_name) in children.iter().zip(&field_names) {
+        let field = format_ident!("{field_name}");
+        let class = format_ident!("{}", to_rust_class_name(&node.class));
+        let path = &node.path;
+        let doc = format!("Node `{path}` of type `{}`.", node.class);
+
+        field_decls.push(quote! {
+            #[doc = #doc]
+            pub #field: ::godot::obj::Gd<#class>
+        });
+        field_inits.push(quote! {
+            #field: root.get_node_as::<#class>(#path)
+        });
+    }
+
+    let accessor = &scene.accessor;
+    let struct_doc = format!("Typed handles to the nodes of scene `{}`.", scene.path);
+    let nodes_fn_doc = format!(
+        "Looks up the nodes of scene `{}`, relative to this object.",
+        scene.path
+    );
+
+    // Tracks the scene files, so that changes to them cause recompilation.
+    let tracked_files = loader.files.iter().map(|path| path.display().to_string());
+
+    Ok(quote! {
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        mod #module {
+            // Node types are resolved in the user's scope first, which allows Rust classes; engine classes are imported as a fallback.
+            #[allow(unused_imports)]
+            use super::*;
+            #[allow(unused_imports)]
+            use ::godot::classes::*;
+
+            #[doc = #struct_doc]
+            pub struct #nodes_struct {
+                #( #field_decls, )*
+            }
+
+            impl #nodes_struct {
+                /// Looks up all nodes relative to `root`, which must be the root of an instance of the scene.
+                ///
+                /// # Panics
+                /// If a node does not exist, or has a different type than in the scene file.
+                pub fn from_root(root: &::godot::classes::Node) -> Self {
+                    Self {
+                        #( #field_inits, )*
+                    }
+                }
+            }
+
+            #( const _: &[u8] = include_bytes!(#tracked_files); )*
+        }
+
+        #vis_marker use #module::#nodes_struct;
+
+        impl #class_name {
+            #[doc = #nodes_fn_doc]
+            ///
+            /// # Panics
+            /// If a node does not exist, or has a different type than in the scene file.
+            #[allow(dead_code)]
+            #vis_marker fn #accessor(&self) -> #nodes_struct {
+                let root = <Self as ::godot::obj::WithBaseField>::to_gd(self)
+                    .upcast::<::godot::classes::Node>();
+
+                #nodes_struct::from_root(&root)
+            }
+        }
+    })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Scene files
+
+/// Node declared in a scene, with its path relative to the scene root (empty for the root itself).
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct SceneNode {
+    path: String,
+    class: String,
+}
+
+#[derive(Default)]
+struct SceneLoader {
+    project_dir: Option<PathBuf>,
+    files: Vec<PathBuf>,
+}
+
+impl SceneLoader {
+    /// Resolves `res://` paths against the Godot project directory, and other paths against the crate's manifest directory.
+    fn resolve(&mut self, path: &str) -> Result<PathBuf, String> {
+        let resolved = match path.strip_prefix("res://") {
+            Some(relative) => self.project_dir()?.join(relative),
+            None => manifest_dir()?.join(path),
+        };
+
+        if resolved.is_file() {
+            Ok(resolved)
+        } else {
+            Err(format!(
+                "scene file `{path}` not found at {}",
+                resolved.display()
+            ))
+        }
+    }
+
+    fn project_dir(&mut self) -> Result<PathBuf, String> {
+        if let Some(dir) = &self.project_dir {
+            return Ok(dir.clone());
+        }
+
+        let dir = find_project_dir(&manifest_dir()?)?;
+        self.project_dir = Some(dir.clone());
+        Ok(dir)
+    }
+
+    fn load_nodes(&mut self, file: &Path, depth: usize) -> Result<Vec<SceneNode>, String> {
+        let content = std::fs::read_to_string(file)
+            .map_err(|e| format!("cannot read {}: {e}", file.display()))?;
+
+        self.files.push(file.to_path_buf());
+
+        parse_tscn(&content, |scene_path| {
+            if depth >= MAX_SCENE_DEPTH {
+                return Vec::new();
+            }
+
+            // Scenes that cannot be resolved (e.g. from addons outside the project) fall back to `Node`.
+            self.resolve(scene_path)
+                .and_then(|file| self.load_nodes(&file, depth + 1))
+                .unwrap_or_default()
+        })
+        .map_err(|e| format!("{}: {e}", file.display()))
+    }
+}
+
+fn manifest_dir() -> Result<PathBuf, String> {
+    std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .ok_or_else(|| "CARGO_MANIFEST_DIR is not set".to_string())
+}
+
+/// Finds the directory containing `project.godot`.
+///
+/// Uses the `GODOT_PROJECT_DIR` environment variable (relative to the manifest directory) if set. Otherwise, searches the manifest
+/// directory and its ancestors, including a `godot` subdirectory of each. The search ends at the repository or workspace root (the first
+/// directory with `.git` or `Cargo.lock`), so that unrelated projects further up the file system are not picked up.
+fn find_project_dir(manifest_dir: &Path) -> Result<PathBuf, String> {
+    if let Some(dir) = std::env::var_os("GODOT_PROJECT_DIR") {
+        return Ok(manifest_dir.join(dir));
+    }
+
+    for dir in manifest_dir.ancestors() {
+        for candidate in [dir.to_path_buf(), dir.join("godot")] {
+            if candidate.join("project.godot").is_file() {
+                return Ok(candidate);
+            }
+        }
+
+        if dir.join(".git").exists() || dir.join("Cargo.lock").is_file() {
+            break;
+        }
+    }
+
+    Err(format!(
+        "cannot find project.godot in {} or its parents up to the workspace root; set GODOT_PROJECT_DIR to the Godot project directory",
+        manifest_dir.display()
+    ))
+}
+
+/// Parses the nodes of a text scene.
+///
+/// `load_scene` is invoked with the `res://` path of inherited or instanced scenes and returns their nodes; it is used to determine the
+/// types of nodes that don't declare one.
+fn parse_tscn(
+    content: &str,
+    mut load_scene: impl FnMut(&str) -> Vec<SceneNode>,
+) -> Result<Vec<SceneNode>, String> {
+    let mut ext_scenes = HashMap::new();
+    let mut nodes: Vec<SceneNode> = Vec::new();
+    let mut in_multiline_string = false;
+
+    for (line_index, line) in content.lines().enumerate() {
+        // Property values may contain lines starting with `[` inside multi-line strings.
+        if in_multiline_string || !line.starts_with('[') {
+            if count_unescaped_quotes(line) % 2 == 1 {
+                in_multiline_string = !in_multiline_string;
+            }
+            continue;
+        }
+
+        let (tag, attributes) =
+            parse_section_header(line).map_err(|e| format!("line {}: {e}", line_index + 1))?;
+
+        match tag.as_str() {
+            "ext_resource" => {
+                let is_scene = attributes.get("type").map(String::as_str) == Some("PackedScene");
+                if let (true, Some(id), Some(path)) =
+                    (is_scene, attributes.get("id"), attributes.get("path"))
+                {
+                    ext_scenes.insert(id.clone(), path.clone());
+                }
+            }
+            "node" => {
+                let name = attributes
+                    .get("name")
+                    .ok_or_else(|| format!("line {}: node without name", line_index + 1))?;
+
+                let path = match attributes.get("parent").map(String::as_str) {
+                    None => String::new(),
+                    Some(".") => name.clone(),
+                    Some(parent) => format!("{parent}/{name}"),
+                };
+
+                let instanced_scene = attributes
+                    .get("instance")
+                    .and_then(|value| ext_resource_id(value))
+                    .and_then(|id| ext_scenes.get(id));
+
+                let class = if let Some(class) = attributes.get("type") {
+                    class.clone()
+                } else if let Some(scene_path) = instanced_scene {
+                    let scene_nodes = load_scene(scene_path);
+
+                    // Root of an inherited scene: nodes of the base scene exist, too.
+                    if path.is_empty() {
+                        nodes.extend(scene_nodes.iter().filter(|n| !n.path.is_empty()).cloned());
+                    }
+
+                    scene_nodes
+                        .into_iter()
+                        .find(|n| n.path.is_empty())
+                        .map(|n| n.class)
+                        .unwrap_or_else(|| "Node".to_string())
+                } else if let Some(existing) = nodes.iter().find(|n| n.path == path) {
+                    // Node of an inherited scene, with overridden properties.
+                    existing.class.clone()
+                } else {
+                    "Node".to_string()
+                };
+
+                match nodes.iter_mut().find(|n| n.path == path) {
+                    Some(existing) => existing.class = class,
+                    None => nodes.push(SceneNode { path, class }),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if !nodes.iter().any(|n| n.path.is_empty()) {
+        return Err("scene has no root node".to_string());
+    }
+
+    Ok(nodes)
+}
+
+/// Parses `[tag key=value key2="value 2"]` into the tag and its attributes. Quoted values are unescaped.
+fn parse_section_header(line: &str) -> Result<(String, HashMap<String, String>), String> {
+    let inner = line
+        .trim_end()
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .ok_or_else(|| "malformed section header".to_string())?;
+
+    let tag_len = inner.find(char::is_whitespace).unwrap_or(inner.len());
+    let tag = inner[..tag_len].to_string();
+
+    let mut attributes = HashMap::new();
+    let mut rest = inner[tag_len..].trim_start();
+
+    while !rest.is_empty() {
+        let eq = rest
+            .find('=')
+            .ok_or_else(|| format!("expected `key=value` in section `{tag}`"))?;
+        let key = rest[..eq].trim().to_string();
+        rest = &rest[eq + 1..];
+
+        let (value, remaining) = if let Some(quoted) = rest.strip_prefix('"') {
+            parse_quoted(quoted)?
+        } else {
+            let len = value_len(rest);
+            (rest[..len].to_string(), &rest[len..])
+        };
+
+        attributes.insert(key, value);
+        rest = remaining.trim_start();
+    }
+
+    Ok((tag, attributes))
+}
+
+/// Parses the rest of a quoted string (after the opening quote), returning the unescaped content and the remaining input.
+fn parse_quoted(input: &str) -> Result<(String, &str), String> {
+    let mut value = String::new();
+    let mut chars = input.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok((value, &input[i + 1..])),
+            '\\' => match chars.next() {
+                Some((_, 'n')) => value.push('\n'),
+                Some((_, 't')) => value.push('\t'),
+                Some((_, escaped)) => value.push(escaped),
+                None => break,
+            },
+            c => value.push(c),
+        }
+    }
+
+    Err("unterminated string".to_string())
+}
+
+/// Length of an unquoted value such as `ExtResource("1_abc")` or `["group"]`, up to the next whitespace outside of brackets and strings.
+fn value_len(input: &str) -> usize {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in input.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            c if c.is_whitespace() && depth == 0 => return i,
+            _ => {}
+        }
+    }
+
+    input.len()
+}
+
+/// Extracts the ID from `ExtResource("1_abc")` (format 3) or `ExtResource(1)` (format 2).
+fn ext_resource_id(value: &str) -> Option<&str> {
+    let id = value
+        .strip_prefix("ExtResource(")?
+        .strip_suffix(')')?
+        .trim();
+    Some(id.trim_matches('"'))
+}
+
+fn count_unescaped_quotes(line: &str) -> usize {
+    let mut count = 0;
+    let mut escaped = false;
+
+    for c in line.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => count += 1,
+            _ => {}
+        }
+    }
+
+    count
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Class names
+
+/// Maps a Godot class name to its Rust name, following the conversion of generated engine classes (`HTTPRequest` -> `HttpRequest`,
+/// `GPUParticles2D` -> `GpuParticles2D`). Names that are already in Rust style, such as most user classes, are returned unchanged.
+fn to_rust_class_name(godot_name: &str) -> String {
+    // Keep in sync with godot-codegen's `to_pascal_case()`.
+    match godot_name {
+        "JSONRPC" => return "JsonRpc".to_string(),
+        "OpenXRAPIExtension" => return "OpenXrApiExtension".to_string(),
+        "OpenXRIPBinding" => return "OpenXrIpBinding".to_string(),
+        _ => {}
+    }
+
+    let mut result = String::new();
+    for word in split_words(godot_name) {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            result.extend(first.to_uppercase());
+            result.extend(chars.flat_map(char::to_lowercase));
+        }
+    }
+
+    result
+        .replace("GdExtension", "GDExtension")
+        .replace("GdNative", "GDNative")
+        .replace("GdScript", "GDScript")
+        .replace("Vsync", "VSync")
+        .replace("Sdfgiy", "SdfgiY")
+}
+
+/// Splits an identifier into words, with the same boundaries as the `heck` crate used by codegen.
+///
+/// A word ends before an uppercase letter that follows a lowercase one (digits continue the preceding word), and before the last uppercase
+/// letter of an acronym that is followed by a lowercase one (`HTTPRequest` -> `HTTP`, `Request`).
+fn split_words(name: &str) -> Vec<&str> {
+    #[derive(Copy, Clone, Eq, PartialEq)]
+    enum Mode {
+        Boundary,
+        Lowercase,
+        Uppercase,
+    }
+
+    let mut words = Vec::new();
+    for part in name.split(|c: char| !c.is_alphanumeric()) {
+        let mut chars = part.char_indices().peekable();
+        let mut start = 0;
+        let mut mode = Mode::Boundary;
+
+        while let Some((i, c)) = chars.next() {
+            let Some(&(next_i, next)) = chars.peek() else {
+                words.push(&part[start..]);
+                break;
+            };
+
+            let next_mode = if c.is_lowercase() {
+                Mode::Lowercase
+            } else if c.is_uppercase() {
+                Mode::Uppercase
+            } else {
+                mode
+            };
+
+            if next_mode == Mode::Lowercase && next.is_uppercase() {
+                words.push(&part[start..next_i]);
+                start = next_i;
+                mode = Mode::Boundary;
+            } else if mode == Mode::Uppercase && c.is_uppercase() && next.is_lowercase() {
+                words.push(&part[start..i]);
+                start = i;
+                mode = Mode::Boundary;
+            } else {
+                mode = next_mode;
+            }
+        }
+    }
+
+    words
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Field names
+
+/// Assigns a unique snake_case field name to each node.
+///
+/// Nodes are named after their node name (`HealthBar` -> `health_bar`). If several nodes share a name, all of them are named after their
+/// full path instead (`Body/Sprite` -> `body_sprite`).
+fn assign_field_names(nodes: &[SceneNode]) -> Result<Vec<String>, String> {
+    let short_names = nodes
+        .iter()
+        .map(|node| {
+            let name = node.path.rsplit('/').next().unwrap_or(&node.path);
+            to_field_name(name)
+        })
+        .collect::<Vec<_>>();
+
+    let names = nodes
+        .iter()
+        .zip(&short_names)
+        .map(|(node, short)| {
+            if short_names.iter().filter(|other| *other == short).count() > 1 {
+                to_field_name(&node.path)
+            } else {
+                short.clone()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    for (i, name) in names.iter().enumerate() {
+        if let Some(j) = names[..i].iter().position(|other| other == name) {
+            return Err(format!(
+                "nodes `{}` and `{}` both map to field `{name}`; rename one of them in the scene",
+                nodes[j].path, nodes[i].path
+            ));
+        }
+    }
+
+    Ok(names)
+}
+
+/// Converts a node name or path to a snake_case identifier, e.g. `HTTPRequest` -> `http_request`, `Area2D` -> `area_2d`.
+fn to_field_name(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut result = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            result.push('_');
+            continue;
+        }
+
+        if let Some(&prev) = i.checked_sub(1).map(|p| &chars[p]) {
+            let next = chars.get(i + 1).copied();
+            let word_start = (c.is_uppercase()
+                && (prev.is_lowercase()
+                    || (prev.is_uppercase() && next.is_some_and(char::is_lowercase))))
+                || (c.is_ascii_digit() && prev.is_alphabetic());
+
+            if word_start {
+                result.push('_');
+            }
+        }
+
+        result.extend(c.to_lowercase());
+    }
+
+    // Collapse repeated underscores and trim them at both ends.
+    let mut name = result
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_");
+
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert_str(0, "node_");
+    }
+
+    if is_keyword(&name) {
+        name.push('_');
+    }
+
+    name
+}
+
+fn is_keyword(name: &str) -> bool {
+    matches!(
+        name,
+        "as" | "async"
+            | "await"
+            | "break"
+            | "const"
+            | "continue"
+            | "crate"
+            | "dyn"
+            | "else"
+            | "enum"
+            | "extern"
+            | "false"
+            | "fn"
+            | "for"
+            | "gen"
+            | "if"
+            | "impl"
+            | "in"
+            | "let"
+            | "loop"
+            | "match"
+            | "mod"
+            | "move"
+            | "mut"
+            | "pub"
+            | "ref"
+            | "return"
+            | "self"
+            | "static"
+            | "struct"
+            | "super"
+            | "trait"
+            | "true"
+            | "type"
+            | "unsafe"
+            | "use"
+            | "where"
+            | "while"
+            | "yield"
+    )
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(path: &str, class: &str) -> SceneNode {
+        SceneNode {
+            path: path.to_string(),
+            class: class.to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_scene_nodes() {
+        let base = r#"[gd_scene format=3]
+
+[node name="Base" type="CharacterBody2D"]
+
+[node name="Sprite" type="Sprite2D" parent="."]
+"#;
+
+        let scene = r#"[gd_scene load_steps=3 format=3 uid="uid://abc"]
+
+[ext_resource type="PackedScene" path="res://base.tscn" id="1_base"]
+[ext_resource type="Texture2D" path="res://icon.svg" id="2_icon"]
+
+[node name="Player" instance=ExtResource("1_base")]
+
+[node name="Sprite" parent="."]
+texture = ExtResource("2_icon")
+
+[node name="Label" type="Label" parent="Sprite" groups=["ui", "hud"]]
+text = "first line
+[b]not a section[/b] \"quoted\""
+
+[node name="Weird \"Name\"" type="Node" parent="."]
+
+[connection signal="ready" from="." to="." method="on_ready"]
+"#;
+
+        let nodes = parse_tscn(scene, |path| {
+            assert_eq!(path, "res://base.tscn");
+            parse_tscn(base, |_| Vec::new()).unwrap()
+        })
+        .unwrap();
+
+        assert_eq!(
+            nodes,
+            [
+                node("Sprite", "Sprite2D"),
+                node("", "CharacterBody2D"),
+                node("Sprite/Label", "Label"),
+                node("Weird \"Name\"", "Node"),
+            ]
+        );
+    }
+
+    #[test]
+    fn class_names() {
+        // Engine classes, see godot-codegen's `test_pascal_conversion`.
+        assert_eq!(to_rust_class_name("HTTPRequest"), "HttpRequest");
+        assert_eq!(to_rust_class_name("GPUParticles2D"), "GpuParticles2D");
+        assert_eq!(to_rust_class_name("CSGBox3D"), "CsgBox3D");
+        assert_eq!(to_rust_class_name("XROrigin3D"), "XrOrigin3D");
+        assert_eq!(to_rust_class_name("AStar3D"), "AStar3D");
+        assert_eq!(to_rust_class_name("AudioEffectEQ21"), "AudioEffectEq21");
+        assert_eq!(to_rust_class_name("X509Certificate"), "X509Certificate");
+        assert_eq!(to_rust_class_name("HSeparator"), "HSeparator");
+        assert_eq!(to_rust_class_name("JSONRPC"), "JsonRpc");
+        assert_eq!(to_rust_class_name("OpenXRIPBinding"), "OpenXrIpBinding");
+        assert_eq!(to_rust_class_name("GDScript"), "GDScript");
+        assert_eq!(
+            to_rust_class_name("GDExtensionManager"),
+            "GDExtensionManager"
+        );
+
+        // Names already in Rust style.
+        assert_eq!(to_rust_class_name("Node2D"), "Node2D");
+        assert_eq!(to_rust_class_name("CharacterBody2D"), "CharacterBody2D");
+        assert_eq!(to_rust_class_name("SceneNodesOwner"), "SceneNodesOwner");
+    }
+
+    #[test]
+    fn field_names() {
+        assert_eq!(to_field_name("Sprite"), "sprite");
+        assert_eq!(to_field_name("HealthBar"), "health_bar");
+        assert_eq!(to_field_name("HTTPRequest"), "http_request");
+        assert_eq!(to_field_name("Area2D"), "area_2d");
+        assert_eq!(to_field_name("my node-1"), "my_node_1");
+        assert_eq!(to_field_name("2nd"), "node_2nd");
+        assert_eq!(to_field_name("Type"), "type_");
+
+        let names = assign_field_names(&[
+            node("Body/Sprite", "Sprite2D"),
+            node("Sprite", "Sprite2D"),
+            node("Body", "Node2D"),
+        ])
+        .unwrap();
+        assert_eq!(names, ["body_sprite", "sprite", "body"]);
+
+        let err = assign_field_names(&[
+            node("BodySprite", "Node"),
+            node("Body/Sprite", "Node"),
+            node("Sprite", "Node"),
+        ]);
+        assert!(err.is_err());
+    }
+}
@@ -26,6 +26,9 @@ pub struct CStyleEnum {
     /// - `(1 + 2)`
     /// - `Enum::Variant as isize`
     enumerator_ords: Vec<TokenStream>,
+
+    /// The attributes of each enumerator, including doc comments.
+    enumerator_attributes: Vec<Vec<venial::Attribute>>,
 }
 
 impl CStyleEnum {
@@ -39,11 +42,13 @@ impl CStyleEnum {
             .map(CStyleEnumerator::parse_enum_variant)
             .collect::<ParseResult<Vec<_>>>()?;
 
+        let attributes = variants.iter().map(|v| v.attributes.clone()).collect();
         let (names, ord_exprs) = Self::create_discriminant_mapping(variants)?;
 
         Ok(Self {
             enumerator_names: names,
             enumerator_ords: ord_exprs,
+            enumerator_attributes: attributes,
         })
     }
 
@@ -89,6 +94,11 @@ impl CStyleEnum {
         &self.enumerator_ords
     }
 
+    /// Returns the attributes of each enumerator, in order of declaration.
+    pub fn enumerator_attributes(&self) -> &[Vec<venial::Attribute>] {
+        &self.enumerator_attributes
+    }
+
     /// Return a hint string for use with `PropertyHint::ENUM` where each variant has an explicit integer hint.
     pub fn to_int_hint(&self) -> TokenStream {
         // We can't build the format string directly, since the ords may be expressions and not literals.
@@ -140,6 +150,8 @@ pub struct CStyleEnumerator {
     name: Ident,
     /// The explicit discriminant of the variant, `None` means there was no explicit discriminant.
     discriminant: Option<TokenTree>,
+    /// The attributes of the variant, e.g. doc comments.
+    attributes: Vec<venial::Attribute>,
 }
 
 impl CStyleEnumerator {
@@ -158,6 +170,7 @@ impl CStyleEnumerator {
         Ok(Self {
             name: enum_variant.name.clone(),
             discriminant: enum_variant.value.as_ref().map(|val| &val.value).cloned(),
+            attributes: enum_variant.attributes.clone(),
         })
     }
 
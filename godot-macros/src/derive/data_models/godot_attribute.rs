@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::ToTokens;
 
 use crate::util::{bail, KvParser};
@@ -17,6 +17,8 @@ pub enum GodotAttribute {
     Transparent { span: Span },
     /// `#[godot(via = via_type)]`
     Via { span: Span, via_type: ViaType },
+    /// `#[godot(via = Dictionary)]`, optionally with `tag = "key"` for enums.
+    ViaDictionary { span: Span, tag: Option<Literal> },
 }
 
 impl GodotAttribute {
@@ -36,6 +38,11 @@ impl GodotAttribute {
         }
 
         if let Some(via_type) = parser.handle_ident("via")? {
+            if via_type == "Dictionary" {
+                let tag = parser.handle_literal("tag", "String")?;
+                return Ok(Self::ViaDictionary { span, tag });
+            }
+
             return Ok(Self::Via {
                 span,
                 via_type: ViaType::parse_ident(via_type)?,
@@ -55,6 +62,7 @@ impl GodotAttribute {
         match self {
             GodotAttribute::Transparent { span } => *span,
             GodotAttribute::Via { span, .. } => *span,
+            GodotAttribute::ViaDictionary { span, .. } => *span,
         }
     }
 }
@@ -72,7 +80,7 @@ impl ViaType {
         let via_type = match ident.to_string().as_str() {
             "GString" => ViaType::GString { gstring_ident: ident },
             "i8" |"i16" | "i32" | "i64" | "u8" | "u16" | "u32" => ViaType::Int { int_ident: ident },
-            other => return bail!(ident, "Via type `{other}` is not supported, expected one of: GString, i8, i16, i32, i64, u8, u16, u32")
+            other => return bail!(ident, "Via type `{other}` is not supported, expected one of: GString, Dictionary, i8, i16, i32, i64, u8, u16, u32")
         };
 
         Ok(via_type)
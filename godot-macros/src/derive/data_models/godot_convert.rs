@@ -6,11 +6,12 @@
  */
 
 use proc_macro2::{Ident, TokenStream};
-use quote::ToTokens;
+use quote::{quote, ToTokens};
 
 use super::c_style_enum::CStyleEnum;
 use super::godot_attribute::{GodotAttribute, ViaType};
 use super::newtype::NewtypeStruct;
+use super::via_dictionary::{DictEnum, DictStruct};
 use crate::util::bail;
 use crate::ParseResult;
 
@@ -74,6 +75,10 @@ pub enum ConvertType {
     NewType { field: NewtypeStruct },
     /// Deriving for an enum.
     Enum { variants: CStyleEnum, via: ViaType },
+    /// Deriving for a struct with named fields, converted via `Dictionary`.
+    DictStruct { struct_: DictStruct },
+    /// Deriving for an enum with payloads, converted via tagged `Dictionary`.
+    DictEnum { enum_: DictEnum },
 }
 
 impl ConvertType {
@@ -81,28 +86,34 @@ impl ConvertType {
         let attribute = GodotAttribute::parse_attribute(&item)?;
 
         match &item {
-            venial::Item::Struct(struct_) => {
-                let GodotAttribute::Transparent { .. } = attribute else {
-                    return bail!(attribute.span(), "#[derive(GodotConvert)] on structs currently only works with #[godot(transparent)]");
-                };
-
-                Ok(Self::NewType {
+            venial::Item::Struct(struct_) => match attribute {
+                GodotAttribute::Transparent { .. } => Ok(Self::NewType {
                     field: NewtypeStruct::parse_struct(struct_)?,
-                })
-            }
-            venial::Item::Enum(enum_) => {
-                let GodotAttribute::Via { via_type, .. } = attribute else {
-                    return bail!(
-                        attribute.span(),
-                        "#[derive(GodotConvert)] on enums requires #[godot(via = ...)]"
-                    );
-                };
-
-                Ok(Self::Enum {
+                }),
+                GodotAttribute::ViaDictionary { tag: None, .. } => Ok(Self::DictStruct {
+                    struct_: DictStruct::parse_struct(struct_)?,
+                }),
+                GodotAttribute::ViaDictionary { tag: Some(tag), .. } => {
+                    bail!(tag, "`tag` is only supported for enums")
+                }
+                _ => bail!(
+                    attribute.span(),
+                    "#[derive(GodotConvert)] on structs requires #[godot(transparent)] or #[godot(via = Dictionary)]"
+                ),
+            },
+            venial::Item::Enum(enum_) => match attribute {
+                GodotAttribute::Via { via_type, .. } => Ok(Self::Enum {
                     variants: CStyleEnum::parse_enum(enum_)?,
                     via: via_type,
-                })
-            }
+                }),
+                GodotAttribute::ViaDictionary { tag, .. } => Ok(Self::DictEnum {
+                    enum_: DictEnum::parse_enum(enum_, tag)?,
+                }),
+                _ => bail!(
+                    attribute.span(),
+                    "#[derive(GodotConvert)] on enums requires #[godot(via = ...)]"
+                ),
+            },
             _ => unreachable!(), // already checked outside.
         }
     }
@@ -112,6 +123,9 @@ impl ConvertType {
         match self {
             ConvertType::NewType { field } => field.ty.to_token_stream(),
             ConvertType::Enum { via, .. } => via.to_token_stream(),
+            ConvertType::DictStruct { .. } | ConvertType::DictEnum { .. } => {
+                quote! { ::godot::builtin::Dictionary }
+            }
         }
     }
 }
@@ -9,8 +9,10 @@ mod c_style_enum;
 mod godot_attribute;
 mod godot_convert;
 mod newtype;
+mod via_dictionary;
 
 pub use c_style_enum::*;
 pub use godot_attribute::*;
 pub use godot_convert::*;
 pub use newtype::*;
+pub use via_dictionary::*;
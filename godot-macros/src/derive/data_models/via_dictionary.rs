@@ -0,0 +1,247 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::quote;
+
+use crate::util::{bail, ident, KvParser};
+use crate::ParseResult;
+
+/// Dictionary key under which the enumerator name is stored, unless overridden with `#[godot(via = Dictionary, tag = "...")]`.
+const DEFAULT_ENUM_TAG: &str = "type";
+
+/// Stores info from a struct with named fields, for `#[godot(via = Dictionary)]`.
+pub struct DictStruct {
+    pub fields: Vec<DictField>,
+}
+
+impl DictStruct {
+    /// Parses a struct with named fields. Tuple and unit structs are rejected.
+    pub fn parse_struct(struct_: &venial::Struct) -> ParseResult<Self> {
+        let venial::Fields::Named(named_fields) = &struct_.fields else {
+            return bail!(
+                &struct_.fields,
+                "#[godot(via = Dictionary)] requires a struct with named fields"
+            );
+        };
+
+        let fields = named_fields
+            .fields
+            .items()
+            .map(DictField::parse_named_field)
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        ensure_unique_keys(&fields, None)?;
+
+        Ok(Self { fields })
+    }
+}
+
+/// Stores info from an enum whose enumerators may carry payloads, for `#[godot(via = Dictionary)]`.
+///
+/// Each value is encoded as a dictionary, with the enumerator name stored under the tag key and the payload fields stored alongside.
+pub struct DictEnum {
+    /// Dictionary key holding the enumerator name, e.g. `"type"`.
+    pub tag_key: String,
+    pub enumerators: Vec<DictEnumerator>,
+}
+
+impl DictEnum {
+    pub fn parse_enum(enum_: &venial::Enum, tag: Option<Literal>) -> ParseResult<Self> {
+        let tag_key = match tag {
+            Some(literal) => parse_string_literal(&literal)?,
+            None => DEFAULT_ENUM_TAG.to_string(),
+        };
+
+        let enumerators = enum_
+            .variants
+            .items()
+            .map(|variant| DictEnumerator::parse_enum_variant(variant, &tag_key))
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        let mut tag_values = enumerators.iter().map(|e| &e.tag_value).collect::<Vec<_>>();
+        tag_values.sort();
+        if let Some(duplicate) = tag_values.windows(2).find(|w| w[0] == w[1]) {
+            return bail!(
+                &enum_.name,
+                "multiple enumerators map to the same dictionary tag \"{}\"",
+                duplicate[0]
+            );
+        }
+
+        Ok(Self {
+            tag_key,
+            enumerators,
+        })
+    }
+}
+
+/// Single enumerator of a [`DictEnum`].
+pub struct DictEnumerator {
+    pub name: Ident,
+
+    /// Value stored under the enum's tag key; the enumerator name unless renamed.
+    pub tag_value: String,
+
+    pub shape: EnumeratorShape,
+    pub fields: Vec<DictField>,
+}
+
+/// How the payload of an enumerator is declared in Rust.
+pub enum EnumeratorShape {
+    /// `Variant`
+    Unit,
+    /// `Variant(A, B)` -- fields are stored under keys `"0"`, `"1"`, ...
+    Tuple,
+    /// `Variant { a: A, b: B }`
+    Named,
+}
+
+impl DictEnumerator {
+    fn parse_enum_variant(variant: &venial::EnumVariant, tag_key: &str) -> ParseResult<Self> {
+        let name = variant.name.clone();
+
+        let mut tag_value = name.to_string();
+        if let Some(mut parser) = KvParser::parse(&variant.attributes, "godot")? {
+            if let Some(rename) = parser.handle_literal("rename", "String")? {
+                tag_value = parse_string_literal(&rename)?;
+            }
+            parser.finish()?;
+        }
+
+        let (shape, fields) = match &variant.fields {
+            venial::Fields::Unit => (EnumeratorShape::Unit, vec![]),
+            venial::Fields::Tuple(tuple_fields) => {
+                let fields = tuple_fields
+                    .fields
+                    .items()
+                    .enumerate()
+                    .map(|(i, field)| {
+                        let binding = ident(&format!("__field{i}"));
+                        DictField::parse(
+                            binding,
+                            &field.attributes,
+                            field.ty.clone(),
+                            i.to_string(),
+                        )
+                    })
+                    .collect::<ParseResult<Vec<_>>>()?;
+
+                (EnumeratorShape::Tuple, fields)
+            }
+            venial::Fields::Named(named_fields) => {
+                let fields = named_fields
+                    .fields
+                    .items()
+                    .map(DictField::parse_named_field)
+                    .collect::<ParseResult<Vec<_>>>()?;
+
+                (EnumeratorShape::Named, fields)
+            }
+        };
+
+        ensure_unique_keys(&fields, Some(tag_key))?;
+
+        Ok(Self {
+            name,
+            tag_value,
+            shape,
+            fields,
+        })
+    }
+}
+
+/// Field of a [`DictStruct`] or [`DictEnumerator`], stored as one dictionary entry.
+pub struct DictField {
+    /// Rust field name, or generated binding name for tuple fields.
+    pub binding: Ident,
+
+    /// Type of the field, must implement `ToGodot` + `FromGodot`.
+    pub ty: venial::TypeExpr,
+
+    /// Key in the dictionary (field name, unless renamed).
+    pub key: String,
+
+    /// Expression used if the key is absent; `None` means the key is required.
+    pub default: Option<TokenStream>,
+
+    /// Span for error messages.
+    pub span: Span,
+}
+
+impl DictField {
+    fn parse_named_field(field: &venial::NamedField) -> ParseResult<Self> {
+        let name = field.name.clone();
+        let key = name.to_string().trim_start_matches("r#").to_string();
+
+        Self::parse(name, &field.attributes, field.ty.clone(), key)
+    }
+
+    fn parse(
+        binding: Ident,
+        attributes: &[venial::Attribute],
+        ty: venial::TypeExpr,
+        mut key: String,
+    ) -> ParseResult<Self> {
+        let span = binding.span();
+        let mut default = None;
+
+        if let Some(mut parser) = KvParser::parse(attributes, "godot")? {
+            if let Some(rename) = parser.handle_literal("rename", "String")? {
+                key = parse_string_literal(&rename)?;
+            }
+
+            default = match parser.handle_any("default") {
+                None => None,
+                Some(None) => Some(quote! { ::std::default::Default::default() }),
+                Some(Some(value)) => Some(value.expr()?),
+            };
+
+            parser.finish()?;
+        }
+
+        Ok(Self {
+            binding,
+            ty,
+            key,
+            default,
+            span,
+        })
+    }
+}
+
+/// Extracts the content of a string literal such as `"name"`.
+fn parse_string_literal(literal: &Literal) -> ParseResult<String> {
+    let repr = literal.to_string();
+    match repr.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        Some(content) if !content.contains('\\') => Ok(content.to_string()),
+        _ => bail!(literal, "expected plain string literal, e.g. \"name\""),
+    }
+}
+
+/// Makes sure that no two fields (or a field and the enum tag) are stored under the same dictionary key.
+fn ensure_unique_keys(fields: &[DictField], tag_key: Option<&str>) -> ParseResult<()> {
+    for (i, field) in fields.iter().enumerate() {
+        if tag_key == Some(field.key.as_str()) {
+            return bail!(
+                field.span,
+                "field key \"{}\" collides with the enum tag key; use #[godot(rename = \"...\")] or a different `tag`",
+                field.key
+            );
+        }
+
+        if fields[..i].iter().any(|other| other.key == field.key) {
+            return bail!(
+                field.span,
+                "multiple fields map to the same dictionary key \"{}\"",
+                field.key
+            );
+        }
+    }
+
+    Ok(())
+}
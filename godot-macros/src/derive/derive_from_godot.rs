@@ -8,7 +8,10 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-use crate::derive::data_models::{CStyleEnum, ConvertType, GodotConvert, NewtypeStruct, ViaType};
+use crate::derive::data_models::{
+    CStyleEnum, ConvertType, DictEnum, DictField, DictStruct, EnumeratorShape, GodotConvert,
+    NewtypeStruct, ViaType,
+};
 use crate::derive::derive_godot_convert::EnumeratorExprCache;
 use crate::util;
 
@@ -33,6 +36,9 @@ pub fn make_fromgodot(convert: &GodotConvert, cache: &mut EnumeratorExprCache) -
             variants,
             via: ViaType::Int { int_ident },
         } => make_fromgodot_for_int_enum(name, variants, int_ident, cache),
+
+        ConvertType::DictStruct { struct_ } => make_fromgodot_for_dict_struct(name, struct_),
+        ConvertType::DictEnum { enum_ } => make_fromgodot_for_dict_enum(name, enum_),
     }
 }
 
@@ -112,3 +118,88 @@ fn make_fromgodot_for_gstring_enum(name: &Ident, enum_: &CStyleEnum) -> TokenStr
         }
     }
 }
+
+/// Derives `FromGodot` for structs with a via type of `Dictionary`.
+fn make_fromgodot_for_dict_struct(name: &Ident, struct_: &DictStruct) -> TokenStream {
+    let type_name = name.to_string();
+    let bindings = struct_.fields.iter().map(|field| &field.binding);
+    let field_exprs = struct_
+        .fields
+        .iter()
+        .map(|field| make_dict_field_expr(field, &type_name));
+
+    quote! {
+        impl ::godot::meta::FromGodot for #name {
+            fn try_from_godot(via: ::godot::builtin::Dictionary) -> ::std::result::Result<Self, ::godot::meta::error::ConvertError> {
+                Ok(Self {
+                    #(
+                        #bindings: #field_exprs,
+                    )*
+                })
+            }
+        }
+    }
+}
+
+/// Derives `FromGodot` for enums with a via type of `Dictionary`, dispatching on the value stored under the tag key.
+fn make_fromgodot_for_dict_enum(name: &Ident, enum_: &DictEnum) -> TokenStream {
+    let type_name = name.to_string();
+    let tag_key = &enum_.tag_key;
+
+    let match_arms = enum_.enumerators.iter().map(|enumerator| {
+        let enumerator_name = &enumerator.name;
+        let tag_value = &enumerator.tag_value;
+
+        // Errors in fields mention the enumerator, e.g. `Shape::Circle`.
+        let qualified_name = format!("{name}::{enumerator_name}");
+        let bindings = enumerator.fields.iter().map(|field| &field.binding);
+        let field_exprs = enumerator
+            .fields
+            .iter()
+            .map(|field| make_dict_field_expr(field, &qualified_name));
+
+        let construction = match enumerator.shape {
+            EnumeratorShape::Unit => quote! { #name::#enumerator_name },
+            EnumeratorShape::Tuple => quote! { #name::#enumerator_name( #(#field_exprs),* ) },
+            EnumeratorShape::Named => quote! {
+                #name::#enumerator_name { #( #bindings: #field_exprs ),* }
+            },
+        };
+
+        quote! {
+            #tag_value => Ok(#construction),
+        }
+    });
+
+    quote! {
+        impl ::godot::meta::FromGodot for #name {
+            fn try_from_godot(via: ::godot::builtin::Dictionary) -> ::std::result::Result<Self, ::godot::meta::error::ConvertError> {
+                let tag = ::godot::private::dict_enum_tag(&via, #type_name, #tag_key)?;
+
+                match tag.as_str() {
+                    #( #match_arms )*
+                    other => Err(::godot::private::dict_unknown_enumerator(&via, #type_name, other)),
+                }
+            }
+        }
+    }
+}
+
+/// Expression extracting one field from the `via` dictionary, falling back to the default value or returning an error.
+fn make_dict_field_expr(field: &DictField, type_name: &str) -> TokenStream {
+    let DictField { ty, key, .. } = field;
+
+    let fallback = match &field.default {
+        Some(default_expr) => quote! { #default_expr },
+        None => quote! {
+            return Err(::godot::private::dict_missing_field(&via, #type_name, #key))
+        },
+    };
+
+    quote! {
+        match ::godot::private::dict_field::<#ty>(&via, #type_name, #key)? {
+            Some(value) => value,
+            None => #fallback,
+        }
+    }
+}
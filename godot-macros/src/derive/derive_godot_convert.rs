@@ -10,7 +10,7 @@ use std::collections::HashMap;
 use proc_macro2::{Ident, TokenStream, TokenTree};
 use quote::quote;
 
-use crate::derive::data_models::GodotConvert;
+use crate::derive::data_models::{CStyleEnum, ConvertType, GodotConvert, ViaType};
 use crate::derive::{make_fromgodot, make_togodot};
 use crate::ParseResult;
 
@@ -27,6 +27,15 @@ pub fn derive_godot_convert(item: venial::Item) -> ParseResult<TokenStream> {
     let to_godot_impl = make_togodot(&convert, &mut cache);
     let from_godot_impl = make_fromgodot(&convert, &mut cache);
 
+    // Enums with integer representation can additionally be registered as enum/bitfield of a class.
+    let constant_enum_impl = match &convert.convert_type {
+        ConvertType::Enum {
+            variants,
+            via: ViaType::Int { .. },
+        } => make_constant_enum(name, variants),
+        _ => TokenStream::new(),
+    };
+
     Ok(quote! {
         impl ::godot::meta::GodotConvert for #name  {
             type Via = #via_type;
@@ -34,9 +43,40 @@ pub fn derive_godot_convert(item: venial::Item) -> ParseResult<TokenStream> {
 
         #to_godot_impl
         #from_godot_impl
+        #constant_enum_impl
     })
 }
 
+/// Implements `ConstantEnum` (and the docs counterpart), used by `#[constant(enum)] type Name = Enum;` in `#[godot_api]` blocks.
+fn make_constant_enum(name: &Ident, variants: &CStyleEnum) -> TokenStream {
+    let enumerator_names = variants.enumerator_names();
+    let enumerator_strs = enumerator_names.iter().map(|name| name.to_string());
+
+    let docs_impl = crate::docs::make_constant_enum_docs_impl(
+        name,
+        enumerator_names,
+        variants.enumerator_attributes(),
+    );
+
+    quote! {
+        impl ::godot::register::private::constant::ConstantEnum for #name {
+            #[allow(deprecated)]
+            fn enumerators() -> Vec<::godot::register::private::constant::IntegerConstant> {
+                vec![
+                    #(
+                        ::godot::register::private::constant::IntegerConstant::new(
+                            #enumerator_strs,
+                            #name::#enumerator_names as i64,
+                        ),
+                    )*
+                ]
+            }
+        }
+
+        #docs_impl
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helpers for submodules
 
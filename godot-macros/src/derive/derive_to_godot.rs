@@ -8,7 +8,10 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-use crate::derive::data_models::{CStyleEnum, ConvertType, GodotConvert, NewtypeStruct, ViaType};
+use crate::derive::data_models::{
+    CStyleEnum, ConvertType, DictEnum, DictStruct, EnumeratorShape, GodotConvert, NewtypeStruct,
+    ViaType,
+};
 use crate::derive::derive_godot_convert::EnumeratorExprCache;
 
 /// Creates a `ToGodot` impl for the given `GodotConvert`.
@@ -32,6 +35,9 @@ pub fn make_togodot(convert: &GodotConvert, cache: &mut EnumeratorExprCache) ->
             variants,
             via: ViaType::Int { int_ident },
         } => make_togodot_for_int_enum(name, variants, int_ident, cache),
+
+        ConvertType::DictStruct { struct_ } => make_togodot_for_dict_struct(name, struct_),
+        ConvertType::DictEnum { enum_ } => make_togodot_for_dict_enum(name, enum_),
     }
 }
 
@@ -98,3 +104,68 @@ fn make_togodot_for_string_enum(name: &Ident, enum_: &CStyleEnum) -> TokenStream
         }
     }
 }
+
+/// Derives `ToGodot` for structs with a via type of `Dictionary`.
+fn make_togodot_for_dict_struct(name: &Ident, struct_: &DictStruct) -> TokenStream {
+    let keys = struct_.fields.iter().map(|field| &field.key);
+    let bindings = struct_.fields.iter().map(|field| &field.binding);
+
+    quote! {
+        impl ::godot::meta::ToGodot for #name {
+            type Pass = ::godot::meta::ByValue;
+
+            fn to_godot(&self) -> Self::Via {
+                let mut dict = ::godot::builtin::Dictionary::new();
+                #(
+                    dict.set(#keys, ::godot::meta::ToGodot::to_variant(&self.#bindings));
+                )*
+                dict
+            }
+        }
+    }
+}
+
+/// Derives `ToGodot` for enums with a via type of `Dictionary`, storing the enumerator name under the tag key.
+fn make_togodot_for_dict_enum(name: &Ident, enum_: &DictEnum) -> TokenStream {
+    let tag_key = &enum_.tag_key;
+
+    let match_arms = enum_.enumerators.iter().map(|enumerator| {
+        let enumerator_name = &enumerator.name;
+        let tag_value = &enumerator.tag_value;
+        let keys = enumerator.fields.iter().map(|field| &field.key);
+        let bindings = enumerator
+            .fields
+            .iter()
+            .map(|field| &field.binding)
+            .collect::<Vec<_>>();
+
+        let pattern = match enumerator.shape {
+            EnumeratorShape::Unit => quote! { #name::#enumerator_name },
+            EnumeratorShape::Tuple => quote! { #name::#enumerator_name( #(#bindings),* ) },
+            EnumeratorShape::Named => quote! { #name::#enumerator_name { #(#bindings),* } },
+        };
+
+        quote! {
+            #pattern => {
+                dict.set(#tag_key, #tag_value);
+                #(
+                    dict.set(#keys, ::godot::meta::ToGodot::to_variant(#bindings));
+                )*
+            }
+        }
+    });
+
+    quote! {
+        impl ::godot::meta::ToGodot for #name {
+            type Pass = ::godot::meta::ByValue;
+
+            fn to_godot(&self) -> Self::Via {
+                let mut dict = ::godot::builtin::Dictionary::new();
+                match self {
+                    #( #match_arms )*
+                }
+                dict
+            }
+        }
+    }
+}
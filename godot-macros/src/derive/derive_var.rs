@@ -41,6 +41,7 @@ pub fn derive_var(item: venial::Item) -> ParseResult<TokenStream> {
 /// Make an appropriate property hint implementation.
 ///
 /// For newtype structs we just defer to the wrapped type. For enums we use `PropertyHint::ENUM` with an appropriate hint string.
+/// Types converted via `Dictionary` use the hint of `Dictionary`.
 fn create_property_hint_impl(convert: &GodotConvert) -> TokenStream {
     use super::data_models::{ConvertType, ViaType};
 
@@ -64,5 +65,10 @@ fn create_property_hint_impl(convert: &GodotConvert) -> TokenStream {
                 }
             }
         }
+        ConvertType::DictStruct { .. } | ConvertType::DictEnum { .. } => {
+            quote! {
+                <::godot::builtin::Dictionary as ::godot::register::property::Var>::var_hint()
+            }
+        }
     }
 }
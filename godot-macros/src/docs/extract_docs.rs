@@ -46,6 +46,17 @@ pub fn document_struct(
         .filter_map(format_member_xml)
         .collect::<String>();
 
+    let tutorials = extract_tutorials(description)
+        .into_iter()
+        .map(|(title, url)| {
+            format!(
+                r#"<link title="{title}">{url}</link>"#,
+                title = xml_escape(title),
+                url = xml_escape(url),
+            )
+        })
+        .collect::<String>();
+
     let base_escaped = xml_escape(base);
 
     quote! {
@@ -55,6 +66,7 @@ pub fn document_struct(
             experimental: #experimental_attr,
             deprecated: #deprecated_attr,
             properties: #properties,
+            tutorials: #tutorials,
         }
     }
 }
@@ -72,7 +84,6 @@ pub fn document_inherent_impl(
 
     let constant_xml_elems = constants
         .iter()
-        .map(|ConstDefinition { raw_constant }| raw_constant)
         .filter_map(format_constant_xml)
         .collect::<String>();
 
@@ -88,6 +99,43 @@ pub fn document_inherent_impl(
     }
 }
 
+/// Returns code containing the doc information of each enumerator of a `#[derive(GodotConvert)] enum MyEnum` declaration.
+///
+/// All enumerators are listed, since Godot shows an enum section only with the enumerators present in the docs.
+pub fn document_constant_enum(
+    enum_name: &Ident,
+    enumerator_names: &[Ident],
+    enumerator_attributes: &[Vec<venial::Attribute>],
+) -> TokenStream {
+    let enumerators =
+        enumerator_names
+            .iter()
+            .zip(enumerator_attributes)
+            .map(|(name, attributes)| {
+                let XmlParagraphs {
+                    description_content,
+                    deprecated_attr,
+                    experimental_attr,
+                } = attribute_docs_to_xml_paragraphs(attributes).unwrap_or_default();
+
+                let name_str = name.to_string();
+                let attributes = format!("{deprecated_attr}{experimental_attr}");
+
+                quote! {
+                    ::godot::docs::EnumeratorDocs {
+                        name: #name_str,
+                        value: #enum_name::#name as i64,
+                        attributes: #attributes,
+                        description: #description_content,
+                    }
+                }
+            });
+
+    quote! {
+        &[ #( #enumerators ),* ]
+    }
+}
+
 /// Returns code containing the doc information of a `#[godot_api] impl ITrait for MyClass` declaration.
 pub fn document_interface_trait_impl(impl_members: &[venial::ImplMember]) -> TokenStream {
     let interface_methods = impl_members
@@ -169,6 +217,11 @@ fn docs_with_attributes(doc: &[venial::Attribute]) -> (String, String, String) {
             continue;
         }
 
+        // Tutorial links are collected separately by `extract_tutorials()`.
+        if trimmed.starts_with("@tutorial") {
+            continue;
+        }
+
         // Check for `/// @deprecated` ... or `/// @experimental`
         if trimmed.starts_with("@deprecated") {
             current_bucket = &mut deprecated;
@@ -190,8 +243,13 @@ fn docs_with_attributes(doc: &[venial::Attribute]) -> (String, String, String) {
 /// See also: [`XmlParagraphs`].
 fn attribute_docs_to_xml_paragraphs(doc: &[venial::Attribute]) -> Option<XmlParagraphs> {
     let (docs, deprecated, experimental) = docs_with_attributes(doc);
+    let rust_deprecated = deprecated_attribute_note(doc);
 
-    if docs.is_empty() && deprecated.is_empty() && experimental.is_empty() {
+    if docs.is_empty()
+        && deprecated.is_empty()
+        && experimental.is_empty()
+        && rust_deprecated.is_none()
+    {
         return None;
     }
 
@@ -202,12 +260,29 @@ fn attribute_docs_to_xml_paragraphs(doc: &[venial::Attribute]) -> Option<XmlPara
         // Mind the whitespace before XML attribute declaration.
         |description, attribute| format!(" {attribute}=\"{description}\"");
 
+    // An explicit `@deprecated` paragraph takes precedence over the note of a `#[deprecated]` attribute. Godot treats an empty
+    // attribute as "deprecated without explanation".
+    let deprecated_attr = match (to_bbcode(deprecated), rust_deprecated) {
+        (Some(deprecated), _) => to_xml_attribute(xml_escape(deprecated), "deprecated"),
+        (None, Some(note)) => to_xml_attribute(
+            to_bbcode(note).map(xml_escape).unwrap_or_default(),
+            "deprecated",
+        ),
+        (None, None) => String::new(),
+    };
+
+    let (docs, params) = split_parameters_section(&docs);
+    let mut description_content = to_bbcode(docs).unwrap_or_default();
+    if !params.is_empty() {
+        if !description_content.is_empty() {
+            description_content.push_str("[br][br]");
+        }
+        description_content.push_str(&format_parameters_bbcode(params));
+    }
+
     Some(XmlParagraphs {
-        description_content: to_bbcode(docs).map(xml_escape).unwrap_or_default(),
-        deprecated_attr: to_bbcode(deprecated)
-            .map(xml_escape)
-            .map(|s| to_xml_attribute(s, "deprecated"))
-            .unwrap_or_default(),
+        description_content: xml_escape(description_content),
+        deprecated_attr,
         experimental_attr: to_bbcode(experimental)
             .map(xml_escape)
             .map(|s| to_xml_attribute(s, "experimental"))
@@ -215,6 +290,134 @@ fn attribute_docs_to_xml_paragraphs(doc: &[venial::Attribute]) -> Option<XmlPara
     })
 }
 
+/// Returns the note of a Rust `#[deprecated]` attribute, if present. The note is empty for a plain `#[deprecated]`.
+///
+/// Supports `#[deprecated]`, `#[deprecated = "note"]` and `#[deprecated(since = "...", note = "note")]`.
+fn deprecated_attribute_note(attributes: &[venial::Attribute]) -> Option<String> {
+    let attr = attributes.iter().find(|attr| {
+        attr.get_single_path_segment()
+            .is_some_and(|x| x == "deprecated")
+    })?;
+
+    let string_value = |token: &proc_macro2::TokenTree| {
+        litrs::StringLit::<String>::try_from(token)
+            .map(|lit| lit.into_value())
+            .ok()
+    };
+
+    let note = match &attr.value {
+        venial::AttributeValue::Equals(_, tokens) => tokens.first().and_then(string_value),
+        venial::AttributeValue::Group(_, tokens) => tokens
+            .windows(3)
+            .find(
+                |window| matches!(&window[0], proc_macro2::TokenTree::Ident(key) if key == "note"),
+            )
+            .and_then(|window| string_value(&window[2])),
+        venial::AttributeValue::Empty => None,
+    };
+
+    Some(note.unwrap_or_default())
+}
+
+/// Extracts class tutorial links, written as `@tutorial: https://...` or `@tutorial(Title): https://...` (same as in GDScript).
+///
+/// Returns `(title, url)` pairs; the title is empty if not specified.
+fn extract_tutorials(doc: &[venial::Attribute]) -> Vec<(String, String)> {
+    extract_docs_from_attributes(doc)
+        .filter_map(|line| {
+            let rest = line.trim().strip_prefix("@tutorial")?;
+
+            let (title, rest) = match rest.strip_prefix('(') {
+                Some(rest) => {
+                    let (title, rest) = rest.split_once(')')?;
+                    (title.trim().to_string(), rest)
+                }
+                None => (String::new(), rest),
+            };
+
+            let url = rest.trim_start().strip_prefix(':')?.trim();
+            (!url.is_empty()).then(|| (title, url.to_string()))
+        })
+        .collect()
+}
+
+/// Separates an `# Arguments` or `# Parameters` section (as commonly used in Rust docs) from the rest of the Markdown description.
+///
+/// Each list item of the form `` * `name` - description `` (or with `:` instead of `-`) becomes a `(name, description)` pair.
+/// Section content that does not follow this form remains in the description.
+fn split_parameters_section(docs: &str) -> (String, Vec<(String, String)>) {
+    let mut description = String::new();
+    let mut params: Vec<(String, String)> = Vec::new();
+    let mut in_section = false;
+    let mut has_section = false;
+
+    for line in docs.lines() {
+        let trimmed = line.trim();
+
+        if let Some(heading) = trimmed.strip_prefix('#') {
+            let heading = heading.trim_start_matches('#').trim();
+            in_section = matches!(heading, "Arguments" | "Parameters");
+            if in_section {
+                has_section = true;
+                continue;
+            }
+        }
+
+        if in_section {
+            if let Some(param) = parse_parameter_item(trimmed) {
+                params.push(param);
+                continue;
+            }
+
+            // Continuation of the previous item's description.
+            if let Some((_, param_description)) = params.last_mut() {
+                if !trimmed.is_empty() && line.starts_with([' ', '\t']) {
+                    param_description.push(' ');
+                    param_description.push_str(trimmed);
+                    continue;
+                }
+            }
+        }
+
+        description.push_str(line);
+        description.push('\n');
+    }
+
+    if !has_section {
+        return (docs.to_string(), params);
+    }
+
+    (description.trim().to_string(), params)
+}
+
+/// Parses `` * `name` - description `` or `- name: description`.
+fn parse_parameter_item(line: &str) -> Option<(String, String)> {
+    let item = line.strip_prefix(['*', '-'])?.trim_start();
+
+    let (name, rest) = match item.strip_prefix('`') {
+        Some(quoted) => quoted.split_once('`')?,
+        None => item.split_once([':', ' '])?,
+    };
+
+    let description = rest.trim_start().trim_start_matches(['-', ':', '–']).trim();
+
+    let is_ident = !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_');
+    is_ident.then(|| (name.to_string(), description.to_string()))
+}
+
+fn format_parameters_bbcode(params: Vec<(String, String)>) -> String {
+    let items = params
+        .into_iter()
+        .map(|(name, description)| {
+            let description = markdown_converter::to_bbcode(&description);
+            format!("• [param {name}]: {description}")
+        })
+        .collect::<Vec<_>>()
+        .join("[br]");
+
+    format!("[b]Parameters:[/b][br]{items}")
+}
+
 fn format_venial_params_xml(params: &venial::Punctuated<venial::FnParam>) -> String {
     let non_receiver_params = params.iter().filter_map(|(param, _punct)| match param {
         venial::FnParam::Receiver(_) => None,
@@ -248,7 +451,8 @@ fn format_signal_xml(signal: &SignalDefinition) -> Option<String> {
     ))
 }
 
-fn format_constant_xml(constant: &venial::Constant) -> Option<String> {
+fn format_constant_xml(definition: &ConstDefinition) -> Option<String> {
+    let constant = &definition.raw_constant;
     let XmlParagraphs {
         description_content,
         deprecated_attr,
@@ -262,8 +466,16 @@ fn format_constant_xml(constant: &venial::Constant) -> Option<String> {
         .map(|x| x.to_token_stream().to_string())
         .unwrap_or_else(|| "null".to_string());
 
+    let group_attrs = match &definition.group {
+        Some(group) if group.is_bitfield => {
+            format!(r#" enum="{}" is_bitfield="true""#, group.name)
+        }
+        Some(group) => format!(r#" enum="{}""#, group.name),
+        None => String::new(),
+    };
+
     Some(format!(
-        r#"<constant name="{name}" value="{value}"{deprecated_attr}{experimental_attr}>{description_content}</constant>"#,
+        r#"<constant name="{name}" value="{value}"{group_attrs}{deprecated_attr}{experimental_attr}>{description_content}</constant>"#,
         name = xml_escape(name),
         value = xml_escape(value),
     ))
@@ -355,7 +567,12 @@ fn format_method_xml(method: &FuncDefinition) -> Option<String> {
 
     let signature = &method.signature_info;
 
-    let return_ty = signature.return_type.to_token_stream().to_string();
+    // Async methods return a handle object; render it as users would write it, not as the fully qualified path used in codegen.
+    let return_ty = if signature.is_async {
+        "Gd<RefCounted>".to_string()
+    } else {
+        signature.return_type.to_token_stream().to_string()
+    };
     let return_ty = xml_escape(return_ty);
 
     let param_names_and_types = signature.param_idents.iter().zip(&signature.param_types);
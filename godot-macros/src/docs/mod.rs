@@ -11,7 +11,7 @@ mod markdown_converter;
 
 use proc_macro2::{Ident, TokenStream};
 
-use crate::class::{ConstDefinition, Field, FuncDefinition, SignalDefinition};
+use crate::class::{ConstDefinition, ConstEnumDefinition, Field, FuncDefinition, SignalDefinition};
 
 #[cfg(all(feature = "register-docs", since_api = "4.3"))]
 mod docs_generators {
@@ -39,6 +39,7 @@ mod docs_generators {
     pub fn make_trait_docs_registration(
         functions: &[FuncDefinition],
         constants: &[ConstDefinition],
+        constant_enums: &[ConstEnumDefinition],
         signals: &[SignalDefinition],
         class_name: &Ident,
         prv: &TokenStream,
@@ -49,6 +50,23 @@ mod docs_generators {
             signal_xml_elems,
         } = extract_docs::document_inherent_impl(functions, constants, signals);
 
+        // Enumerator docs are provided by #[derive(GodotConvert)] on the enum itself.
+        let constant_enum_docs = constant_enums.iter().map(|def| {
+            let name = def.name.to_string();
+            let enum_ty = &def.enum_ty;
+            let is_bitfield = def.is_bitfield;
+
+            quote! {
+                ::godot::sys::plugin_add!(#prv::__GODOT_DOCS_REGISTRY; #prv::DocsPlugin::new::<#class_name>(
+                    #prv::DocsItem::ConstantEnum(#prv::ConstantEnumDocs {
+                        name: #name,
+                        is_bitfield: #is_bitfield,
+                        enumerators: <#enum_ty as ::godot::docs::ConstantEnumDocsProvider>::ENUMERATOR_DOCS,
+                    })
+                ));
+            }
+        });
+
         quote! {
             ::godot::sys::plugin_add!(#prv::__GODOT_DOCS_REGISTRY; #prv::DocsPlugin::new::<#class_name>(
                 #prv::DocsItem::InherentImpl(#prv::InherentImplDocs {
@@ -57,6 +75,27 @@ mod docs_generators {
                     constants_xml: #constant_xml_elems
                 })
             ));
+
+            #( #constant_enum_docs )*
+        }
+    }
+
+    pub fn make_constant_enum_docs_impl(
+        enum_name: &Ident,
+        enumerator_names: &[Ident],
+        enumerator_attributes: &[Vec<venial::Attribute>],
+    ) -> TokenStream {
+        let enumerator_docs = extract_docs::document_constant_enum(
+            enum_name,
+            enumerator_names,
+            enumerator_attributes,
+        );
+
+        quote! {
+            impl ::godot::docs::ConstantEnumDocsProvider for #enum_name {
+                #[allow(deprecated)]
+                const ENUMERATOR_DOCS: &'static [::godot::docs::EnumeratorDocs] = #enumerator_docs;
+            }
         }
     }
 
@@ -95,6 +134,7 @@ mod placeholders {
     pub fn make_trait_docs_registration(
         _functions: &[FuncDefinition],
         _constants: &[ConstDefinition],
+        _constant_enums: &[ConstEnumDefinition],
         _signals: &[SignalDefinition],
         _class_name: &Ident,
         _prv: &proc_macro2::TokenStream,
@@ -102,6 +142,14 @@ mod placeholders {
         TokenStream::new()
     }
 
+    pub fn make_constant_enum_docs_impl(
+        _enum_name: &Ident,
+        _enumerator_names: &[Ident],
+        _enumerator_attributes: &[Vec<venial::Attribute>],
+    ) -> TokenStream {
+        TokenStream::new()
+    }
+
     pub fn make_interface_impl_docs_registration(
         _impl_members: &[venial::ImplMember],
         _class_name: &Ident,
@@ -13,13 +13,57 @@ use crate::util::{
 };
 use crate::ParseResult;
 
+/// Where the generated test registers itself.
+#[derive(Copy, Clone)]
+enum TestFlavor {
+    /// `#[itest]` inside godot-rust's own integration tests.
+    Itest,
+
+    /// Public `#[godot::test]` for user crates.
+    GodotTest,
+}
+
+impl TestFlavor {
+    /// Name of the attribute, as seen by the parser.
+    fn attr_name(self) -> &'static str {
+        match self {
+            Self::Itest => "itest",
+            Self::GodotTest => "test",
+        }
+    }
+
+    /// Name of the attribute in error messages.
+    fn display_name(self) -> &'static str {
+        match self {
+            Self::Itest => "itest",
+            Self::GodotTest => "godot::test",
+        }
+    }
+}
+
 pub fn attribute_itest(input_item: venial::Item) -> ParseResult<TokenStream> {
+    make_test(input_item, TestFlavor::Itest)
+}
+
+pub fn attribute_godot_test(input_item: venial::Item) -> ParseResult<TokenStream> {
+    make_test(input_item, TestFlavor::GodotTest)
+}
+
+fn make_test(input_item: venial::Item, flavor: TestFlavor) -> ParseResult<TokenStream> {
+    let attr_name = flavor.attr_name();
+    let display_name = flavor.display_name();
+
     let func = match input_item {
         venial::Item::Function(f) => f,
-        _ => return bail!(&input_item, "#[itest] can only be applied to functions"),
+        _ => {
+            return bail!(
+                &input_item,
+                "#[{display_name}] can only be applied to functions"
+            )
+        }
     };
 
-    let mut attr = KvParser::parse_required(&func.attributes, "itest", &func.name)?;
+    let mut attr = KvParser::parse_required(&func.attributes, attr_name, &func.name)?;
     let skipped = attr.handle_alone("skip")?;
     let focused = attr.handle_alone("focus")?;
     let is_async = attr.handle_alone("async")?;
@@ -31,13 +75,13 @@ pub fn attribute_itest(input_item: venial::Item) -> ParseResult<TokenStream> {
         || (func.return_ty.is_some() && !is_async)
         || func.where_clause.is_some()
     {
-        return bad_signature(&func);
+        return bad_signature(&func, flavor);
     }
 
     if skipped && focused {
         return bail!(
             func.name,
-            "#[itest]: keys `skip` and `focus` are mutually exclusive",
+            "#[{display_name}]: keys `skip` and `focus` are mutually exclusive",
         );
     }
 
@@ -51,17 +95,20 @@ pub fn attribute_itest(input_item: venial::Item) -> ParseResult<TokenStream> {
             if path_ends_with(&param.ty.tokens, "TestContext") {
                 param.to_token_stream()
             } else if is_async {
-                return bad_async_signature(&func);
+                return bad_async_signature(&func, flavor);
             } else {
-                return bad_signature(&func);
+                return bad_signature(&func, flavor);
             }
         } else if is_async {
-            return bad_async_signature(&func);
+            return bad_async_signature(&func, flavor);
         } else {
-            return bad_signature(&func);
+            return bad_signature(&func, flavor);
         }
     } else {
-        quote! { __unused_context: &crate::framework::TestContext }
+        match flavor {
+            TestFlavor::Itest => quote! { __unused_context: &crate::framework::TestContext },
+            TestFlavor::GodotTest => quote! { __unused_context: &::godot::testing::TestContext },
+        }
     };
 
     let return_ty = func.return_ty.as_ref();
@@ -70,25 +117,65 @@ pub fn attribute_itest(input_item: venial::Item) -> ParseResult<TokenStream> {
             .and_then(extract_typename)
             .is_none_or(|segment| segment.ident != "TaskHandle")
     {
-        return bad_async_signature(&func);
+        return bad_async_signature(&func, flavor);
     }
 
     let body = &func.body;
 
-    let (return_tokens, test_case_ty, plugin_name);
-    if is_async {
+    let return_tokens = if is_async {
         let [arrow, arrow_head] = func.tk_return_arrow.unwrap();
-        return_tokens = quote! { #arrow #arrow_head #return_ty }; // retain span.
-        test_case_ty = quote! { crate::framework::AsyncRustTestCase };
-        plugin_name = ident("__GODOT_ASYNC_ITEST");
+        quote! { #arrow #arrow_head #return_ty } // retain span.
     } else {
-        return_tokens = TokenStream::new();
-        test_case_ty = quote! { crate::framework::RustTestCase };
-        plugin_name = ident("__GODOT_ITEST");
+        TokenStream::new()
     };
 
-    // Filter out #[itest] itself, but preserve other attributes like #[allow], #[expect], etc.
-    let other_attributes = retain_attributes_except(&func.attributes, "itest");
+    // Filter out the test attribute itself, but preserve other attributes like #[allow], #[expect], etc.
+    let other_attributes = retain_attributes_except(&func.attributes, attr_name);
+
+    let registration = match flavor {
+        TestFlavor::Itest => {
+            let (test_case_ty, plugin_name) = if is_async {
+                (
+                    quote! { crate::framework::AsyncRustTestCase },
+                    ident("__GODOT_ASYNC_ITEST"),
+                )
+            } else {
+                (
+                    quote! { crate::framework::RustTestCase },
+                    ident("__GODOT_ITEST"),
+                )
+            };
+
+            quote! {
+                ::godot::sys::plugin_add!(crate::framework::#plugin_name; #test_case_ty {
+                    name: #test_name_str,
+                    skipped: #skipped,
+                    focused: #focused,
+                    file: std::file!(),
+                    line: std::line!(),
+                    function: #test_name,
+                });
+            }
+        }
+        TestFlavor::GodotTest => {
+            let variant = if is_async {
+                ident("Async")
+            } else {
+                ident("Sync")
+            };
+
+            quote! {
+                ::godot::sys::plugin_add!(::godot::testing::__GODOT_TEST; ::godot::testing::TestCase {
+                    name: #test_name_str,
+                    skipped: #skipped,
+                    focused: #focused,
+                    file: ::std::file!(),
+                    line: ::std::line!(),
+                    function: ::godot::testing::TestFunction::#variant(#test_name),
+                });
+            }
+        }
+    };
 
     Ok(quote! {
         #(#other_attributes)*
@@ -96,33 +183,34 @@ pub fn attribute_itest(input_item: venial::Item) -> ParseResult<TokenStream> {
             #body
         }
 
-        ::godot::sys::plugin_add!(crate::framework::#plugin_name; #test_case_ty {
-            name: #test_name_str,
-            skipped: #skipped,
-            focused: #focused,
-            file: std::file!(),
-            line: std::line!(),
-            function: #test_name,
-        });
+        #registration
     })
 }
 
-fn bad_signature(func: &venial::Function) -> Result<TokenStream, venial::Error> {
+fn bad_signature(
+    func: &venial::Function,
+    flavor: TestFlavor,
+) -> Result<TokenStream, venial::Error> {
     bail!(
         func,
-        "#[itest] function must have one of these signatures:\
+        "#[{attr}] function must have one of these signatures:\
         \n  fn {f}() {{ ... }}\
         \n  fn {f}(ctx: &TestContext) {{ ... }}",
+        attr = flavor.display_name(),
         f = func.name,
     )
 }
 
-fn bad_async_signature(func: &venial::Function) -> Result<TokenStream, venial::Error> {
+fn bad_async_signature(
+    func: &venial::Function,
+    flavor: TestFlavor,
+) -> Result<TokenStream, venial::Error> {
     bail!(
         func,
-        "#[itest(async)] function must have one of these signatures:\
+        "#[{attr}(async)] function must have one of these signatures:\
         \n  fn {f}() -> TaskHandle {{ ... }}\
         \n  fn {f}(ctx: &TestContext) -> TaskHandle {{ ... }}",
+        attr = flavor.display_name(),
         f = func.name,
     )
 }
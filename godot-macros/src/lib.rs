@@ -321,6 +321,40 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// Some property hints have no GDScript annotation, but are available as dedicated keys. Where possible, the field type is checked at
+/// compile time.
+///
+/// | Rust attribute                                                  | Property hint           | Field types                         |
+/// |-----------------------------------------------------------------|-------------------------|-------------------------------------|
+/// | `#[export(password)]`                                           | `PASSWORD`              | `GString`, `StringName`             |
+/// | `#[export(locale_id)]`                                          | `LOCALE_ID`             | `GString`, `StringName`             |
+/// | `#[export(expression)]`                                         | `EXPRESSION`            | `GString`, `StringName`             |
+/// | `#[export(input_name)]`<br>`#[export(input_name = (show_builtin, loose_mode))]` | `INPUT_NAME` (Godot 4.3+) | `GString`, `StringName` |
+/// | `#[export(node_path_valid_types = (Class1, ...))]`              | `NODE_PATH_VALID_TYPES` | `NodePath`; classes must be nodes   |
+/// | `#[export(link)]`                                               | `LINK`                  | vectors                             |
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init, base=Node)]
+/// struct Login {
+///     #[export(password)]
+///     secret: GString,
+///
+///     #[export(input_name = (show_builtin))]
+///     jump_action: StringName,
+///
+///     // @export_node_path("Node2D", "Node3D")
+///     #[export(node_path_valid_types = (Node2D, Node3D))]
+///     avatar: NodePath,
+///
+///     #[export(link)]
+///     scale: Vector2,
+///
+///     base: Base<Node>,
+/// }
+/// ```
+///
 /// It is possible to group your exported properties inside the Inspector with the `#[export_group(name = "...", prefix =  "...")]` attribute.
 /// Every exported property after this attribute will be added to the group. Start a new group or use `#[export_group(name = "")]` (with an empty name) to break out.
 ///
@@ -378,6 +412,67 @@ use crate::util::{bail, ident, KvParser};
 ///```
 ///
 ///
+/// Above groups, properties can be organized into categories with `#[export_category(name = "...")]`, equivalent to GDScript's
+/// `@export_category`. A category ends the current group and subgroup, and lasts until the next category.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct Enemy {
+///     // @export_category("Stats")
+///     #[export_category(name = "Stats")]
+///     #[export]
+///     health: i32,
+///
+///     #[export_group(name = "Movement")]
+///     #[export]
+///     speed: f32,
+///
+///     // @export_category("Visuals")
+///     #[export_category(name = "Visuals")]
+///     #[export]
+///     tint: Color,
+/// }
+/// ```
+///
+///
+/// ## Editor buttons -- `#[export_tool_button]`
+///
+/// Available since Godot 4.4. The equivalent of GDScript's `@export_tool_button` shows a clickable button in the Inspector, which invokes a
+/// [`Callable`](../builtin/struct.Callable.html) when pressed. The class must be a `#[class(tool)]`.
+///
+/// The button either uses a `Callable` stored in the field, or binds a `#[func]` of the same class via `method` on a
+/// [`PhantomVar<Callable>`](../register/property/struct.PhantomVar.html) field. Keys `label` (required) and `icon` (name of an editor
+/// theme icon) configure the button.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, tool, base=Node3D)]
+/// struct Level {
+///     // @export_tool_button("Bake navmesh", "Bake") var bake_button = bake_navmesh
+///     #[export_tool_button(label = "Bake navmesh", icon = "Bake", method = bake_navmesh)]
+///     bake_button: PhantomVar<Callable>,
+///
+///     // @export_tool_button("Regenerate") var regenerate_button = Callable(...)
+///     #[export_tool_button(label = "Regenerate")]
+///     #[init(val = Callable::from_fn("regenerate", |_| godot_print!("Regenerating...")))]
+///     regenerate_button: Callable,
+///
+///     base: Base<Node3D>,
+/// }
+///
+/// #[godot_api]
+/// impl Level {
+///     #[func]
+///     fn bake_navmesh(&mut self) {
+///         godot_print!("Baking...");
+///     }
+/// }
+/// ```
+///
+///
 /// ## Low-level property hints and usage
 ///
 /// You can specify custom property hints, hint strings, and usage flags in a `#[var]` attribute using the `hint`, `hint_string`
@@ -405,6 +500,19 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// To keep the `Export` trait checks of `#[export]` while setting hint, hint string and usage manually, use
+/// `#[export(custom = (...))]`, the equivalent of GDScript's `@export_custom`. Only `hint` is required; `usage` defaults to `DEFAULT`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     // @export_custom(PROPERTY_HINT_ENUM, "One,Two", PROPERTY_USAGE_EDITOR | PROPERTY_USAGE_STORAGE)
+///     #[export(custom = (hint = ENUM, hint_string = "One,Two", usage = [EDITOR, STORAGE]))]
+///     my_field: i64,
+/// }
+/// ```
 ///
 /// # Further class customization
 ///
@@ -484,6 +592,66 @@ use crate::util::{bail, ident, KvParser};
 /// Even though this class is a `Node` and it has an init function, it still won't show up in the editor as a node you can add to a scene
 /// because we have added a `hidden` key to the class. This will also prevent it from showing up in documentation.
 ///
+/// ## Typed scene nodes
+///
+/// For a class that is the root of a scene, `#[class(scene = "res://path.tscn")]` reads the scene file at compile time and generates a
+/// struct with one `Gd<T>` field per node. An instance is returned by the `nodes()` method, which looks up all nodes relative to `self`:
+///
+/// ```ignore
+/// # use godot::prelude::*;
+/// # use godot::classes::INode2D;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D, scene = "res://player.tscn")]
+/// pub struct Player {
+///     base: Base<Node2D>,
+/// }
+///
+/// #[godot_api]
+/// impl INode2D for Player {
+///     fn ready(&mut self) {
+///         // Node "Weapon" of type Node2D in player.tscn.
+///         let mut weapon: Gd<Node2D> = self.nodes().weapon;
+///         weapon.set_rotation(0.5);
+///     }
+/// }
+/// ```
+///
+/// Field names are the snake_case node names, e.g. `HealthBar` becomes `health_bar`. If several nodes share a name, their full paths are
+/// used instead (`Body/Sprite` becomes `body_sprite`). When a node is renamed or removed in the scene, code referring to the old field no
+/// longer compiles. Node types are taken from the scene file; instanced and inherited scenes are followed to determine their root type.
+///
+/// Godot class names are mapped to Rust names like the generated engine classes, e.g. `HTTPRequest` becomes `HttpRequest` and
+/// `GPUParticles2D` becomes `GpuParticles2D`. Rust classes are resolved in the scope of the annotated class. A class registered under a
+/// different name with `#[class(rename = ...)]` is not found automatically; bring it into scope under its Godot name, for example
+/// `use MyRustClass as MyGodotClass;`.
+///
+/// `res://` paths are resolved relative to the directory containing `project.godot`, which is searched in the crate directory and its
+/// parents up to the workspace root (as well as a `godot` subdirectory of each). Set the `GODOT_PROJECT_DIR` environment variable to point
+/// elsewhere. Other paths are relative to the crate's `Cargo.toml`. The class needs a `Base<T>` field.
+///
+/// The struct is named after the class with a `Nodes` suffix (here `PlayerNodes`). Its `from_root()` constructor accepts any scene root,
+/// for example one obtained from `PackedScene::instantiate()`.
+///
+/// If the class already has a method named `nodes`, choose a different name for the accessor with `scene_accessor`:
+/// ```ignore
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D, scene = "res://player.tscn", scene_accessor = scene_nodes)]
+/// pub struct Player {
+///     base: Base<Node2D>,
+/// }
+///
+/// impl Player {
+///     fn nodes(&self) -> i32 {
+///         self.base().get_child_count()
+///     }
+///
+///     fn health_bar(&self) -> Gd<Node> {
+///         self.scene_nodes().health_bar
+///     }
+/// }
+/// ```
+///
 /// # Further field customization
 ///
 /// ## Fine-grained inference hints
@@ -531,6 +699,8 @@ use crate::util::{bail, ident, KvParser};
 /// #[derive(GodotClass)]
 /// # #[class(init)]
 /// /// This is an example struct for documentation, inside documentation.
+/// ///
+/// /// @tutorial(Getting started): https://godot-rust.github.io/book
 /// struct DocumentedStruct {
 ///     /// This is a class member.
 ///     /// You can use markdown formatting such as _italics_.
@@ -550,8 +720,40 @@ use crate::util::{bail, ident, KvParser};
 ///     pub fn produce_item(&self) -> f32 {
 ///         self.item + 0.2
 ///     }
+///
+///     /// Adds to the item.
+///     ///
+///     /// # Arguments
+///     /// * `amount` - How much to add. May be negative.
+///     #[func]
+///     #[deprecated = "Use `produce_item()` instead."]
+///     pub fn add_to_item(&mut self, amount: f32) {
+///         self.item += amount;
+///     }
+///
+///     /// Not moving.
+///     #[constant(enum = Motion)]
+///     const IDLE: i32 = 0;
+///
+///     /// Walking slowly.
+///     #[constant(enum = Motion)]
+///     const WALKING: i32 = 1;
 /// }
 /// ```
+///
+/// Beyond plain descriptions, the following are recognized:
+/// - `@tutorial: url` or `@tutorial(Title): url` lines on the class add links to its _Tutorials_ section.
+/// - An `# Arguments` (or `# Parameters`) section listing `` * `name` - description `` items documents the parameters of a method or signal.
+/// - Rust's `#[deprecated]` attribute marks the item as deprecated, unless an explicit `@deprecated` paragraph is present.
+/// - Constants declared with `#[constant(enum = Name)]` or `#[constant(bitfield = Name)]` are grouped into an enum or bitfield.
+/// - Rust enums registered with `#[constant(enum)] type Name = MyEnum;` list their enumerators, documented on the enum variants.
+///
+/// Besides showing up in the editor, the documentation can be published as Godot class reference XML, Markdown or HTML pages, either
+/// from Rust with `godot::docs::export_docs()`, or from the command line:
+///
+/// ```text
+/// godot --headless --path my-project -- --godot-rust-export-docs docs/api md html
+/// ```
 #[doc(
     alias = "class",
     alias = "base",
@@ -561,11 +763,23 @@ use crate::util::{bail, ident, KvParser};
     alias = "export",
     alias = "tool",
     alias = "rename",
-    alias = "internal"
+    alias = "internal",
+    alias = "scene"
 )]
 #[proc_macro_derive(
     GodotClass,
-    attributes(class, base, hint, var, export, export_group, export_subgroup, init)
+    attributes(
+        class,
+        base,
+        hint,
+        var,
+        export,
+        export_category,
+        export_group,
+        export_subgroup,
+        export_tool_button,
+        init
+    )
 )]
 pub fn derive_godot_class(input: TokenStream) -> TokenStream {
     translate(input, class::derive_godot_class)
@@ -753,6 +967,40 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// - Default expressions are evaluated on each function call (not cached). **This may change**, see
 ///   [PR #1396](https://github.com/godot-rust/gdext/pull/1396).
 ///
+/// ## Async functions
+///
+/// `#[func]` can be declared as `async fn`. When called from Godot, the function is spawned as a task on the [async
+/// runtime](../task/fn.spawn.html), and the caller immediately receives a `RefCounted` handle. Once the future completes, this handle
+/// emits a `completed(result)` signal carrying the return value.
+///
+/// Since the task runs across several frames, it cannot hold a borrow of the instance. Async functions must therefore either be
+/// static, or take a `Gd<Self>` receiver via `#[func(gd_self)]` and bind it only where needed (never across `.await`).
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use std::time::Duration;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Level {
+///     loaded: bool,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Level {
+///     #[func(gd_self)]
+///     async fn load(mut this: Gd<Self>, level: i64) -> GString {
+///         godot::task::sleep(Duration::from_secs(1)).await;
+///         this.bind_mut().loaded = true;
+///
+///         format!("level {level}").into()
+///     }
+/// }
+/// ```
+/// ```gdscript
+/// var result = await level.load(3).completed  # "level 3"
+/// ```
+///
 /// ## Virtual methods
 ///
 /// Functions with the `#[func(virtual)]` attribute are virtual functions, meaning attached scripts can override them.
@@ -867,7 +1115,32 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ### Typed RPC calls
+///
+/// For each `#[rpc]`, a method with the same name and parameters is generated on a collection returned by `self.rpcs()` (trait
+/// [`WithUserRpcs`]) or `gd.rpcs()`. It returns an [`RpcCall`], which is sent with `to_peer(id)` or `to_all()`. Misspelled method names
+/// and wrong argument types are thus detected at compile time, unlike with the stringly-typed `Node::rpc()`.
+///
+/// ```ignore
+/// #[godot_api]
+/// impl Player {
+///     #[rpc(any_peer, reliable)]
+///     fn take_damage(&mut self, amount: i32) { ... }
+///
+///     #[func]
+///     fn on_hit(&mut self, attacker_id: i64) {
+///         // Instead of: self.base_mut().rpc_id(attacker_id, "take_damage", vslice![5]);
+///         self.rpcs().take_damage(5).to_peer(attacker_id);
+///     }
+/// }
+///
+/// // From outside the class:
+/// player.rpcs().take_damage(10).to_all();
+/// ```
+///
 // Note: for some reason, the intra-doc links don't work here, despite dev-dependency on godot.
+/// [`WithUserRpcs`]: ../obj/trait.WithUserRpcs.html
+/// [`RpcCall`]: ../register/struct.RpcCall.html
 /// [`RpcMode`]: ../classes/multiplayer_api/struct.RpcMode.html
 /// [`TransferMode`]: ../classes/multiplayer_peer/struct.TransferMode.html
 /// [`RpcConfig`]: ../register/struct.RpcConfig.html
@@ -958,6 +1231,53 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///
 /// Please refer to [the book](https://godot-rust.github.io/book/register/constants.html).
 ///
+/// Constants can be grouped into a named enum with `#[constant(enum = Name)]`, or into a bitfield with `#[constant(bitfield = Name)]`.
+/// Godot then treats them like the enums of engine classes, e.g. in GDScript autocompletion and in the class docs.
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {
+/// #     base: Base<RefCounted>,
+/// # }
+/// #[godot_api]
+/// impl MyStruct {
+///     #[constant(enum = Direction)]
+///     const LEFT: i32 = 0;
+///     #[constant(enum = Direction)]
+///     const RIGHT: i32 = 1;
+///
+///     #[constant(bitfield = Layers)]
+///     const GROUND: i32 = 1;
+///     #[constant(bitfield = Layers)]
+///     const WATER: i32 = 2;
+/// }
+/// ```
+///
+/// An existing Rust enum can be registered as a whole, if it derives `GodotConvert` with an integer `via` type. The name of the type alias
+/// becomes the name of the enum in Godot; the alias itself is removed from the `impl` block. Use `#[constant(bitfield)]` for flags.
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {
+/// #     base: Base<RefCounted>,
+/// # }
+/// #[derive(GodotConvert)]
+/// #[godot(via = i64)]
+/// pub enum Terrain {
+///     Grass,
+///     Sand,
+///     Rock = 10,
+/// }
+///
+/// #[godot_api]
+/// impl MyStruct {
+///     #[constant(enum)]
+///     type Terrain = Terrain;
+/// }
+/// ```
+///
 /// # Multiple inherent `impl` blocks
 ///
 /// Just like with regular structs, you can have multiple inherent `impl` blocks. This can be useful for code organization or when you want to generate code from a proc-macro.
@@ -1084,7 +1404,7 @@ pub fn godot_dyn(_meta: TokenStream, input: TokenStream) -> TokenStream {
 /// # Choosing a Via type
 ///
 /// To specify the `Via` type that your type should be converted to, you must use the `godot` attribute.
-/// There are currently two modes supported.
+/// There are currently three modes supported.
 ///
 /// ## `transparent`
 ///
@@ -1204,6 +1524,60 @@ pub fn godot_dyn(_meta: TokenStream, input: TokenStream) -> TokenStream {
 /// assert_eq!(MyEnum::B.to_godot(), 10);
 /// assert_eq!(MyEnum::C.to_godot(), 11);
 /// ```
+///
+/// ## `via = Dictionary`
+///
+/// Structs with named fields and enums with arbitrary payloads can be converted to a [`Dictionary`](../builtin/struct.Dictionary.html).
+///
+/// For structs, each field is stored under its name. Fields can be customized with these attributes:
+/// - `#[godot(rename = "key")]` stores the field under a different key.
+/// - `#[godot(default)]` uses `Default::default()` if the key is absent, `#[godot(default = expr)]` uses the given expression.
+///
+/// Fields without default are required; converting a dictionary without them (or with values of the wrong type) fails with an error
+/// naming the field.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::builtin::vdict;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(via = Dictionary)]
+/// struct Player {
+///     name: GString,
+///     #[godot(rename = "hp")]
+///     health: i64,
+///     #[godot(default = 1)]
+///     level: i32,
+/// }
+///
+/// let dict = vdict! { "name": "Alice", "hp": 100 };
+/// let player = Player::from_godot(dict);
+/// assert_eq!(player.level, 1);
+/// ```
+///
+/// Enums are stored as tagged dictionaries: the enumerator name (or its `#[godot(rename = "...")]`) is stored under the key `"type"`.
+/// A different key can be chosen with `#[godot(via = Dictionary, tag = "key")]`. Named fields are stored under their names, tuple fields
+/// under `"0"`, `"1"`, etc. Fields accept the same attributes as in structs.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(via = Dictionary, tag = "kind")]
+/// enum Shape {
+///     Circle { radius: f32 },
+///     Rect(Vector2),
+///     #[godot(rename = "none")]
+///     Empty,
+/// }
+///
+/// let dict = Shape::Circle { radius: 2.0 }.to_godot();
+/// assert_eq!(dict.at("kind"), "Circle".to_variant());
+/// assert_eq!(dict.at("radius"), 2.0.to_variant());
+///
+/// let dict = Shape::Rect(Vector2::ONE).to_godot();
+/// assert_eq!(dict.at("0"), Vector2::ONE.to_variant());
+/// ```
 #[proc_macro_derive(GodotConvert, attributes(godot))]
 pub fn derive_godot_convert(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_godot_convert)
@@ -1234,6 +1608,19 @@ pub fn itest(meta: TokenStream, input: TokenStream) -> TokenStream {
     translate_meta("itest", meta, input, itest::attribute_itest)
 }
 
+/// Registers a test that runs inside a Godot process, driven by [`HeadlessRunner`](../testing/struct.HeadlessRunner.html).
+///
+/// Supports the same signatures and keys as godot-rust's internal integration tests:
+/// - `#[godot::test] fn name() { ... }` or `fn name(ctx: &TestContext)`.
+/// - `#[godot::test(async)] fn name() -> TaskHandle { ... }`, where the test completes once the returned task has finished.
+/// - `#[godot::test(skip)]` and `#[godot::test(focus)]` to skip a test, or only run focused tests.
+///
+/// See the [`testing`](../testing/index.html) module for how to run them from `cargo test`.
+#[proc_macro_attribute]
+pub fn godot_test(meta: TokenStream, input: TokenStream) -> TokenStream {
+    translate_meta("test", meta, input, itest::attribute_godot_test)
+}
+
 /// Similar to `#[test]`, but runs a benchmark with Godot.
 ///
 /// Calls the `fn` many times and gathers statistics from its execution time.
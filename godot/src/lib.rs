@@ -181,6 +181,7 @@
 //!   Generates documentation for your structs from your Rust documentation.
 //!   Documentation is visible in Godot via `F1` -> searching for that class.
 //!   This feature requires at least Godot 4.3.
+//!   The same documentation can be exported as class reference XML, Markdown or HTML with `godot::docs::export_docs()`.
 //!   See also: [`#[derive(GodotClass)]`](register/derive.GodotClass.html#documentation)
 //!
 //! _Safeguards:_
@@ -200,7 +201,18 @@
 //! * **`serde`**
 //!
 //!   Implement the [serde](https://serde.rs/) traits `Serialize` and `Deserialize` traits for certain built-in types.
-//!   The serialized representation underlies **no stability guarantees** and may change at any time, even without a SemVer-breaking change.
+//!   The serialized representation underlies **no stability guarantees** and may change at any time, even without a SemVer-breaking change.<br><br>
+//!
+//! * **`log`**
+//!
+//!   Forward records of the [log](https://docs.rs/log) crate to Godot's output, through [`GodotLogger`](global/struct.GodotLogger.html).
+//!   The logger is installed automatically; see [`ExtensionLibrary::log_filter()`](init/trait.ExtensionLibrary.html#method.log_filter).<br><br>
+//!
+//! * **`tracing`**
+//!
+//!   Forward events of the [tracing](https://docs.rs/tracing) crate to Godot's output, through the
+//!   [`GodotLayer`](global/struct.GodotLayer.html) subscriber layer. Unless another global subscriber is set, one containing this layer is
+//!   installed automatically.
 //!
 
 #![doc(
@@ -253,7 +265,7 @@ pub mod register {
     pub use godot_core::registry::property;
     pub use godot_core::registry::signal::re_export::*;
     #[cfg(feature = "__codegen-full")]
-    pub use godot_core::registry::RpcConfig;
+    pub use godot_core::registry::{RpcCall, RpcConfig};
     pub use godot_macros::{godot_api, godot_dyn, Export, GodotClass, GodotConvert, Var};
 
     /// Re-exports used by proc-macro API.
@@ -262,6 +274,8 @@ pub mod register {
         #[cfg(feature = "__codegen-full")]
         pub use godot_core::registry::class::auto_register_rpcs;
         pub use godot_core::registry::godot_register_wrappers::*;
+        #[cfg(feature = "__codegen-full")]
+        pub use godot_core::registry::RpcTarget;
         pub use godot_core::registry::{constant, method};
     }
 }
@@ -272,10 +286,60 @@ pub mod test {
     pub use godot_macros::{bench, itest};
 }
 
+pub mod testing {
+    //! Running Rust tests inside a headless Godot process, driven from `cargo test`.
+    //!
+    //! Pure Rust logic that touches Godot types such as `GString`, `Array` or `Vector3` cannot be tested with plain `#[test]`, because those
+    //! types need a running engine. This module provides a small harness that closes the gap:
+    //!
+    //! 1. Annotate test functions in your extension library with [`#[godot::test]`](../attr.test.html). They are compiled into the library
+    //!    and registered automatically, similar to classes.
+    //! 2. From a regular Rust test (typically in the `tests/` directory, so Cargo builds the library first), use [`HeadlessRunner`] to launch
+    //!    Godot in headless mode on a project that loads your extension.
+    //! 3. The library detects the test run on startup, executes all registered tests on the main thread, prints the results and quits.
+    //!    `HeadlessRunner` parses them into a [`TestReport`].
+    //!
+    //! ```no_run
+    //! // src/lib.rs of your extension.
+    //! use godot::prelude::*;
+    //!
+    //! #[godot::test]
+    //! fn vector_length() {
+    //!     assert_eq!(Vector3::new(3.0, 4.0, 0.0).length(), 5.0);
+    //! }
+    //!
+    //! #[godot::test(async)]
+    //! fn waits_for_frame() -> godot::task::TaskHandle {
+    //!     godot::task::spawn(async {
+    //!         godot::task::next_frame().await;
+    //!     })
+    //! }
+    //! ```
+    //!
+    //! ```no_run
+    //! // tests/godot.rs
+    //! #[test]
+    //! fn godot_tests() {
+    //!     godot::testing::HeadlessRunner::new(concat!(env!("CARGO_MANIFEST_DIR"), "/godot"))
+    //!         .run()
+    //!         .assert_success();
+    //! }
+    //! ```
+    //!
+    //! Like the integration tests of godot-rust itself, tests support the keys `skip`, `focus` and `async`. If at least one test is focused,
+    //! only focused tests are run.
+    //!
+    //! The Godot binary is taken from the `GODOT4_BIN` environment variable, falling back to `godot4` in the `PATH`.
+
+    pub use godot_core::testing::*;
+}
+
 #[doc(hidden)]
 pub use godot_core::__deprecated;
 #[doc(hidden)]
 pub use godot_core::private;
+#[doc(inline)]
+pub use godot_macros::godot_test as test;
 
 /// Often-imported symbols.
 pub mod prelude;
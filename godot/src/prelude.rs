@@ -18,7 +18,7 @@ pub use super::meta::error::{ConvertError, IoError};
 pub use super::meta::{FromGodot, GodotConvert, ToGodot};
 pub use super::obj::{
     AsDyn, Base, DynGd, DynGdMut, DynGdRef, Gd, GdMut, GdRef, GodotClass, Inherits, InstanceId,
-    OnEditor, OnReady,
+    OnEditor, OnReady, WeakGd,
 };
 pub use super::register::property::{Export, PhantomVar, Var};
 // Re-export macros.
@@ -37,6 +37,8 @@ mod trait_reexports {
     pub use crate::obj::WithBaseField as _; // base(), base_mut(), to_gd(), run_deferred(), run_deferred_gd()
     pub use crate::obj::WithSignals as _; // Gd::signals()
     pub use crate::obj::WithUserSignals as _; // self.signals()
+    #[cfg(feature = "__codegen-full")]
+    pub use crate::obj::WithUserRpcs as _; // self.rpcs()
 }
 
 pub use trait_reexports::*;
@@ -0,0 +1,239 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::vdict;
+use godot::prelude::*;
+
+use crate::framework::{create_gdscript, expect_panic, itest};
+
+#[itest]
+fn dict_new() {
+    let dict: Dict<GString, i64> = Dict::new();
+
+    assert_eq!(dict.len(), 0);
+    assert!(dict.is_empty());
+}
+
+#[itest]
+fn dict_set_get() {
+    let mut dict: Dict<GString, i64> = Dict::new();
+    dict.set("one", 1);
+    dict.set("two", 2);
+
+    assert_eq!(dict.len(), 2);
+    assert_eq!(dict.get("one"), Some(1));
+    assert_eq!(dict.at("two"), 2);
+    assert_eq!(dict.get("three"), None);
+    assert!(dict.contains_key("one"));
+    assert!(!dict.contains_key("three"));
+
+    expect_panic("Dict::at() with missing key", || {
+        dict.at("three");
+    });
+}
+
+#[itest]
+fn dict_insert_remove() {
+    let mut dict: Dict<i64, GString> = Dict::new();
+
+    assert_eq!(dict.insert(1, "first"), None);
+    assert_eq!(dict.insert(1, "second"), Some(GString::from("first")));
+    assert_eq!(dict.remove(1), Some(GString::from("second")));
+    assert_eq!(dict.remove(1), None);
+    assert!(dict.is_empty());
+}
+
+#[itest]
+fn dict_from_iterator() {
+    let dict: Dict<GString, i64> = [("a", 1), ("b", 2)]
+        .into_iter()
+        .map(|(key, value)| (GString::from(key), value))
+        .collect();
+
+    let mut entries: Vec<(String, i64)> = dict
+        .iter_shared()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect();
+    entries.sort();
+
+    assert_eq!(entries, vec![("a".to_string(), 1), ("b".to_string(), 2)]);
+    assert_eq!(dict.keys_array().len(), 2);
+    assert_eq!(dict.values_array().len(), 2);
+}
+
+#[itest]
+fn dict_shares_reference() {
+    let mut dict: Dict<i64, i64> = Dict::new();
+    let clone = dict.clone();
+    let shallow = dict.duplicate_shallow();
+
+    dict.set(1, 10);
+
+    assert_eq!(clone.get(1), Some(10));
+    assert_eq!(shallow.get(1), None);
+}
+
+#[itest]
+fn dict_variant_roundtrip() {
+    let mut dict: Dict<GString, i64> = Dict::new();
+    dict.set("key", 42);
+
+    let variant = dict.to_variant();
+    let back = Dict::<GString, i64>::try_from_variant(&variant).expect("same types convert back");
+
+    assert_eq!(back, dict);
+    assert_eq!(back.at("key"), 42);
+}
+
+#[itest]
+fn dict_untyped_variant_kv() {
+    let untyped = vdict! { "key": 1, 2: true };
+
+    // Dict<Variant, Variant> is equivalent to an untyped dictionary.
+    let dict = Dict::<Variant, Variant>::try_from_untyped(untyped).expect("untyped dictionary");
+    assert_eq!(dict.at(&2.to_variant()), true.to_variant());
+}
+
+#[itest]
+fn dict_try_from_mismatched_untyped() {
+    let untyped = vdict! { "key": "not an int" };
+
+    let result = Dict::<GString, i64>::try_from_untyped(untyped);
+    result.expect_err("untyped dictionary with wrong value types should not convert");
+}
+
+#[itest]
+#[cfg(since_api = "4.4")]
+fn dict_element_types() {
+    use godot::builtin::VariantType;
+    use godot::meta::ElementType;
+
+    let dict: Dict<GString, Gd<RefCounted>> = Dict::new();
+
+    assert_eq!(
+        dict.key_element_type(),
+        ElementType::Builtin(VariantType::STRING)
+    );
+    assert!(matches!(dict.value_element_type(), ElementType::Class(_)));
+
+    // The untyped view keeps the runtime types.
+    let untyped: Dictionary = dict.into_untyped();
+    assert_eq!(
+        untyped.key_element_type(),
+        ElementType::Builtin(VariantType::STRING)
+    );
+}
+
+#[itest]
+#[cfg(since_api = "4.4")]
+fn dict_try_from_empty_untyped() {
+    // Even if empty, an untyped dictionary does not have the runtime types of Dict<GString, i64>.
+    let err = Dict::<GString, i64>::try_from_untyped(Dictionary::new())
+        .expect_err("untyped dictionary should not coerce to typed dictionary");
+
+    assert!(
+        err.to_string().contains("expected dictionary of type"),
+        "unexpected error: {err}"
+    );
+}
+
+#[itest]
+#[cfg(since_api = "4.4")]
+fn dict_from_gdscript() {
+    let gdscript = create_gdscript(
+        r#"
+extends RefCounted
+
+func typed_dict() -> Dictionary[String, int]:
+    return { "a": 1, "b": 2 }
+
+func other_typed_dict() -> Dictionary[int, String]:
+    return { 1: "a" }
+
+func sum(dict: Dictionary[String, int]) -> int:
+    var total := 0
+    for value in dict.values():
+        total += value
+    return total
+"#,
+    );
+
+    let mut object = RefCounted::new_gd();
+    object.set_script(&gdscript);
+
+    let dict = object.call("typed_dict", &[]).to::<Dict<GString, i64>>();
+    assert_eq!(dict.at("b"), 2);
+
+    let result = object
+        .call("other_typed_dict", &[])
+        .try_to::<Dict<GString, i64>>();
+    result.expect_err("Dictionary[int, String] should not convert to Dict<GString, i64>");
+
+    // Rust-created Dict is accepted by a GDScript typed parameter.
+    let mut dict: Dict<GString, i64> = Dict::new();
+    dict.set("x", 10);
+    dict.set("y", 20);
+    let total = object.call("sum", &[dict.to_variant()]).to::<i64>();
+    assert_eq!(total, 30);
+}
+
+#[itest]
+fn dict_func_return() {
+    let obj = DictTest::new_gd();
+    let dict = obj.bind().return_typed_dict(3);
+
+    assert_eq!(dict.len(), 3);
+    assert_eq!(dict.at(2), GString::from("2"));
+}
+
+#[itest]
+#[cfg(since_api = "4.4")]
+fn dict_property_hints() {
+    use godot::global::PropertyHint;
+
+    let obj = DictTest::new_gd();
+    let properties = obj.get_property_list();
+
+    for name in ["scores", "exported_scores"] {
+        let property = properties
+            .iter_shared()
+            .find(|p| p.get_or_nil("name") == name.to_variant())
+            .unwrap_or_else(|| panic!("property `{name}` not registered"));
+
+        assert_eq!(
+            property.get_or_nil("hint"),
+            PropertyHint::DICTIONARY_TYPE.ord().to_variant(),
+            "hint of `{name}`"
+        );
+        assert_eq!(
+            property.get_or_nil("hint_string"),
+            "String;int".to_variant(),
+            "hint_string of `{name}`"
+        );
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Class definitions
+
+#[derive(GodotClass, Debug)]
+#[class(init, base=RefCounted)]
+struct DictTest {
+    #[var]
+    scores: Dict<GString, i64>,
+
+    #[export]
+    exported_scores: Dict<GString, i64>,
+}
+
+#[godot_api]
+impl DictTest {
+    #[func]
+    fn return_typed_dict(&self, n: i64) -> Dict<i64, GString> {
+        (0..n).map(|i| (i, GString::from(&i.to_string()))).collect()
+    }
+}
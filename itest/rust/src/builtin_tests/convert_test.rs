@@ -5,15 +5,20 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::rc::Rc;
+
 use godot::builtin::{
-    array, vdict, Array, Dictionary, GString, NodePath, StringName, Variant, VariantArray, Vector2,
-    Vector2Axis,
+    array, varray, vdict, Array, Dict, Dictionary, GString, NodePath, StringName, Variant,
+    VariantArray, Vector2, Vector2Axis,
 };
-use godot::classes::{Node, Resource};
+use godot::classes::{Node, Object, RefCounted, Resource};
 use godot::meta;
 use godot::meta::error::ConvertError;
 use godot::meta::{AsArg, CowArg, FromGodot, GodotConvert, ToGodot};
-use godot::obj::{Gd, NewAlloc};
+use godot::obj::{Base, Gd, NewAlloc, NewGd};
+use godot::register::{godot_api, GodotClass};
 
 use crate::framework::itest;
 
@@ -327,6 +332,101 @@ fn slice_to_array() {
     assert!(to.is_err());
 }
 
+#[itest]
+fn map_to_dict() {
+    let from = HashMap::from([(GString::from("a"), 1), (GString::from("b"), 2)]);
+    let to = from.to_variant().to::<Dict<GString, i64>>();
+    assert_eq!(to.len(), 2);
+    assert_eq!(to.at("a"), 1);
+    assert_eq!(to.at("b"), 2);
+
+    let from = BTreeMap::from([(1, Vector2::ONE), (2, Vector2::ZERO)]);
+    let to = from.to_variant().to::<Dict<i64, Vector2>>();
+    assert_eq!(to.at(1), Vector2::ONE);
+    assert_eq!(to.at(2), Vector2::ZERO);
+}
+
+#[itest]
+fn dict_to_map() {
+    let from: Dict<GString, i64> = [(GString::from("a"), 1), (GString::from("b"), 2)]
+        .into_iter()
+        .collect();
+
+    let to = from.to_variant().to::<HashMap<GString, i64>>();
+    assert_eq!(
+        to,
+        HashMap::from([(GString::from("a"), 1), (GString::from("b"), 2)])
+    );
+
+    let to = from.to_variant().to::<BTreeMap<GString, i64>>();
+    assert_eq!(
+        to.into_iter().collect::<Vec<_>>(),
+        vec![(GString::from("a"), 1), (GString::from("b"), 2)]
+    );
+
+    // Invalid conversion.
+    let to = from.to_variant().try_to::<HashMap<GString, GString>>();
+    assert!(to.is_err());
+}
+
+#[itest]
+fn set_to_array() {
+    let from = BTreeSet::from([3, 1, 2]);
+    let to = from.to_variant().to::<Array<i32>>();
+    assert_eq!(to, array![1, 2, 3]);
+
+    let from = HashSet::from([GString::from("Hello")]);
+    let to = from.to_variant().to::<Array<GString>>();
+    assert_eq!(to, array!["Hello"]);
+}
+
+#[itest]
+fn array_to_set() {
+    // Duplicates are merged.
+    let from = array![3, 1, 3, 2];
+    let to = from.to_variant().to::<HashSet<i32>>();
+    assert_eq!(to, HashSet::from([1, 2, 3]));
+
+    let to = from.to_variant().to::<BTreeSet<i32>>();
+    assert_eq!(to, BTreeSet::from([1, 2, 3]));
+
+    // Invalid conversion.
+    let to = from.to_variant().try_to::<HashSet<GString>>();
+    assert!(to.is_err());
+}
+
+#[itest]
+fn tuple_roundtrip() {
+    let from = (1, GString::from("two"), Vector2::new(3.0, 4.0));
+    let to = from.to_variant().to::<VariantArray>();
+    assert_eq!(to, varray![1, "two", Vector2::new(3.0, 4.0)]);
+
+    let back = to.to_variant().to::<(i32, GString, Vector2)>();
+    assert_eq!(back, from);
+
+    let single = (true,).to_variant().to::<(bool,)>();
+    assert_eq!(single, (true,));
+}
+
+#[itest]
+fn tuple_invalid() {
+    let from = varray![1, 2];
+
+    // Wrong length.
+    let err = from
+        .to_variant()
+        .try_to::<(i32, i32, i32)>()
+        .expect_err("length mismatch");
+    assert!(
+        err.to_string().contains("length 2"),
+        "unexpected error: {err}"
+    );
+
+    // Wrong element type.
+    let to = from.to_variant().try_to::<(i32, GString)>();
+    assert!(to.is_err());
+}
+
 fn as_gstr_arg<'arg, T: 'arg + AsArg<GString>>(t: T) -> CowArg<'arg, GString> {
     t.into_arg()
 }
@@ -380,3 +480,84 @@ fn to_arg_helpers() {
 
     assert_eq!(names, array!["Hello", "Godot", "Godot"]);
 }
+
+#[itest]
+fn collections_in_func_signatures() {
+    let mut obj = CollectionFuncs::new_gd();
+
+    let scores = HashMap::from([(GString::from("a"), 1), (GString::from("b"), 2)]);
+    let inverted = obj
+        .call("invert", &[scores.to_variant()])
+        .to::<BTreeMap<i64, GString>>();
+    assert_eq!(
+        inverted,
+        BTreeMap::from([(1, GString::from("a")), (2, GString::from("b"))])
+    );
+
+    let unique = obj
+        .call("unique", &[array![3i64, 1, 3].to_variant()])
+        .to::<BTreeSet<i64>>();
+    assert_eq!(unique, BTreeSet::from([1, 3]));
+
+    let swapped = obj
+        .call("swap", &[(7, GString::from("seven")).to_variant()])
+        .to::<(GString, i64)>();
+    assert_eq!(swapped, (GString::from("seven"), 7));
+
+    // Godot-side type check of the typed dictionary parameter.
+    let untyped = vdict! { "a": "not an int" };
+    let result = obj.try_call("invert", &[untyped.to_variant()]);
+    assert!(result.is_err(), "mismatched dictionary must be rejected");
+}
+
+#[itest]
+fn collections_in_signal_signatures() {
+    let obj = CollectionFuncs::new_gd();
+    let received = Rc::new(RefCell::new(None));
+
+    let received_clone = received.clone();
+    obj.signals()
+        .totals_changed()
+        .connect(move |totals, range| {
+            *received_clone.borrow_mut() = Some((totals, range));
+        });
+
+    // Typed emit.
+    let totals = HashMap::from([(GString::from("x"), 10)]);
+    obj.signals().totals_changed().emit(totals.clone(), (1, 5));
+    assert_eq!(received.take(), Some((totals.clone(), (1, 5))));
+
+    // Untyped emit, converting from Variant on the receiving side.
+    obj.upcast::<Object>().emit_signal(
+        "totals_changed",
+        &[totals.to_variant(), (2, 6).to_variant()],
+    );
+    assert_eq!(received.take(), Some((totals, (2, 6))));
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct CollectionFuncs {
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl CollectionFuncs {
+    #[signal]
+    fn totals_changed(totals: HashMap<GString, i64>, range: (i64, i64));
+
+    #[func]
+    fn invert(&self, scores: HashMap<GString, i64>) -> BTreeMap<i64, GString> {
+        scores.into_iter().map(|(k, v)| (v, k)).collect()
+    }
+
+    #[func]
+    fn unique(&self, values: HashSet<i64>) -> BTreeSet<i64> {
+        values.into_iter().collect()
+    }
+
+    #[func]
+    fn swap(&self, pair: (i64, GString)) -> (GString, i64) {
+        (pair.1, pair.0)
+    }
+}
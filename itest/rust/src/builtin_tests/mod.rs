@@ -27,6 +27,7 @@ mod geometry {
 mod containers {
     mod array_test;
     mod callable_test;
+    mod dict_test;
     mod dictionary_test;
     mod packed_array_test;
     mod rid_test;
@@ -44,6 +45,7 @@ mod string {
 
 mod script {
     mod script_instance_tests;
+    mod script_language_test;
 }
 
 mod color_test;
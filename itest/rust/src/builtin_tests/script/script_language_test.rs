@@ -0,0 +1,317 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+
+use godot::builtin::{GString, StringName, Variant, VariantType};
+use godot::classes::{Object, Script, ScriptExtension, ScriptLanguage, ScriptLanguageExtension};
+use godot::global::Error;
+use godot::meta::error::CallErrorType;
+use godot::meta::{MethodInfo, PropertyInfo, ToGodot};
+use godot::obj::script::{ScriptInstance, SiMut};
+use godot::obj::script_language::{
+    register_script_language, script_language, unregister_script_language, CustomScript,
+    CustomScriptLanguage, ScriptDiagnostic,
+};
+use godot::obj::{Base, Gd, NewAlloc, WithBaseField};
+use godot::register::GodotClass;
+use godot::tools::{try_load, try_save};
+
+use crate::framework::itest;
+
+// Minimal language: each line declares an integer property, e.g. `health = 10`.
+
+#[derive(GodotClass)]
+#[class(init, base = ScriptLanguageExtension, tool)]
+struct ToyLanguage {
+    base: Base<ScriptLanguageExtension>,
+}
+
+impl CustomScriptLanguage for ToyLanguage {
+    type Script = ToyScript;
+
+    fn name(&self) -> GString {
+        GString::from("Toy")
+    }
+
+    fn file_extension(&self) -> GString {
+        GString::from("toy")
+    }
+
+    fn create_script(&self) -> Gd<ToyScript> {
+        let language = self.to_gd();
+        Gd::from_init_fn(|base| ToyScript {
+            base,
+            language,
+            source: GString::new(),
+            properties: Vec::new(),
+        })
+    }
+}
+
+#[derive(GodotClass)]
+#[class(no_init, base = ScriptExtension, tool)]
+struct ToyScript {
+    base: Base<ScriptExtension>,
+    language: Gd<ToyLanguage>,
+    source: GString,
+    properties: Vec<(StringName, i64)>,
+}
+
+impl CustomScript for ToyScript {
+    type Language = ToyLanguage;
+    type Instance = ToyInstance;
+
+    fn language(&self) -> Gd<ToyLanguage> {
+        self.language.clone()
+    }
+
+    fn source_code(&self) -> GString {
+        self.source.clone()
+    }
+
+    fn set_source_code(&mut self, code: GString) {
+        self.source = code;
+    }
+
+    fn reload(&mut self, _keep_state: bool) -> Result<(), ScriptDiagnostic> {
+        let source = self.source.to_string();
+        let mut properties = Vec::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let line_number = index as i32 + 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let Some((name, value)) = line.split_once('=') else {
+                return Err(ScriptDiagnostic::new(
+                    line_number,
+                    1,
+                    "expected `name = value`",
+                ));
+            };
+
+            let value = value
+                .trim()
+                .parse::<i64>()
+                .map_err(|e| ScriptDiagnostic::new(line_number, 1, e.to_string()))?;
+
+            properties.push((StringName::from(name.trim()), value));
+        }
+
+        self.properties = properties;
+        Ok(())
+    }
+
+    fn instantiate(&self, _for_object: &Gd<Object>) -> ToyInstance {
+        ToyInstance {
+            script: self.to_gd().upcast(),
+            language: self.language.clone().upcast(),
+            values: self.properties.iter().cloned().collect(),
+        }
+    }
+
+    fn properties(&self) -> Vec<PropertyInfo> {
+        self.properties
+            .iter()
+            .map(|(name, _)| PropertyInfo::new_var::<i64>(&name.to_string()))
+            .collect()
+    }
+
+    fn property_default(&self, name: &StringName) -> Option<Variant> {
+        self.properties
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.to_variant())
+    }
+}
+
+script_language! {
+    language: ToyLanguage,
+    script: ToyScript,
+    loader: ToyScriptLoader,
+    saver: ToyScriptSaver,
+}
+
+struct ToyInstance {
+    script: Gd<Script>,
+    language: Gd<ScriptLanguage>,
+    values: HashMap<StringName, i64>,
+}
+
+impl ScriptInstance for ToyInstance {
+    type Base = Object;
+
+    fn class_name(&self) -> GString {
+        GString::from("ToyScript")
+    }
+
+    fn set_property(mut this: SiMut<Self>, name: StringName, value: &Variant) -> bool {
+        match this.values.get_mut(&name) {
+            Some(slot) => {
+                *slot = value.to();
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn get_property(&self, name: StringName) -> Option<Variant> {
+        self.values.get(&name).map(|value| value.to_variant())
+    }
+
+    fn get_property_list(&self) -> Vec<PropertyInfo> {
+        self.values
+            .keys()
+            .map(|name| PropertyInfo::new_var::<i64>(&name.to_string()))
+            .collect()
+    }
+
+    fn get_method_list(&self) -> Vec<MethodInfo> {
+        vec![]
+    }
+
+    fn call(
+        _this: SiMut<Self>,
+        _method: StringName,
+        _args: &[&Variant],
+    ) -> Result<Variant, CallErrorType> {
+        Err(CallErrorType::InvalidMethod)
+    }
+
+    fn is_placeholder(&self) -> bool {
+        false
+    }
+
+    fn has_method(&self, _method: StringName) -> bool {
+        false
+    }
+
+    fn get_script(&self) -> &Gd<Script> {
+        &self.script
+    }
+
+    fn get_property_type(&self, name: StringName) -> VariantType {
+        if self.values.contains_key(&name) {
+            VariantType::INT
+        } else {
+            VariantType::NIL
+        }
+    }
+
+    fn to_string(&self) -> GString {
+        GString::from("ToyInstance")
+    }
+
+    fn get_property_state(&self) -> Vec<(StringName, Variant)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.clone(), value.to_variant()))
+            .collect()
+    }
+
+    fn get_language(&self) -> Gd<ScriptLanguage> {
+        self.language.clone()
+    }
+
+    fn on_refcount_decremented(&self) -> bool {
+        true
+    }
+
+    fn on_refcount_incremented(&self) {}
+
+    fn property_get_fallback(&self, _name: StringName) -> Option<Variant> {
+        None
+    }
+
+    fn property_set_fallback(_this: SiMut<Self>, _name: StringName, _value: &Variant) -> bool {
+        false
+    }
+
+    #[cfg(since_api = "4.3")]
+    fn get_method_argument_count(&self, _method: StringName) -> Option<u32> {
+        None
+    }
+}
+
+fn new_toy_script(language: &Gd<ToyLanguage>, source: &str) -> Gd<ToyScript> {
+    let mut script = language.bind().create_script();
+    script.bind_mut().set_source_code(GString::from(source));
+
+    let result = script.reload();
+    assert_eq!(result, Error::OK);
+
+    script
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Test Cases
+
+#[itest]
+fn script_language_instance_properties() {
+    let language = ToyLanguage::new_alloc();
+    register_script_language(language.clone());
+
+    let script = new_toy_script(&language, "health = 10\nspeed = 3");
+    assert!(script.can_instantiate());
+    assert_eq!(script.get_script_property_list().len(), 2);
+    assert_eq!(script.get_property_default_value("health"), 10.to_variant());
+
+    let mut object = Object::new_alloc();
+    object.set_script(&script);
+
+    assert_eq!(object.get("health"), 10.to_variant());
+    object.set("speed", &7.to_variant());
+    assert_eq!(object.get("speed"), 7.to_variant());
+
+    object.free();
+    drop(script);
+    unregister_script_language::<ToyLanguage>();
+}
+
+#[itest]
+fn script_language_parse_error() {
+    let language = ToyLanguage::new_alloc();
+
+    let mut script = language.bind().create_script();
+    script
+        .bind_mut()
+        .set_source_code(GString::from("health = ten"));
+
+    let result = script.bind_mut().reload(false);
+    let err = result.expect_err("invalid integer should fail to compile");
+    assert_eq!(err.line, 1);
+
+    drop(script);
+    language.free();
+}
+
+#[itest]
+fn script_language_save_load() {
+    const FILE_NAME: &str = "script_language_test.toy";
+    let path = format!("res://{FILE_NAME}");
+
+    let language = ToyLanguage::new_alloc();
+    register_script_language(language.clone());
+
+    let script = new_toy_script(&language, "level = 4");
+    try_save(&script, &path).expect("saving script through generated saver");
+    drop(script);
+
+    let loaded = try_load::<ToyScript>(&path).expect("loading script through generated loader");
+    assert_eq!(loaded.bind().source_code(), GString::from("level = 4"));
+    assert_eq!(loaded.get_property_default_value("level"), 4.to_variant());
+
+    drop(loaded);
+    unregister_script_language::<ToyLanguage>();
+
+    let file_path =
+        std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/")).join(FILE_NAME);
+    std::fs::remove_file(&file_path)
+        .unwrap_or_else(|_| panic!("couldn't remove test file: {}", file_path.display()));
+}
@@ -5,7 +5,14 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::builtin::{array, Array, Color, ColorHsv, GString, NodePath, StringName, Vector2i};
+use godot::builtin::{
+    array, varray, vdict, Array, Color, ColorHsv, GString, NodePath, PackedByteArray,
+    PackedInt32Array, PackedStringArray, PackedVector2Array, Rid, StringName, Variant, Vector2,
+    Vector2i,
+};
+use godot::classes::RefCounted;
+use godot::meta::ToGodot;
+use godot::obj::NewGd;
 use serde::{Deserialize, Serialize};
 
 use crate::framework::itest;
@@ -93,3 +100,100 @@ fn color_hsv_serde() {
     let expected_json = r#"{"h":0.0,"s":0.0,"v":0.0,"a":1.0}"#;
     serde_roundtrip(&color, expected_json);
 }
+
+#[itest]
+fn serde_packed_array() {
+    let value = PackedInt32Array::from(&[1, 2, 3]);
+    serde_roundtrip(&value, "[1,2,3]");
+
+    let value = PackedStringArray::from(&[GString::from("Godot"), GString::from("Rust")]);
+    serde_roundtrip(&value, r#"["Godot","Rust"]"#);
+
+    let value = PackedVector2Array::from(&[Vector2::new(1.0, 2.0)]);
+    serde_roundtrip(&value, r#"[{"x":1.0,"y":2.0}]"#);
+
+    serde_roundtrip(&PackedByteArray::new(), "[]");
+}
+
+#[itest]
+fn serde_variant_scalars() {
+    serde_roundtrip(&Variant::nil(), r#""Nil""#);
+    serde_roundtrip(&true.to_variant(), r#"{"Bool":true}"#);
+    serde_roundtrip(&(-7).to_variant(), r#"{"Int":-7}"#);
+    serde_roundtrip(&2.5.to_variant(), r#"{"Float":2.5}"#);
+    serde_roundtrip(&"hello".to_variant(), r#"{"String":"hello"}"#);
+    serde_roundtrip(
+        &StringName::from("name").to_variant(),
+        r#"{"StringName":"name"}"#,
+    );
+}
+
+#[itest]
+fn serde_variant_builtins() {
+    serde_roundtrip(
+        &Vector2i::new(1, 2).to_variant(),
+        r#"{"Vector2i":{"x":1,"y":2}}"#,
+    );
+    serde_roundtrip(
+        &Color::from_rgba(0.0, 0.5, 1.0, 1.0).to_variant(),
+        r#"{"Color":{"r":0.0,"g":0.5,"b":1.0,"a":1.0}}"#,
+    );
+    serde_roundtrip(
+        &PackedInt32Array::from(&[4, 5]).to_variant(),
+        r#"{"PackedInt32Array":[4,5]}"#,
+    );
+}
+
+#[itest]
+fn serde_variant_rid() {
+    // RIDs are opaque; only the ID is preserved.
+    serde_roundtrip(&Rid::new(42).to_variant(), r#"{"Rid":42}"#);
+}
+
+#[itest]
+fn serde_variant_nested() {
+    let value = varray![1, "two", varray![3.5]].to_variant();
+    let expected_json = r#"{"Array":[{"Int":1},{"String":"two"},{"Array":[{"Float":3.5}]}]}"#;
+
+    serde_roundtrip(&value, expected_json);
+}
+
+#[itest]
+fn serde_dictionary() {
+    let value = vdict! {
+        "key": 1,
+        2: Vector2i::new(3, 4),
+    };
+    let expected_json = r#"[[{"String":"key"},{"Int":1}],[{"Int":2},{"Vector2i":{"x":3,"y":4}}]]"#;
+
+    serde_roundtrip(&value, expected_json);
+    serde_roundtrip(
+        &value.to_variant(),
+        &format!(r#"{{"Dictionary":{expected_json}}}"#),
+    );
+}
+
+#[itest]
+fn serde_variant_object_rejected() {
+    let object = RefCounted::new_gd();
+
+    let err =
+        serde_json::to_string(&object.to_variant()).expect_err("objects are not serializable");
+    assert!(
+        err.to_string().contains("object"),
+        "error should mention object type: {err}"
+    );
+
+    let err = serde_json::to_string(&varray![1, object])
+        .expect_err("nested objects are not serializable");
+    assert!(err.to_string().contains("object"), "{err}");
+}
+
+#[itest]
+fn serde_variant_invalid_tag() {
+    let result = serde_json::from_str::<Variant>(r#"{"NotAType":1}"#);
+    assert!(
+        result.is_err(),
+        "unknown type tag should fail to deserialize"
+    );
+}
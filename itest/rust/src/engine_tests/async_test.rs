@@ -6,12 +6,15 @@
  */
 
 use std::ops::Deref;
+use std::time::Duration;
 
-use godot::builtin::{array, vslice, Array, Callable, Signal};
-use godot::classes::{Object, RefCounted};
-use godot::obj::{Base, Gd, NewAlloc, NewGd};
+use godot::builtin::{array, vslice, Array, Callable, GString, Signal};
+use godot::classes::{Engine, Object, RefCounted, Time};
+use godot::obj::{Base, Gd, NewAlloc, NewGd, Singleton};
 use godot::prelude::{godot_api, GodotClass};
-use godot::task::{self, create_test_signal_future_resolver, SignalFuture, TaskHandle};
+use godot::task::{
+    self, create_test_signal_future_resolver, Either, SignalFuture, TaskHandle, TimeoutError,
+};
 
 use crate::framework::{expect_async_panic, itest, TestContext};
 
@@ -204,6 +207,204 @@ fn signal_future_send_arg_no_panic() -> TaskHandle {
     handle
 }
 
+#[cfg(feature = "experimental-threads")]
+#[itest(async)]
+fn spawn_blocking_resolves_on_main_thread() -> TaskHandle {
+    let main_thread = std::thread::current().id();
+
+    task::spawn(async move {
+        let (sum, worker_thread) = task::spawn_blocking(|| {
+            let sum: i64 = (1..=100).sum();
+            (sum, std::thread::current().id())
+        })
+        .await;
+
+        assert_eq!(sum, 5050);
+        assert_ne!(worker_thread, main_thread);
+        assert_eq!(std::thread::current().id(), main_thread);
+    })
+}
+
+#[cfg(feature = "experimental-threads")]
+#[itest(async)]
+fn spawn_blocking_panic() -> TaskHandle {
+    task::spawn(expect_async_panic(
+        "panic in spawn_blocking() work should propagate to the awaiting task",
+        async {
+            task::spawn_blocking(|| -> i32 { panic!("work panicked") }).await;
+        },
+    ))
+}
+
+#[cfg(feature = "experimental-threads")]
+#[itest(async)]
+fn spawn_on_pool_completes() -> TaskHandle {
+    let main_thread = std::thread::current().id();
+
+    task::spawn_on_pool(
+        || 21 * 2,
+        move |value| {
+            assert_eq!(value, 42);
+            assert_eq!(std::thread::current().id(), main_thread);
+        },
+    )
+}
+
+#[cfg(feature = "experimental-threads")]
+#[itest]
+fn spawn_on_pool_cancel() {
+    let handle = task::spawn_on_pool(|| 42, |_| unreachable!("canceled task must not complete"));
+
+    assert!(handle.is_pending());
+    handle.cancel();
+}
+
+#[itest(async)]
+fn async_sleep() -> TaskHandle {
+    let start = Time::singleton().get_ticks_msec();
+
+    task::spawn(async move {
+        task::sleep(Duration::from_millis(50)).await;
+
+        let elapsed = Time::singleton().get_ticks_msec() - start;
+        assert!(elapsed >= 50, "sleep() returned after {elapsed}ms");
+    })
+}
+
+#[itest(async)]
+fn async_next_frame() -> TaskHandle {
+    task::spawn(async {
+        let process_frames = Engine::singleton().get_process_frames();
+        task::next_frame().await;
+        assert!(Engine::singleton().get_process_frames() > process_frames);
+
+        let physics_frames = Engine::singleton().get_physics_frames();
+        task::next_physics_frame().await;
+        assert!(Engine::singleton().get_physics_frames() > physics_frames);
+    })
+}
+
+#[itest(async)]
+fn async_yield_now() -> TaskHandle {
+    task::spawn(async {
+        for _ in 0..3 {
+            task::yield_now().await;
+        }
+    })
+}
+
+#[itest(async)]
+fn async_join() -> TaskHandle {
+    task::spawn(async {
+        let (left, right) = task::join(
+            async {
+                task::next_frame().await;
+                1
+            },
+            async {
+                task::yield_now().await;
+                "two"
+            },
+        )
+        .await;
+
+        assert_eq!(left, 1);
+        assert_eq!(right, "two");
+    })
+}
+
+#[itest(async)]
+fn async_select() -> TaskHandle {
+    task::spawn(async {
+        let result = task::select(task::sleep(Duration::from_secs(10)), task::yield_now()).await;
+
+        assert_eq!(result, Either::Right(()));
+    })
+}
+
+#[itest(async)]
+fn async_timeout() -> TaskHandle {
+    task::spawn(async {
+        let result = task::timeout(Duration::from_millis(10), std::future::pending::<()>()).await;
+        assert_eq!(result, Err(TimeoutError));
+
+        let result = task::timeout(Duration::from_secs(10), async {
+            task::yield_now().await;
+            5
+        })
+        .await;
+        assert_eq!(result, Ok(5));
+    })
+}
+
+#[itest]
+fn cancel_async_sleep() {
+    let handle = task::spawn(async {
+        task::sleep(Duration::from_millis(10)).await;
+
+        unreachable!();
+    });
+
+    assert!(handle.is_pending());
+    handle.cancel();
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct AsyncFuncObj {
+    calls: i64,
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl AsyncFuncObj {
+    #[func(gd_self)]
+    async fn double_next_frame(mut this: Gd<Self>, value: i64) -> i64 {
+        task::next_frame().await;
+        this.bind_mut().calls += 1;
+
+        value * 2
+    }
+
+    // Completes synchronously; `completed` must still be observable by the caller.
+    #[func]
+    async fn echo_immediately(value: GString) -> GString {
+        value
+    }
+}
+
+#[itest(async)]
+fn async_func_gd_self() -> TaskHandle {
+    let mut obj = AsyncFuncObj::new_gd();
+    let handle = obj
+        .call("double_next_frame", vslice![21])
+        .to::<Gd<RefCounted>>();
+
+    task::spawn(async move {
+        let completed = Signal::from_object_signal(&handle, "completed");
+        let (result,) = completed.to_future::<(i64,)>().await;
+
+        assert_eq!(result, 42);
+        assert_eq!(obj.bind().calls, 1);
+    })
+}
+
+#[itest(async)]
+fn async_func_static_immediate() -> TaskHandle {
+    let mut obj = AsyncFuncObj::new_gd();
+    let handle = obj
+        .call("echo_immediately", vslice!["hello"])
+        .to::<Gd<RefCounted>>();
+
+    // Connect only after the call returned, like GDScript's `await obj.echo_immediately("hello").completed`.
+    task::spawn(async move {
+        let completed = Signal::from_object_signal(&handle, "completed");
+        let (result,) = completed.to_future::<(GString,)>().await;
+
+        assert_eq!(result, GString::from("hello"));
+    })
+}
+
 // Test that two callables created from the same future resolver (but cloned) are equal, while they are not equal to an unrelated
 // callable.
 #[itest]
@@ -254,3 +455,62 @@ fn async_typed_signal_with_array() -> TaskHandle {
 
     task_handle
 }
+
+#[itest(async)]
+fn async_signal_stream_buffers() -> TaskHandle {
+    let object = AsyncRefCounted::new_gd();
+    let mut stream = object.signals().custom_signal().to_stream();
+
+    // Emissions before the first poll are buffered.
+    object.signals().custom_signal().emit(1);
+    object.signals().custom_signal().emit(2);
+    assert_eq!(stream.buffered_len(), 2);
+
+    task::spawn(async move {
+        assert_eq!(stream.next().await, Some((1,)));
+        assert_eq!(stream.next().await, Some((2,)));
+
+        let emitter = object.clone();
+        task::spawn(async move {
+            task::yield_now().await;
+            emitter.signals().custom_signal().emit(3);
+        });
+
+        assert_eq!(stream.next().await, Some((3,)));
+
+        // Stream doesn't keep the RefCounted alive; releasing the last reference ends it.
+        drop(object);
+        assert_eq!(stream.next().await, None);
+    })
+}
+
+#[itest(async)]
+fn async_signal_stream_ends_on_free() -> TaskHandle {
+    let mut obj = Object::new_alloc();
+    obj.add_user_signal("custom_signal");
+
+    let signal = Signal::from_object_signal(&obj, "custom_signal");
+    let mut stream = signal.to_stream::<(u32,)>();
+
+    obj.emit_signal("custom_signal", vslice![5u32]);
+    obj.call_deferred("free", &[]);
+
+    task::spawn(async move {
+        // Buffered emissions are still delivered after the object is freed.
+        assert_eq!(stream.next().await, Some((5,)));
+        assert_eq!(stream.next().await, None);
+        assert_eq!(stream.next().await, None);
+    })
+}
+
+#[itest]
+fn signal_stream_disconnects_on_drop() {
+    let object = AsyncRefCounted::new_gd();
+    let signal = object.signals().custom_signal().to_untyped();
+
+    let stream = signal.to_stream::<(u32,)>();
+    assert_eq!(signal.connections().len(), 1);
+
+    drop(stream);
+    assert_eq!(signal.connections().len(), 0);
+}
@@ -9,7 +9,7 @@ use std::io::{BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
 
 use godot::builtin::GString;
 use godot::classes::file_access::ModeFlags;
-use godot::tools::GFile;
+use godot::tools::{GDir, GFile};
 
 use crate::framework::itest;
 
@@ -161,3 +161,122 @@ fn bufread_trait_works() {
     drop(file);
     remove_test_file();
 }
+
+#[itest]
+fn buffered_read_mixes_with_typed_read_and_seek() {
+    let mut file = GFile::open(TEST_FULL_PATH, ModeFlags::WRITE_READ).unwrap();
+
+    let integers: Vec<u8> = (0..=200).collect();
+    file.write_all(&integers)
+        .expect("couldn't write integer vector");
+    file.rewind().unwrap();
+
+    let mut first = [0; 3];
+    file.read_exact(&mut first).expect("couldn't read bytes");
+    assert_eq!(first, [0, 1, 2]);
+    assert_eq!(file.position(), 3);
+
+    // The whole file has been buffered, but typed reads continue at the logical position.
+    assert_eq!(file.read_u8().unwrap(), 3);
+
+    file.read_exact(&mut first).expect("couldn't read bytes");
+    file.seek(SeekFrom::Current(-2))
+        .expect("couldn't seek from current");
+    assert_eq!(file.stream_position().unwrap(), 5);
+    assert_eq!(file.read_u8().unwrap(), 5);
+
+    let rest = file
+        .read_all_bytes()
+        .expect("couldn't read remaining bytes");
+    assert_eq!(rest, integers[6..]);
+    assert!(file.fill_buf().unwrap().is_empty());
+
+    drop(file);
+    remove_test_file();
+}
+
+#[itest]
+fn read_all_bytes_works() {
+    let mut file = GFile::open(TEST_FULL_PATH, ModeFlags::WRITE).unwrap();
+
+    // Larger than the internal read buffer.
+    let bytes: Vec<u8> = (0..10_000).map(|i| (i % 251) as u8).collect();
+    file.write_all(&bytes).expect("couldn't write bytes");
+    drop(file);
+
+    let mut file = GFile::open(TEST_FULL_PATH, ModeFlags::READ).unwrap();
+    assert_eq!(file.read_all_bytes().unwrap(), bytes);
+    assert!(file.read_all_bytes().unwrap().is_empty());
+
+    let mut file = GFile::open(TEST_FULL_PATH, ModeFlags::READ).unwrap();
+    let mut line = Vec::new();
+    file.read_until(250, &mut line).unwrap();
+    assert_eq!(line, bytes[..=250]);
+
+    let mut rest = Vec::new();
+    file.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, bytes[251..]);
+
+    drop(file);
+    remove_test_file();
+}
+
+#[itest]
+fn gdir_lists_entries() {
+    let test_dir = std::path::Path::new(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../godot/",
+        "dir_tests"
+    ));
+    std::fs::create_dir_all(test_dir.join("nested")).unwrap();
+    std::fs::write(test_dir.join("first.txt"), "1").unwrap();
+    std::fs::write(test_dir.join("second.txt"), "2").unwrap();
+
+    let mut dir = GDir::open("res://dir_tests").expect("couldn't open directory");
+    assert_eq!(dir.path(), GString::from("res://dir_tests"));
+    assert!(dir.file_exists("first.txt"));
+    assert!(dir.dir_exists("nested"));
+
+    let mut entries = dir
+        .entries()
+        .expect("couldn't list directory")
+        .map(|entry| {
+            (
+                entry.name().to_string(),
+                entry.path().to_string(),
+                entry.is_dir(),
+            )
+        })
+        .collect::<Vec<_>>();
+    entries.sort();
+
+    assert_eq!(
+        entries,
+        [
+            (
+                "first.txt".to_string(),
+                "res://dir_tests/first.txt".to_string(),
+                false
+            ),
+            (
+                "nested".to_string(),
+                "res://dir_tests/nested".to_string(),
+                true
+            ),
+            (
+                "second.txt".to_string(),
+                "res://dir_tests/second.txt".to_string(),
+                false
+            ),
+        ]
+    );
+
+    // Entries can be listed again after the previous iterator is dropped.
+    assert_eq!(dir.entries().unwrap().count(), 3);
+    drop(dir);
+
+    assert!(GDir::open("res://no_such_dir").is_err());
+
+    std::fs::remove_dir_all(test_dir)
+        .unwrap_or_else(|_| panic!("couldn't remove test dir: {}", test_dir.display()));
+}
@@ -0,0 +1,150 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Needs the `Logger` class (Godot 4.5+), which is not part of minimal codegen.
+#![cfg(all(
+    any(feature = "log", feature = "tracing"),
+    feature = "codegen-full",
+    since_api = "4.5"
+))]
+
+use godot::builtin::{Array, GString};
+use godot::classes::{logger, ILogger, Logger, Os, ScriptBacktrace};
+use godot::obj::{Base, Gd, NewGd, Singleton};
+use godot::register::{godot_api, GodotClass};
+
+use crate::framework::itest;
+
+#[derive(Clone, Debug)]
+struct LoggedError {
+    function: String,
+    file: String,
+    line: i32,
+    text: String,
+    error_type: i32,
+}
+
+/// Records errors and warnings that Godot sends to its loggers.
+#[derive(GodotClass)]
+#[class(init, base=Logger)]
+struct ErrorCollector {
+    errors: Vec<LoggedError>,
+    base: Base<Logger>,
+}
+
+#[godot_api]
+impl ILogger for ErrorCollector {
+    #[allow(clippy::too_many_arguments)]
+    fn log_error(
+        &mut self,
+        function: GString,
+        file: GString,
+        line: i32,
+        code: GString,
+        rationale: GString,
+        _editor_notify: bool,
+        error_type: i32,
+        _script_backtraces: Array<Gd<ScriptBacktrace>>,
+    ) {
+        self.errors.push(LoggedError {
+            function: function.to_string(),
+            file: file.to_string(),
+            line,
+            text: format!("{code} {rationale}"),
+            error_type,
+        });
+    }
+}
+
+fn collect_errors(code: impl FnOnce()) -> Vec<LoggedError> {
+    let collector = ErrorCollector::new_gd();
+
+    Os::singleton().add_logger(&collector);
+    code();
+    Os::singleton().remove_logger(&collector);
+
+    let errors = collector.bind().errors.clone();
+    errors
+}
+
+fn find_error<'a>(errors: &'a [LoggedError], text: &str) -> &'a LoggedError {
+    errors
+        .iter()
+        .find(|error| error.text.contains(text))
+        .unwrap_or_else(|| panic!("no error containing {text:?} in {errors:#?}"))
+}
+
+#[cfg(feature = "log")]
+#[itest]
+fn log_records_reach_godot_with_location() {
+    let mut error_line = 0;
+    let mut warn_line = 0;
+
+    let errors = collect_errors(|| {
+        error_line = line!() + 1;
+        log::error!("log bridge error {}", 42);
+        warn_line = line!() + 1;
+        log::warn!("log bridge warning");
+    });
+
+    let error = find_error(&errors, "log bridge error 42");
+    assert_eq!(error.error_type, logger::ErrorType::ERROR.ord());
+    assert_eq!(error.file, file!());
+    assert_eq!(error.line, error_line as i32);
+    assert_eq!(error.function, module_path!());
+
+    let warning = find_error(&errors, "log bridge warning");
+    assert_eq!(warning.error_type, logger::ErrorType::WARNING.ord());
+    assert_eq!(warning.file, file!());
+    assert_eq!(warning.line, warn_line as i32);
+}
+
+#[cfg(feature = "log")]
+#[itest]
+fn log_records_info_is_not_error() {
+    let errors = collect_errors(|| {
+        log::info!("log bridge info");
+    });
+
+    assert!(
+        !errors.iter().any(|error| error.text.contains("log bridge")),
+        "info must not be reported as error: {errors:#?}"
+    );
+}
+
+#[cfg(feature = "tracing")]
+#[itest]
+fn tracing_events_reach_godot_with_location() {
+    let mut error_line = 0;
+    let mut warn_line = 0;
+
+    let errors = collect_errors(|| {
+        error_line = line!() + 1;
+        tracing::error!("tracing bridge error {}", 42);
+        warn_line = line!() + 1;
+        tracing::warn!(peer = 7, "tracing bridge warning");
+        tracing::info!("tracing bridge info");
+    });
+
+    let error = find_error(&errors, "tracing bridge error 42");
+    assert_eq!(error.error_type, logger::ErrorType::ERROR.ord());
+    assert_eq!(error.file, file!());
+    assert_eq!(error.line, error_line as i32);
+    assert_eq!(error.function, module_path!());
+
+    // Fields other than the message are appended.
+    let warning = find_error(&errors, "tracing bridge warning peer=7");
+    assert_eq!(warning.error_type, logger::ErrorType::WARNING.ord());
+    assert_eq!(warning.line, warn_line as i32);
+
+    assert!(
+        !errors
+            .iter()
+            .any(|error| error.text.contains("tracing bridge info")),
+        "info must not be reported as error: {errors:#?}"
+    );
+}
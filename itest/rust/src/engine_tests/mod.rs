@@ -11,11 +11,13 @@ mod codegen_enums_test;
 mod codegen_test;
 mod engine_enum_test;
 mod gfile_test;
+mod log_bridge_test;
 mod match_class_test;
 mod native_st_niche_audio_test;
 mod native_st_niche_pointer_test;
 mod native_structures_test;
 mod node_test;
 mod save_load_test;
+mod scene_builder_test;
 mod translate_test;
 mod utilities_test;
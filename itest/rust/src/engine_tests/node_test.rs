@@ -8,8 +8,8 @@
 use std::str::FromStr;
 
 use godot::builtin::{vslice, NodePath};
-use godot::classes::{Node, Node3D};
-use godot::obj::NewAlloc;
+use godot::classes::{Node, Node2D, Node3D};
+use godot::obj::{Gd, Inherits, NewAlloc};
 
 use crate::framework::{itest, TestContext};
 
@@ -59,6 +59,116 @@ fn node_path_from_str(ctx: &TestContext) {
     );
 }
 
+/// Builds the tree:
+/// ```text
+/// root (Node)
+/// ├─ a (Node3D)
+/// │  ├─ a1 (Node2D)
+/// │  └─ a2 (Node3D)
+/// └─ b (Node2D)
+///    └─ b1 (Node3D)
+/// ```
+fn make_traversal_tree() -> Gd<Node> {
+    fn add(parent: &mut Gd<Node>, mut child: Gd<Node>, name: &str) -> Gd<Node> {
+        child.set_name(name);
+        parent.add_child(&child);
+        child
+    }
+
+    let mut root = Node::new_alloc();
+    root.set_name("root");
+
+    let mut a = add(&mut root, Node3D::new_alloc().upcast(), "a");
+    add(&mut a, Node2D::new_alloc().upcast(), "a1");
+    add(&mut a, Node3D::new_alloc().upcast(), "a2");
+
+    let mut b = add(&mut root, Node2D::new_alloc().upcast(), "b");
+    add(&mut b, Node3D::new_alloc().upcast(), "b1");
+
+    root
+}
+
+fn names<T: Inherits<Node>>(nodes: impl Iterator<Item = Gd<T>>) -> Vec<String> {
+    nodes
+        .map(|node| node.upcast::<Node>().get_name().to_string())
+        .collect()
+}
+
+#[itest]
+fn node_children_of() {
+    let root = make_traversal_tree();
+
+    assert_eq!(names(root.children_of::<Node3D>()), ["a"]);
+    assert_eq!(names(root.children_of::<Node2D>()), ["b"]);
+    assert_eq!(names(root.children_of::<Node>()), ["a", "b"]);
+
+    root.free();
+}
+
+#[itest]
+fn node_descendants_of() {
+    let root = make_traversal_tree();
+
+    assert_eq!(names(root.descendants_of::<Node3D>()), ["a", "a2", "b1"]);
+    assert_eq!(
+        names(root.descendants_of::<Node>()),
+        ["a", "a1", "a2", "b", "b1"]
+    );
+    assert_eq!(
+        names(root.descendants_breadth_first_of::<Node>()),
+        ["a", "b", "a1", "a2", "b1"]
+    );
+    assert_eq!(
+        names(root.descendants_breadth_first_of::<Node2D>()),
+        ["b", "a1"]
+    );
+
+    root.free();
+}
+
+#[itest]
+fn node_ancestor_and_find_child() {
+    let root = make_traversal_tree();
+
+    let b1 = root.get_node_as::<Node3D>("b/b1");
+    assert_eq!(
+        b1.ancestor_of::<Node2D>()
+            .map(|node| node.get_name().to_string()),
+        Some("b".to_string())
+    );
+    assert_eq!(b1.ancestor_of::<Node>().unwrap().get_name(), "b".into());
+    assert!(b1.ancestor_of::<Node3D>().is_none());
+
+    let found = root.find_child_as::<Node3D>("?2").expect("a2 exists");
+    assert_eq!(found.get_name(), "a2".into());
+    assert_eq!(
+        root.find_child_as::<Node3D>("b*")
+            .map(|node| node.get_name()),
+        Some("b1".into())
+    );
+    assert!(root.find_child_as::<Node2D>("a2").is_none());
+
+    root.free();
+}
+
+#[itest]
+fn scene_tree_nodes_in_group_as(ctx: &TestContext) {
+    let mut root = make_traversal_tree();
+    for mut node in root.descendants_of::<Node>() {
+        node.add_to_group("traversal_group");
+    }
+
+    let mut scene = ctx.scene_tree.clone();
+    scene.add_child(&root);
+
+    let mut tree = scene.get_tree().unwrap();
+    let nodes = tree.nodes_in_group_as::<Node3D>("traversal_group");
+    assert_eq!(names(nodes.into_iter()), ["a", "a2", "b1"]);
+
+    scene.remove_child(&root);
+    root.free();
+}
+
 // Regression test against call_group() crashing, see https://github.com/godot-rust/gdext/pull/167.
 // https://github.com/godot-rust/gdext/commit/207c4e72ac0c24cfb83bab16f856dd09ebc8671c
 #[itest]
@@ -5,15 +5,20 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::io::Read;
+
+use godot::builtin::GString;
 use godot::classes;
 use godot::classes::notify::NodeNotification;
+use godot::global::Error;
 use godot::obj::{Base, Gd, NewAlloc, NewGd, OnReady};
 use godot::register::GodotClass;
-use godot::tools::{load, save, try_load, try_save};
+use godot::tools::resource_format::{resource_format, ResourceFormat};
+use godot::tools::{load, save, try_load, try_save, GFile};
 
-use crate::framework::itest;
+use crate::framework::{itest, suppress_godot_print};
 
-fn remove_test_file(file_name: &str) {
+pub(crate) fn remove_test_file(file_name: &str) {
     let godot_path = std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/"));
     let file_path = godot_path.join(file_name);
     std::fs::remove_file(&file_path)
@@ -99,3 +104,142 @@ fn load_with_onready() {
 
     remove_test_file(RESOURCE_NAME);
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Custom resource formats
+
+#[derive(GodotClass)]
+#[class(base=Resource, init)]
+struct HighScore {
+    #[var]
+    player: GString,
+    #[var]
+    score: u32,
+}
+
+/// Text format `<player>:<score>`, using the default whole-file `load`/`save`.
+struct HighScoreFormat;
+
+impl ResourceFormat for HighScoreFormat {
+    type Resource = HighScore;
+
+    fn extensions() -> &'static [&'static str] {
+        &["highscore"]
+    }
+
+    fn load(bytes: &[u8]) -> Result<Gd<HighScore>, Error> {
+        let text = std::str::from_utf8(bytes).map_err(|_| Error::ERR_FILE_CORRUPT)?;
+        let (player, score) = text.rsplit_once(':').ok_or(Error::ERR_PARSE_ERROR)?;
+        let score = score.parse().map_err(|_| Error::ERR_PARSE_ERROR)?;
+
+        Ok(Gd::from_object(HighScore {
+            player: player.into(),
+            score,
+        }))
+    }
+
+    fn save(high_score: &HighScore) -> Result<Vec<u8>, Error> {
+        Ok(format!("{}:{}", high_score.player, high_score.score).into_bytes())
+    }
+}
+
+resource_format!(format: HighScoreFormat, loader: HighScoreLoader, saver: HighScoreSaver);
+
+/// Binary format that streams fields directly from/to the file.
+struct HighScoreBinaryFormat;
+
+impl ResourceFormat for HighScoreBinaryFormat {
+    type Resource = HighScore;
+
+    fn extensions() -> &'static [&'static str] {
+        &["highscore_bin"]
+    }
+
+    fn load(_bytes: &[u8]) -> Result<Gd<HighScore>, Error> {
+        unreachable!("load_from_file() is overridden")
+    }
+
+    fn save(_high_score: &HighScore) -> Result<Vec<u8>, Error> {
+        unreachable!("save_to_file() is overridden")
+    }
+
+    fn load_from_file(file: &mut GFile) -> Result<Gd<HighScore>, Error> {
+        let player = file
+            .read_pascal_string()
+            .map_err(|_| Error::ERR_FILE_CORRUPT)?;
+        let score = file.read_u32().map_err(|_| Error::ERR_FILE_CORRUPT)?;
+
+        Ok(Gd::from_object(HighScore { player, score }))
+    }
+
+    fn save_to_file(high_score: &HighScore, file: &mut GFile) -> Result<(), Error> {
+        file.write_pascal_string(&high_score.player)
+            .and_then(|_| file.write_u32(high_score.score))
+            .map_err(|_| Error::ERR_FILE_CANT_WRITE)
+    }
+}
+
+resource_format!(
+    format: HighScoreBinaryFormat,
+    loader: HighScoreBinaryLoader,
+    saver: HighScoreBinarySaver,
+);
+
+fn high_score(player: &str, score: u32) -> Gd<HighScore> {
+    let mut resource = HighScore::new_gd();
+    {
+        let mut guard = resource.bind_mut();
+        guard.set_player(player.into());
+        guard.set_score(score);
+    }
+    resource
+}
+
+#[itest]
+fn resource_format_round_trip() {
+    let file_name = "test_score.highscore";
+    let res_path = format!("res://{file_name}");
+
+    let resource = high_score("Ferris", 9001);
+    try_save(&resource, &res_path).expect("save with custom format");
+
+    let loaded = try_load::<HighScore>(&res_path).expect("load with custom format");
+    assert_eq!(loaded.bind().get_player(), GString::from("Ferris"));
+    assert_eq!(loaded.bind().get_score(), 9001);
+
+    let mut content = String::new();
+    GFile::open(&res_path, classes::file_access::ModeFlags::READ)
+        .and_then(|mut file| file.read_to_string(&mut content))
+        .expect("read saved file");
+    assert_eq!(content, "Ferris:9001");
+
+    drop(loaded);
+    remove_test_file(file_name);
+}
+
+#[itest]
+fn resource_format_gfile_streaming() {
+    let file_name = "test_score.highscore_bin";
+    let res_path = format!("res://{file_name}");
+
+    let resource = high_score("Gödöt", 42);
+    try_save(&resource, &res_path).expect("save with streaming format");
+
+    let loaded = try_load::<HighScore>(&res_path).expect("load with streaming format");
+    assert_eq!(loaded.bind().get_player(), GString::from("Gödöt"));
+    assert_eq!(loaded.bind().get_score(), 42);
+
+    drop(loaded);
+    remove_test_file(file_name);
+}
+
+#[itest]
+fn resource_format_rejects_other_resources() {
+    // SavedGame is not handled by any custom format, so saving it with the format's extension must fail.
+    let resource = SavedGame::new_gd();
+
+    // Godot reports an error about the missing saver.
+    let mut res = Ok(());
+    suppress_godot_print(|| res = try_save(&resource, "res://test_saved_game.highscore"));
+    assert!(res.is_err());
+}
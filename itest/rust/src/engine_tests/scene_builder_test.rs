@@ -0,0 +1,133 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use godot::builtin::{GString, Vector2};
+use godot::classes::{Node, Node2D, Node3D, PackedScene};
+use godot::tools::{load, save, NodeBuilder};
+
+use super::save_load_test::remove_test_file;
+use crate::framework::{itest, TestContext};
+
+const SCENE_NAME: &str = "scene_builder_test.tscn";
+
+fn make_level() -> NodeBuilder<Node2D> {
+    NodeBuilder::<Node2D>::new()
+        .name("Level")
+        .child(
+            NodeBuilder::<Node2D>::new()
+                .name("Player")
+                .with(|player| player.set_position(Vector2::new(3.0, 4.0)))
+                .child(NodeBuilder::<Node3D>::new().name("Camera")),
+        )
+        .child(
+            NodeBuilder::<Node>::new()
+                .name("Debug")
+                .without_owner()
+                .child(NodeBuilder::<Node>::new().name("Overlay")),
+        )
+        .children((0..2).map(|i| NodeBuilder::<Node>::new().name(format!("Spawn{i}").as_str())))
+}
+
+#[itest]
+fn scene_builder_hierarchy_and_owner() {
+    let level = make_level().build();
+    let root = level.clone().upcast::<Node>();
+
+    let player = level.get_node_as::<Node2D>("Player");
+    assert_eq!(player.get_position(), Vector2::new(3.0, 4.0));
+    assert_eq!(player.get_owner(), Some(root.clone()));
+
+    let camera = level.get_node_as::<Node3D>("Player/Camera");
+    assert_eq!(camera.get_owner(), Some(root));
+
+    // Unowned subtree.
+    assert_eq!(level.get_node_as::<Node>("Debug").get_owner(), None);
+    assert_eq!(level.get_node_as::<Node>("Debug/Overlay").get_owner(), None);
+
+    let names = level
+        .get_children()
+        .iter_shared()
+        .map(|child| child.get_name().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["Player", "Debug", "Spawn0", "Spawn1"]);
+
+    level.free();
+}
+
+#[itest]
+fn scene_builder_property_and_signals(ctx: &TestContext) {
+    let renamed_count = Rc::new(Cell::new(0));
+
+    let builder = NodeBuilder::<Node>::new()
+        .property("editor_description", "built in Rust")
+        .signals(|mut signals| {
+            let renamed_count = renamed_count.clone();
+            signals
+                .renamed()
+                .connect(move || renamed_count.set(renamed_count.get() + 1));
+        });
+
+    let mut node = builder.build();
+    assert_eq!(
+        node.get_editor_description(),
+        GString::from("built in Rust")
+    );
+
+    // `renamed` is only emitted for nodes inside the tree.
+    let mut tree = ctx.scene_tree.clone();
+    tree.add_child(&node);
+
+    node.set_name("renamed");
+    assert_eq!(renamed_count.get(), 1);
+
+    tree.remove_child(&node);
+    node.free();
+}
+
+#[itest]
+fn scene_builder_drop_frees_nodes() {
+    let child = NodeBuilder::<Node>::new();
+    let child_id = child.node().instance_id();
+
+    let builder = NodeBuilder::<Node>::new().child(child);
+    let root_id = builder.node().instance_id();
+
+    drop(builder);
+    assert!(!root_id.lookup_validity());
+    assert!(!child_id.lookup_validity());
+}
+
+#[itest]
+fn scene_builder_pack_and_save() {
+    let scene = make_level().pack().expect("pack scene");
+
+    let path = format!("res://{SCENE_NAME}");
+    save(&scene, &path);
+    let loaded = load::<PackedScene>(&path);
+
+    for scene in [scene, loaded] {
+        let level = scene.instantiate_as::<Node2D>();
+
+        assert_eq!(level.get_name(), "Level".into());
+        assert_eq!(
+            level.get_node_as::<Node2D>("Player").get_position(),
+            Vector2::new(3.0, 4.0)
+        );
+        assert!(level.has_node("Player/Camera"));
+        assert!(level.has_node("Spawn1"));
+
+        // Nodes without owner are not packed.
+        assert!(!level.has_node("Debug"));
+
+        level.free();
+    }
+
+    remove_test_file(SCENE_NAME);
+}
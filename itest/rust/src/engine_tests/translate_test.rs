@@ -6,7 +6,7 @@
  */
 
 use godot::builtin::Vector2;
-use godot::tools::{tr, tr_n};
+use godot::tools::{pot_catalog, tr, tr_n, translation_messages, TranslationContext};
 
 use crate::framework::itest;
 
@@ -47,3 +47,57 @@ fn tr_n_macro_format_plural() {
     let hello = tr_n!(n; "Hello singular {}!", "Hello plural {}s!", "world");
     assert_eq!(hello.to_string(), "Hello plural worlds!");
 }
+
+#[itest]
+fn tr_catalog_contains_messages() {
+    let _ = tr!("ui"; "Catalog entry");
+    let _ = tr_n!(2, "ui"; "{} catalog apple", "{} catalog apples", 2);
+
+    let context = "menu";
+    let _ = tr!(context; "Catalog runtime context");
+
+    let messages: Vec<_> = translation_messages()
+        .into_iter()
+        .filter(|message| message.file == file!())
+        .collect();
+
+    let entry = messages
+        .iter()
+        .find(|message| message.msgid == "Catalog entry")
+        .expect("tr! message registered");
+    assert_eq!(entry.context, Some(TranslationContext::Literal("ui")));
+    assert_eq!(entry.plural, None);
+
+    let plural = messages
+        .iter()
+        .find(|message| message.msgid == "{} catalog apple")
+        .expect("tr_n! message registered");
+    assert_eq!(plural.plural, Some("{} catalog apples"));
+
+    // Non-literal contexts are only known at runtime.
+    let runtime = messages
+        .iter()
+        .find(|message| message.msgid == "Catalog runtime context")
+        .expect("tr! with runtime context registered");
+    assert_eq!(
+        runtime.context,
+        Some(TranslationContext::Expression("context"))
+    );
+
+    // Literals other than strings are recorded as they are formatted at runtime.
+    let named = messages
+        .iter()
+        .find(|message| message.msgid == "Named: x: {x}, y: {y}")
+        .expect("tr! with bool context registered");
+    assert_eq!(named.context, Some(TranslationContext::Literal("false")));
+
+    assert!(!entry.is_parameterized());
+    assert!(plural.is_parameterized());
+
+    // Parameterized messages keep their placeholders and are flagged for translation tools.
+    let pot = pot_catalog();
+    assert!(pot.contains("msgctxt \"ui\"\nmsgid \"Catalog entry\"\nmsgstr \"\"\n"));
+    assert!(pot.contains(
+        "#, rust-format\nmsgctxt \"ui\"\nmsgid \"{} catalog apple\"\nmsgid_plural \"{} catalog apples\"\n"
+    ));
+}
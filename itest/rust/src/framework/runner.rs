@@ -12,6 +12,7 @@ use godot::classes::{Engine, Node, Os};
 use godot::global::godot_error;
 use godot::obj::{Gd, Singleton};
 use godot::register::{godot_api, GodotClass};
+use godot::testing::{run_async_test, run_sync_test};
 
 use super::AsyncRustTestCase;
 use crate::framework::{
@@ -422,14 +423,9 @@ fn run_rust_test(test: &RustTestCase, ctx: &TestContext) -> TestOutcome {
         return TestOutcome::Skipped;
     }
 
-    // This will appear in all panics, but those inside expect_panic() are suppressed.
-    // So the "itest failed" message will only appear for unexpected panics, where tests indeed fail.
     let err_context = || format!("itest `{}` failed", test.name);
 
-    // Explicit type to prevent tests from returning a value.
-    let success: Result<(), _> = godot::private::handle_panic(err_context, || (test.function)(ctx));
-
-    TestOutcome::from_bool(success.is_ok())
+    TestOutcome::from_bool(run_sync_test(err_context, || (test.function)(ctx)))
 }
 
 fn run_async_rust_test(
@@ -441,54 +437,17 @@ fn run_async_rust_test(
         return on_test_finished(TestOutcome::Skipped);
     }
 
-    // Explicit type to prevent tests from returning a value
-    let err_context = || format!("itest `{}` failed", test.name);
-    let success: Result<godot::task::TaskHandle, _> =
-        godot::private::handle_panic(err_context, || (test.function)(ctx));
-
-    let Ok(task_handle) = success else {
-        return on_test_finished(TestOutcome::Failed);
-    };
-
-    check_async_test_task(task_handle, on_test_finished, ctx);
-}
-
-fn check_async_test_task(
-    task_handle: godot::task::TaskHandle,
-    on_test_finished: impl FnOnce(TestOutcome) + 'static,
-    ctx: &TestContext,
-) {
-    use godot::classes::object::ConnectFlags;
-    use godot::task::has_godot_task_panicked;
-
-    if !task_handle.is_pending() {
-        on_test_finished(TestOutcome::from_bool(!has_godot_task_panicked(
-            task_handle,
-        )));
-
-        return;
-    }
-
-    let next_ctx = ctx.clone();
-    let mut callback = Some(on_test_finished);
-    let mut probably_task_handle = Some(task_handle);
-
-    let deferred = Callable::from_fn("run_async_rust_test", move |_| {
-        check_async_test_task(
-            probably_task_handle
-                .take()
-                .expect("Callable will only be called once!"),
-            callback
-                .take()
-                .expect("Callable should not be called multiple times!"),
-            &next_ctx,
-        );
-    });
-
-    ctx.scene_tree
+    let tree = ctx
+        .scene_tree
         .get_tree()
-        .expect("The itest scene tree node is part of a Godot SceneTree")
-        .connect_flags("process_frame", &deferred, ConnectFlags::ONE_SHOT);
+        .expect("The itest scene tree node is part of a Godot SceneTree");
+
+    run_async_test(
+        || format!("itest `{}` failed", test.name),
+        tree,
+        || (test.function)(ctx),
+        |success| on_test_finished(TestOutcome::from_bool(success)),
+    );
 }
 
 fn print_test_pre(test_case: &str, test_file: &str, last_file: Option<&str>, flush: bool) {
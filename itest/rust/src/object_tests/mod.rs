@@ -25,12 +25,15 @@ mod phantom_var_test;
 mod property_template_test;
 mod property_test;
 mod reentrant_test;
+mod scene_nodes_test;
 mod singleton_test;
+mod tracking_test;
 // `validate_property` is only supported in Godot 4.2+.
 mod base_init_test;
 mod validate_property_test;
 mod virtual_methods_niche_test;
 mod virtual_methods_test;
+mod weak_gd_test;
 
 // Need to test this in the init level method.
 pub use init_stage_test::*;
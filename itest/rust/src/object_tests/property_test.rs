@@ -6,12 +6,13 @@
  */
 
 use godot::builtin::{
-    vdict, vslice, Color, Dictionary, GString, PackedInt32Array, Variant, VariantType,
+    vdict, vslice, Color, Dictionary, GString, NodePath, PackedInt32Array, StringName, Variant,
+    VariantType, Vector2,
 };
-use godot::classes::{INode, IRefCounted, Node, Object, RefCounted, Resource, Texture};
+use godot::classes::{INode, IRefCounted, Node, Node2D, Object, RefCounted, Resource, Texture};
 use godot::global::{PropertyHint, PropertyUsageFlags};
 use godot::meta::{GodotConvert, PropertyHintInfo, ToGodot};
-use godot::obj::{Base, EngineBitfield, EngineEnum, Gd, NewAlloc, NewGd, OnEditor};
+use godot::obj::{Base, EngineBitfield, EngineEnum, Gd, Inherits, NewAlloc, NewGd, OnEditor};
 use godot::register::property::{Export, Var};
 use godot::register::{godot_api, Export, GodotClass, GodotConvert, Var};
 use godot::test::itest;
@@ -622,6 +623,128 @@ fn guaranteed_ordering() {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportHints {
+    #[export(password)]
+    password: GString,
+
+    #[export(locale_id)]
+    locale: GString,
+
+    #[export(expression)]
+    formula: StringName,
+
+    #[export(link)]
+    scale: Vector2,
+
+    #[export(node_path_valid_types = (Node2D, HasProperty))]
+    target: NodePath,
+
+    #[export_category(name = "Advanced")]
+    #[export(custom = (hint = ENUM, hint_string = "One,Two", usage = [EDITOR, STORAGE, READ_ONLY]))]
+    custom: i64,
+
+    #[export(custom = (hint = MULTILINE_TEXT))]
+    custom_default_usage: GString,
+
+    #[cfg(since_api = "4.3")]
+    #[export(input_name = (show_builtin, loose_mode))]
+    action: StringName,
+
+    base: Base<Node>,
+}
+
+fn find_property(object: &Gd<impl Inherits<Object>>, name: &str) -> Dictionary {
+    object
+        .upcast_ref::<Object>()
+        .get_property_list()
+        .iter_shared()
+        .find(|p| p.get_or_nil("name") == name.to_variant())
+        .unwrap_or_else(|| panic!("property `{name}` not registered"))
+}
+
+#[itest]
+fn export_dedicated_hints() {
+    let node = ExportHints::new_alloc();
+
+    let expected = [
+        ("password", PropertyHint::PASSWORD, ""),
+        ("locale", PropertyHint::LOCALE_ID, ""),
+        ("formula", PropertyHint::EXPRESSION, ""),
+        ("scale", PropertyHint::LINK, ""),
+        (
+            "target",
+            PropertyHint::NODE_PATH_VALID_TYPES,
+            "Node2D,HasProperty",
+        ),
+        #[cfg(since_api = "4.3")]
+        (
+            "action",
+            PropertyHint::INPUT_NAME,
+            "show_builtin,loose_mode",
+        ),
+    ];
+
+    for (name, hint, hint_string) in expected {
+        let property = find_property(&node, name);
+        check_property(&property, "hint", hint.ord());
+        check_property(&property, "hint_string", hint_string);
+        check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
+    }
+
+    node.free();
+}
+
+#[itest]
+fn export_custom_hint_and_usage() {
+    let node = ExportHints::new_alloc();
+
+    let property = find_property(&node, "custom");
+    check_property(&property, "type", VariantType::INT.ord());
+    check_property(&property, "hint", PropertyHint::ENUM.ord());
+    check_property(&property, "hint_string", "One,Two");
+    check_property(
+        &property,
+        "usage",
+        (PropertyUsageFlags::EDITOR | PropertyUsageFlags::STORAGE | PropertyUsageFlags::READ_ONLY)
+            .ord(),
+    );
+
+    let property = find_property(&node, "custom_default_usage");
+    check_property(&property, "hint", PropertyHint::MULTILINE_TEXT.ord());
+    check_property(&property, "hint_string", "");
+    check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
+
+    node.free();
+}
+
+#[itest]
+fn export_category_precedes_field() {
+    let node = ExportHints::new_alloc();
+
+    let names = node
+        .get_property_list()
+        .iter_shared()
+        .map(|p| p.get_or_nil("name").to::<String>())
+        .collect::<Vec<_>>();
+
+    let category_index = names
+        .iter()
+        .position(|name| name == "Advanced")
+        .expect("category `Advanced` registered");
+    assert_eq!(names[category_index - 1], "target");
+    assert_eq!(names[category_index + 1], "custom");
+
+    let category = find_property(&node, "Advanced");
+    check_property(&category, "type", VariantType::NIL.ord());
+    check_property(&category, "usage", PropertyUsageFlags::CATEGORY.ord());
+
+    node.free();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 #[derive(GodotClass)]
 #[class(base=Node, init)]
 struct RenamedFunc {
@@ -759,3 +882,81 @@ fn test_duplicate_retains_properties() {
     duplicated.free();
     original.free();
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(since_api = "4.4")]
+mod tool_button {
+    use godot::builtin::{Callable, VariantType};
+    use godot::classes::Node;
+    use godot::global::{PropertyHint, PropertyUsageFlags};
+    use godot::meta::ToGodot;
+    use godot::obj::{Base, EngineBitfield, EngineEnum, NewAlloc};
+    use godot::register::property::PhantomVar;
+    use godot::register::{godot_api, GodotClass};
+    use godot::test::itest;
+
+    use super::check_property;
+
+    #[derive(GodotClass)]
+    #[class(init, tool, base=Node)]
+    struct ToolButtons {
+        #[export_tool_button(label = "Bake navmesh", icon = "Bake", method = bake)]
+        bake_button: PhantomVar<Callable>,
+
+        #[export_tool_button(label = "Regenerate")]
+        #[init(val = Callable::from_fn("regenerate", |_| 42))]
+        regenerate_button: Callable,
+
+        bake_count: i32,
+        base: Base<Node>,
+    }
+
+    #[godot_api]
+    impl ToolButtons {
+        #[func]
+        fn bake(&mut self) {
+            self.bake_count += 1;
+        }
+    }
+
+    #[itest]
+    fn export_tool_button_property_info() {
+        let node = ToolButtons::new_alloc();
+        let properties = node.get_property_list();
+
+        for (name, hint_string) in [
+            ("bake_button", "Bake navmesh,Bake"),
+            ("regenerate_button", "Regenerate"),
+        ] {
+            let property = properties
+                .iter_shared()
+                .find(|p| p.get_or_nil("name") == name.to_variant())
+                .unwrap_or_else(|| panic!("property `{name}` not registered"));
+
+            check_property(&property, "type", VariantType::CALLABLE.ord());
+            check_property(&property, "hint", PropertyHint::TOOL_BUTTON.ord());
+            check_property(&property, "hint_string", hint_string);
+            check_property(&property, "usage", PropertyUsageFlags::EDITOR.ord());
+        }
+
+        node.free();
+    }
+
+    #[itest]
+    fn export_tool_button_invokes_callable() {
+        let node = ToolButtons::new_alloc();
+
+        // Pressing the button in the inspector calls the callable returned by the property getter.
+        let bake = node.get("bake_button").to::<Callable>();
+        assert!(bake.is_valid());
+        bake.call(&[]);
+        bake.call(&[]);
+        assert_eq!(node.bind().bake_count, 2);
+
+        let regenerate = node.get("regenerate_button").to::<Callable>();
+        assert_eq!(regenerate.call(&[]), 42.to_variant());
+
+        node.free();
+    }
+}
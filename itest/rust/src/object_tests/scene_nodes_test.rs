@@ -0,0 +1,102 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{GpuParticles2D, HttpRequest, Node2D, Node3D, PackedScene};
+use godot::prelude::*;
+use godot::tools::load;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Node, scene = "res://SceneNodesTest.tscn")]
+struct SceneNodesOwner {
+    base: Base<Node>,
+}
+
+/// Registered under a different name, which the scene file refers to.
+#[derive(GodotClass)]
+#[class(init, base=Node, rename=SceneNodesChild)]
+struct SceneNodesChildRust {
+    base: Base<Node>,
+}
+
+// Makes the renamed class available under its Godot name, for the node types of the scene.
+use SceneNodesChildRust as SceneNodesChild;
+
+/// Defines its own `nodes()` method, so the generated accessor is renamed.
+#[derive(GodotClass)]
+#[class(init, base=Node, scene = "res://SceneNodesTest.tscn", scene_accessor = scene_nodes)]
+struct SceneNodesRenamed {
+    base: Base<Node>,
+}
+
+impl SceneNodesRenamed {
+    fn nodes(&self) -> usize {
+        self.base().get_child_count() as usize
+    }
+}
+
+fn instantiate_scene() -> Gd<SceneNodesOwner> {
+    load::<PackedScene>("res://SceneNodesTest.tscn").instantiate_as::<SceneNodesOwner>()
+}
+
+#[itest]
+fn scene_nodes_typed_fields() {
+    let owner = instantiate_scene();
+
+    let nodes = owner.bind().nodes();
+    let body: Gd<Node3D> = nodes.body;
+    let body_sprite: Gd<Node2D> = nodes.body_sprite;
+    let sprite: Gd<Node2D> = nodes.sprite;
+    let health_bar: Gd<Node> = nodes.health_bar_2;
+    let particles: Gd<GpuParticles2D> = nodes.particles;
+    let request: Gd<HttpRequest> = nodes.http_request;
+    let child: Gd<SceneNodesChildRust> = nodes.child;
+
+    assert_eq!(body.get_name(), "Body".into());
+    assert_eq!(body_sprite.get_parent(), Some(body.upcast()));
+    assert_eq!(sprite.get_position(), Vector2::new(4.0, 2.0));
+    assert_eq!(health_bar.get_name(), "HealthBar2".into());
+    assert_eq!(particles.get_parent(), Some(sprite.upcast()));
+    assert_eq!(request.get_name(), "HTTPRequest".into());
+    assert_eq!(child.get_name(), "Child".into());
+
+    owner.free();
+}
+
+#[itest]
+fn scene_nodes_from_root() {
+    let owner = instantiate_scene();
+    let root = owner.clone().upcast::<Node>();
+
+    let nodes = SceneNodesOwnerNodes::from_root(&root);
+    assert_eq!(nodes.sprite.get_parent(), Some(root));
+    assert_eq!(nodes.body_sprite.get_name(), "Sprite".into());
+
+    owner.free();
+}
+
+#[itest]
+fn scene_nodes_renamed_accessor() {
+    let mut owner = instantiate_scene().upcast::<Node>();
+    let mut renamed = SceneNodesRenamed::new_alloc();
+
+    // Move the scene's nodes to an instance of the other class, which uses the same scene file.
+    for child in owner.get_children().iter_shared() {
+        owner.remove_child(&child);
+        renamed.add_child(&child);
+    }
+    owner.free();
+
+    assert_eq!(renamed.bind().nodes(), 5);
+
+    let nodes = renamed.bind().scene_nodes();
+    assert_eq!(nodes.body_sprite.get_name(), "Sprite".into());
+    assert_eq!(nodes.health_bar_2.get_name(), "HealthBar2".into());
+
+    renamed.free();
+}
@@ -0,0 +1,106 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Object tracking is only available in strict safeguard level.
+#![cfg(safeguards_strict)]
+
+use godot::classes::{ClassDb, Node};
+use godot::obj::tracking;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct TrackedNode {}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct TrackedRefc {
+    other: Option<Gd<TrackedRefc>>,
+}
+
+#[itest]
+fn tracking_counts_manual_objects() {
+    let before = tracking::live_count::<TrackedNode>();
+
+    let first = TrackedNode::new_alloc();
+    let second = Gd::from_object(TrackedNode {});
+    assert_eq!(tracking::live_count::<TrackedNode>(), before + 2);
+    assert_eq!(
+        tracking::live_counts().get(&TrackedNode::class_id()),
+        Some(&(before + 2))
+    );
+
+    first.free();
+    assert_eq!(tracking::live_count::<TrackedNode>(), before + 1);
+
+    second.free();
+    assert_eq!(tracking::live_count::<TrackedNode>(), before);
+}
+
+#[itest]
+fn tracking_counts_engine_objects() {
+    let before = tracking::live_count::<Node>();
+
+    let node = Node::new_alloc();
+    let id = node.instance_id();
+
+    let live = tracking::live_objects();
+    let tracked = live
+        .iter()
+        .find(|obj| obj.instance_id() == id)
+        .expect("engine object created from Rust is tracked");
+
+    assert_eq!(tracked.class_id(), Node::class_id());
+    assert!(!tracked.is_ref_counted());
+
+    node.free();
+    assert_eq!(tracking::live_count::<Node>(), before);
+}
+
+#[itest]
+fn tracking_detects_reference_cycle() {
+    let before = tracking::live_count::<TrackedRefc>();
+
+    let mut a = TrackedRefc::new_gd();
+    let b = TrackedRefc::new_gd();
+    assert_eq!(tracking::live_count::<TrackedRefc>(), before + 2);
+
+    // Without a cycle, dropping frees the object.
+    drop(b);
+    assert_eq!(tracking::live_count::<TrackedRefc>(), before + 1);
+
+    // Cycle keeps the object alive after dropping all external references.
+    let id = a.instance_id();
+    a.bind_mut().other = Some(a.clone());
+    drop(a);
+
+    let leaked = tracking::live_objects()
+        .into_iter()
+        .find(|obj| obj.instance_id() == id)
+        .expect("object in cycle is alive");
+    assert!(leaked.is_ref_counted());
+
+    // Break the cycle, to not leak in the test itself.
+    let mut a = Gd::<TrackedRefc>::from_instance_id(id);
+    a.bind_mut().other = None;
+    drop(a);
+    assert_eq!(tracking::live_count::<TrackedRefc>(), before);
+}
+
+#[itest]
+fn tracking_ignores_objects_created_by_godot() {
+    let before = tracking::live_count::<TrackedNode>();
+
+    let node = ClassDb::singleton()
+        .instantiate("TrackedNode")
+        .to::<Gd<TrackedNode>>();
+    assert_eq!(tracking::live_count::<TrackedNode>(), before);
+
+    node.free();
+}
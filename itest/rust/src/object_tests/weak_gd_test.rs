@@ -0,0 +1,106 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Resource)]
+struct WeakParent {
+    children: Vec<Gd<WeakChild>>,
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Resource)]
+struct WeakChild {
+    #[var]
+    parent: WeakGd<WeakParent>,
+}
+
+#[itest]
+fn weak_gd_refcounted_upgrade() {
+    let strong = RefCounted::new_gd();
+    let weak = strong.downgrade();
+
+    assert!(weak.is_alive());
+    assert_eq!(weak.instance_id(), Some(strong.instance_id()));
+
+    let upgraded = weak.upgrade().expect("object is alive");
+    assert_eq!(upgraded, strong);
+    assert_eq!(strong.get_reference_count(), 2);
+
+    // The weak pointer itself does not keep the object alive.
+    drop(upgraded);
+    drop(strong);
+    assert!(!weak.is_alive());
+    assert!(weak.upgrade().is_none());
+}
+
+#[itest]
+fn weak_gd_manual_upgrade() {
+    let node = Node::new_alloc();
+    let weak = node.downgrade();
+    assert_eq!(weak.upgrade(), Some(node.clone()));
+
+    node.free();
+    assert!(weak.upgrade().is_none());
+    assert!(weak.instance_id().is_some());
+}
+
+#[itest]
+fn weak_gd_empty() {
+    let weak = WeakGd::<Node>::default();
+
+    assert!(!weak.is_alive());
+    assert!(weak.upgrade().is_none());
+    assert_eq!(weak, WeakGd::new());
+    assert_eq!(weak.to_variant(), Variant::nil());
+}
+
+#[itest]
+fn weak_gd_breaks_cycle() {
+    let mut parent = WeakParent::new_gd();
+    let weak_parent = parent.downgrade();
+
+    let mut child = WeakChild::new_gd();
+    child.bind_mut().parent = parent.downgrade();
+    parent.bind_mut().children.push(child.clone());
+
+    // Child -> parent is weak, so the parent is only kept alive by `parent`.
+    assert_eq!(parent.get_reference_count(), 1);
+    drop(parent);
+
+    assert!(weak_parent.upgrade().is_none());
+    assert!(child.bind().parent.upgrade().is_none());
+}
+
+#[itest]
+fn weak_gd_var_property() {
+    let parent = WeakParent::new_gd();
+    let mut child = WeakChild::new_gd();
+
+    // Strong objects assigned from Godot are downgraded.
+    child.set("parent", &parent.to_variant());
+    assert_eq!(child.bind().parent.upgrade(), Some(parent.clone()));
+
+    // Godot sees a WeakRef object.
+    let weak_ref = child.get("parent");
+    let weak_ref_obj = weak_ref.to::<Gd<Object>>();
+    assert!(weak_ref_obj.is_class("WeakRef"));
+    assert_eq!(weak_ref.call("get_ref", &[]), parent.to_variant());
+
+    // WeakRef objects are accepted, too.
+    child.set("parent", &Variant::nil());
+    assert!(child.bind().parent.upgrade().is_none());
+
+    child.set("parent", &weak_ref);
+    assert_eq!(child.bind().parent.upgrade(), Some(parent.clone()));
+
+    let roundtrip = WeakGd::<WeakParent>::from_variant(&weak_ref);
+    assert_eq!(roundtrip, parent.downgrade());
+}
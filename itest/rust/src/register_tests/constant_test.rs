@@ -120,63 +120,30 @@ struct HasOtherConstants {}
 
 impl HasOtherConstants {
     const ENUM_NAME: &'static str = "SomeEnum";
+    const BITFIELD_NAME: &'static str = "SomeBitfield";
+}
+
+#[godot_api]
+impl HasOtherConstants {
+    #[constant(enum = SomeEnum)]
     const ENUM_A: i64 = 0;
+    #[constant(enum = SomeEnum)]
     const ENUM_B: i64 = 1;
+    #[constant(enum = SomeEnum)]
     const ENUM_C: i64 = 2;
 
-    const BITFIELD_NAME: &'static str = "SomeBitfield";
+    #[constant(bitfield = SomeBitfield)]
     const BITFIELD_A: i64 = 1;
+    #[constant(bitfield = SomeBitfield)]
     const BITFIELD_B: i64 = 2;
+    #[constant(bitfield = SomeBitfield)]
     const BITFIELD_C: i64 = 4;
-}
 
-// TODO: replace with proc-macro api when constant enums and bitfields can be exported through the
-// proc-macro.
-impl godot::obj::cap::ImplementsGodotApi for HasOtherConstants {
-    fn __register_methods() {}
-    fn __register_constants() {
-        use ::godot::register::private::constant::*;
-        // Try exporting an enum.
-        ExportConstant::new(
-            HasOtherConstants::class_id(),
-            ConstantKind::Enum {
-                name: Self::ENUM_NAME.into(),
-                enumerators: vec![
-                    IntegerConstant::new("ENUM_A", Self::ENUM_A),
-                    IntegerConstant::new("ENUM_B", Self::ENUM_B),
-                    IntegerConstant::new("ENUM_C", Self::ENUM_C),
-                ],
-            },
-        )
-        .register();
-
-        // Try exporting an enum.
-        ExportConstant::new(
-            HasOtherConstants::class_id(),
-            ConstantKind::Bitfield {
-                name: Self::BITFIELD_NAME.into(),
-                flags: vec![
-                    IntegerConstant::new("BITFIELD_A", Self::BITFIELD_A),
-                    IntegerConstant::new("BITFIELD_B", Self::BITFIELD_B),
-                    IntegerConstant::new("BITFIELD_C", Self::BITFIELD_C),
-                ],
-            },
-        )
-        .register();
-    }
+    // Constants without a group stay plain integer constants, even when mixed with enumerators.
+    #[constant]
+    const UNGROUPED: i64 = 7;
 }
 
-// TODO once this is done via proc-macro, see if `register-docs` is still used in register_docs_test.rs. Otherwise, remove feature from Cargo.toml.
-godot::sys::plugin_add!(
-    godot::private::__GODOT_PLUGIN_REGISTRY;
-    godot::private::ClassPlugin::new::<HasOtherConstants>(
-        godot::private::PluginItem::InherentImpl(
-            godot::private::InherentImpl::new::<HasOtherConstants>(
-            )
-        )
-    )
-);
-
 macro_rules! test_enum_export {
     (
         $class:ty, $enum_name:ident, [$($enumerators:ident),* $(,)?];
@@ -231,3 +198,80 @@ test_enum_export!(
     #[itest]
     fn bitfield_export_correct_values() { .. }
 );
+
+#[derive(GodotConvert, Copy, Clone, Eq, PartialEq, Debug)]
+#[godot(via = i64)]
+enum RustTerrain {
+    Grass,
+    Sand = 5,
+    #[deprecated = "Use `Sand` instead."]
+    Desert,
+}
+
+#[derive(GodotConvert, Copy, Clone, Eq, PartialEq, Debug)]
+#[godot(via = u8)]
+enum RustLayers {
+    Ground = 1,
+    Water = 2,
+    Air = 4,
+}
+
+#[derive(GodotClass)]
+#[class(no_init)]
+struct HasRustEnums {}
+
+#[godot_api]
+impl HasRustEnums {
+    #[constant(enum)]
+    type Terrain = RustTerrain;
+
+    #[constant(bitfield)]
+    type Layers = RustLayers;
+}
+
+#[itest]
+#[allow(deprecated)]
+fn enum_export_from_godot_convert() {
+    let class_name = HasRustEnums::class_id().to_string_name();
+    let class_db = ClassDb::singleton();
+
+    let cases = [
+        ("Terrain", "Grass", RustTerrain::Grass as i64),
+        ("Terrain", "Sand", RustTerrain::Sand as i64),
+        ("Terrain", "Desert", RustTerrain::Desert as i64),
+        ("Layers", "Ground", RustLayers::Ground as i64),
+        ("Layers", "Water", RustLayers::Water as i64),
+        ("Layers", "Air", RustLayers::Air as i64),
+    ];
+
+    for (enum_name, enumerator, value) in cases {
+        assert!(class_db
+            .class_has_enum_ex(&class_name, enum_name)
+            .no_inheritance(true)
+            .done());
+        assert_eq!(
+            class_db.class_get_integer_constant_enum(&class_name, enumerator),
+            StringName::from(enum_name)
+        );
+        assert_eq!(
+            class_db.class_get_integer_constant(&class_name, enumerator),
+            value
+        );
+    }
+
+    assert_eq!(RustTerrain::Desert as i64, 6);
+}
+
+#[itest]
+fn enum_export_keeps_ungrouped_constants() {
+    let class_name = HasOtherConstants::class_id().to_string_name();
+
+    assert!(class_has_integer_constant::<HasOtherConstants>("UNGROUPED"));
+    assert!(ClassDb::singleton()
+        .class_get_integer_constant_enum(&class_name, "UNGROUPED")
+        .is_empty());
+    assert_eq!(
+        ClassDb::singleton().class_get_integer_constant_enum(&class_name, "ENUM_B"),
+        StringName::from(HasOtherConstants::ENUM_NAME)
+    );
+}
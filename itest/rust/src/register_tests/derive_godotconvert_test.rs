@@ -7,8 +7,8 @@
 
 use std::fmt::Debug;
 
-use godot::builtin::{GString, Vector2};
-use godot::meta::ToGodot;
+use godot::builtin::{vdict, Dictionary, GString, Vector2};
+use godot::meta::{FromGodot, ToGodot};
 use godot::register::GodotConvert;
 
 use crate::common::roundtrip;
@@ -148,3 +148,153 @@ test_inty!(i64, test_enum_i64, EnumI64);
 test_inty!(u8, test_enum_u8, EnumU8);
 test_inty!(u16, test_enum_u16, EnumU16);
 test_inty!(u32, test_enum_u32, EnumU32);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversions via Dictionary
+
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(via = Dictionary)]
+struct DictPlayer {
+    name: GString,
+    #[godot(rename = "hp")]
+    health: i64,
+    #[godot(default)]
+    position: Vector2,
+    #[godot(default = 1)]
+    level: i32,
+}
+
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(via = Dictionary)]
+enum DictShape {
+    Circle {
+        radius: f32,
+    },
+    Rect(Vector2, #[godot(default)] bool),
+    #[godot(rename = "none")]
+    Empty,
+}
+
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(via = Dictionary, tag = "kind")]
+enum DictCommand {
+    Move { r#type: GString },
+    Stop,
+}
+
+fn make_player() -> DictPlayer {
+    DictPlayer {
+        name: "Alice".into(),
+        health: 100,
+        position: Vector2::new(1.0, 2.0),
+        level: 7,
+    }
+}
+
+#[itest]
+fn dict_struct_roundtrip() {
+    roundtrip(make_player());
+
+    let dict = make_player().to_godot();
+    assert_eq!(dict.len(), 4);
+    assert_eq!(dict.at("name"), "Alice".to_variant());
+    assert_eq!(dict.at("hp"), 100.to_variant());
+    assert!(!dict.contains_key("health"));
+}
+
+#[itest]
+fn dict_struct_defaults() {
+    let dict = vdict! { "name": "Bob", "hp": 50 };
+    let player = DictPlayer::try_from_godot(dict).expect("defaulted fields may be absent");
+
+    assert_eq!(player.position, Vector2::ZERO);
+    assert_eq!(player.level, 1);
+}
+
+#[itest]
+fn dict_struct_missing_field() {
+    let dict = vdict! { "name": "Bob" };
+    let err = DictPlayer::try_from_godot(dict).expect_err("required field `hp` is absent");
+
+    let message = err.to_string();
+    assert!(message.contains("`hp`"), "unexpected error: {message}");
+    assert!(
+        message.contains("DictPlayer"),
+        "unexpected error: {message}"
+    );
+}
+
+#[itest]
+fn dict_struct_mistyped_field() {
+    let dict = vdict! { "name": "Bob", "hp": "lots" };
+    let err = DictPlayer::try_from_godot(dict).expect_err("field `hp` has wrong type");
+
+    let message = err.to_string();
+    assert!(message.contains("`hp`"), "unexpected error: {message}");
+
+    // Offending value and underlying conversion error are kept.
+    assert_eq!(err.value(), Some(&"lots".to_variant()));
+    let cause = err.cause().expect("cause");
+    assert!(cause.source().is_some(), "missing source: {cause}");
+}
+
+#[itest]
+fn dict_enum_roundtrip() {
+    roundtrip(DictShape::Circle { radius: 2.5 });
+    roundtrip(DictShape::Rect(Vector2::new(3.0, 4.0), true));
+    roundtrip(DictShape::Empty);
+
+    let dict = DictShape::Circle { radius: 2.5 }.to_godot();
+    assert_eq!(dict.at("type"), "Circle".to_variant());
+    assert_eq!(dict.at("radius"), 2.5f32.to_variant());
+
+    let dict = DictShape::Rect(Vector2::new(3.0, 4.0), false).to_godot();
+    assert_eq!(dict.at("0"), Vector2::new(3.0, 4.0).to_variant());
+    assert_eq!(dict.at("1"), false.to_variant());
+
+    let dict = DictShape::Empty.to_godot();
+    assert_eq!(dict, vdict! { "type": "none" });
+}
+
+#[itest]
+fn dict_enum_defaults() {
+    let dict = vdict! { "type": "Rect", "0": Vector2::ONE };
+    let shape = DictShape::try_from_godot(dict).expect("defaulted tuple field may be absent");
+
+    assert_eq!(shape, DictShape::Rect(Vector2::ONE, false));
+}
+
+#[itest]
+fn dict_enum_errors() {
+    let err =
+        DictShape::try_from_godot(vdict! { "type": "Triangle" }).expect_err("unknown enumerator");
+    let message = err.to_string();
+    assert!(message.contains("Triangle"), "unexpected error: {message}");
+
+    let err = DictShape::try_from_godot(Dictionary::new()).expect_err("missing tag");
+    let message = err.to_string();
+    assert!(message.contains("`type`"), "unexpected error: {message}");
+
+    let err = DictShape::try_from_godot(vdict! { "type": "Circle" })
+        .expect_err("missing enumerator field");
+    let message = err.to_string();
+    assert!(message.contains("`radius`"), "unexpected error: {message}");
+    assert!(
+        message.contains("DictShape::Circle"),
+        "unexpected error: {message}"
+    );
+}
+
+#[itest]
+fn dict_enum_custom_tag() {
+    roundtrip(DictCommand::Move {
+        r#type: "walk".into(),
+    });
+    roundtrip(DictCommand::Stop);
+
+    let dict = DictCommand::Move {
+        r#type: "walk".into(),
+    }
+    .to_godot();
+    assert_eq!(dict, vdict! { "kind": "Move", "type": "walk" });
+}
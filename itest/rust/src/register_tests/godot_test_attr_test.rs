@@ -0,0 +1,85 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Tests for the public #[godot::test] attribute. Those tests are not run by the itest runner itself; instead, their registrations are
+// inspected, and a nested Godot process runs them through `HeadlessRunner`.
+
+use godot::builtin::GString;
+use godot::classes::Os;
+use godot::obj::Singleton;
+use godot::task::TaskHandle;
+use godot::testing::{
+    HeadlessRunner, TestCase, TestContext, TestFunction, TestOutcome, __GODOT_TEST,
+};
+
+use crate::framework::itest;
+
+#[godot::test]
+fn user_test_plain() {
+    assert_eq!(GString::from("abc").len(), 3);
+}
+
+#[godot::test(skip)]
+fn user_test_skipped(_ctx: &TestContext) {}
+
+#[godot::test(async)]
+fn user_test_async() -> TaskHandle {
+    godot::task::spawn(async {})
+}
+
+fn find_registered(name: &str) -> TestCase {
+    let registry = __GODOT_TEST.lock().unwrap();
+
+    *registry
+        .iter()
+        .find(|test| test.name == name)
+        .unwrap_or_else(|| panic!("#[godot::test] `{name}` not registered"))
+}
+
+#[itest]
+fn godot_test_registers_functions() {
+    let plain = find_registered("user_test_plain");
+    assert!(!plain.skipped);
+    assert!(!plain.focused);
+    assert!(plain.file.ends_with("godot_test_attr_test.rs"));
+    assert!(matches!(plain.function, TestFunction::Sync(_)));
+
+    let skipped = find_registered("user_test_skipped");
+    assert!(skipped.skipped);
+
+    let async_test = find_registered("user_test_async");
+    assert!(matches!(async_test.function, TestFunction::Async(_)));
+}
+
+#[itest]
+fn godot_test_headless_runner_end_to_end() {
+    // Launches the same Godot executable on this project, with an empty scene instead of the itest runner.
+    let report = HeadlessRunner::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../godot"))
+        .godot_binary(Os::singleton().get_executable_path().to_string())
+        .scene("res://HeadlessRunnerTest.tscn")
+        .filter("user_test_")
+        .run();
+
+    report.assert_success();
+    assert!(!report.focus_run);
+
+    let mut outcomes = report
+        .results
+        .iter()
+        .map(|test| (test.name.as_str(), test.outcome))
+        .collect::<Vec<_>>();
+    outcomes.sort_by_key(|(name, _)| *name);
+
+    assert_eq!(
+        outcomes,
+        [
+            ("user_test_async", TestOutcome::Passed),
+            ("user_test_plain", TestOutcome::Passed),
+            ("user_test_skipped", TestOutcome::Ignored),
+        ]
+    );
+}
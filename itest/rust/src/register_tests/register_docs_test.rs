@@ -319,6 +319,64 @@ impl FairlyDocumented {
     fn tertiary_but_documented(&self, _smth: i64) {}
 }
 
+/// Material a door is made of.
+#[derive(GodotConvert)]
+#[godot(via = i64)]
+pub enum DoorMaterial {
+    /// Cheap and light.
+    Wood,
+    /// Hard to break.
+    Steel = 4,
+    #[deprecated = "Rusts too quickly."]
+    Iron,
+}
+
+/// Door that can be opened.
+///
+/// @tutorial(Doors in depth): https://example.com/doors
+/// @tutorial: https://example.com/more-doors
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+pub struct DocumentedDoor {}
+
+#[godot_api]
+impl DocumentedDoor {
+    /// Fully closed.
+    #[constant(enum = DoorState)]
+    const CLOSED: i64 = 0;
+
+    /// Fully open.
+    #[constant(enum = DoorState)]
+    const OPEN: i64 = 1;
+
+    /// Cannot be opened.
+    #[constant(bitfield = DoorFlags)]
+    const LOCKED: i64 = 1;
+
+    #[constant(enum)]
+    type Material = DoorMaterial;
+
+    /// Opens the door partially.
+    ///
+    /// # Arguments
+    /// * `ratio` - How far to open, from `0` to `1`.
+    #[func]
+    fn open(&self, ratio: f32) {
+        let _ = ratio;
+    }
+
+    /// Slams the door.
+    #[func]
+    #[deprecated = "Doors should be closed gently."]
+    fn slam(&self) {}
+
+    /// Waits until the door is unlocked.
+    #[func]
+    async fn unlocked() -> bool {
+        true
+    }
+}
+
 #[itest]
 fn test_register_docs() {
     let actual_xml = find_class_docs("FairlyDocumented");
@@ -361,6 +419,82 @@ fn test_register_docs() {
     );
 }
 
+#[itest]
+fn export_docs_markdown_html() {
+    use godot::docs::{class_docs, DocsFormat};
+
+    let find_file = |format: DocsFormat, file_name: &str| {
+        class_docs(format)
+            .into_iter()
+            .find(|file| file.file_name == file_name)
+            .unwrap_or_else(|| panic!("{file_name} not exported"))
+            .content
+    };
+
+    let xml = find_file(DocsFormat::Xml, "FairlyDocumented.xml");
+    assert_eq!(xml, find_class_docs("FairlyDocumented"));
+
+    let md = find_file(DocsFormat::Markdown, "FairlyDocumented.md");
+    assert!(md.starts_with("# FairlyDocumented\n\n**Inherits:** `Node`\n\n"));
+    assert!(md.contains(
+        "> **Deprecated:** we will use normal integration tests with editor in the future."
+    ));
+    assert!(md.contains("### `secondary_but_documented(smth: i64)`\n\nDocumented method in godot_api secondary block"));
+
+    let index = find_file(DocsFormat::Markdown, "index.md");
+    assert!(index.contains("* [`FairlyDocumented`](FairlyDocumented.md): *documented*"));
+
+    let html = find_file(DocsFormat::Html, "FairlyDocumented.html");
+    assert!(html.contains("<h1>FairlyDocumented</h1>"));
+    assert!(html.contains("<code>Gd&lt;Node&gt;</code>"));
+}
+
+#[itest]
+fn export_docs_to_dir() {
+    use godot::docs::{export_docs, DocsFormat};
+
+    let dir = std::env::temp_dir().join("godot_rust_export_docs_test");
+    let paths = export_docs(&dir, DocsFormat::Markdown).expect("export docs");
+
+    assert!(paths.contains(&dir.join("FairlyDocumented.md")));
+    assert!(paths.contains(&dir.join("index.md")));
+    for path in &paths {
+        assert!(path.is_file(), "missing {}", path.display());
+    }
+
+    std::fs::remove_dir_all(&dir).expect("remove exported docs");
+}
+
+#[itest]
+fn register_docs_enums_params_tutorials() {
+    let xml = find_class_docs("DocumentedDoor");
+
+    assert!(xml.contains(r#"<link title="Doors in depth">https://example.com/doors</link>"#));
+    assert!(xml.contains(r#"<link title="">https://example.com/more-doors</link>"#));
+    assert!(!xml.contains("@tutorial"));
+    assert!(xml.contains(r#"name="OPEN" value="1" enum="DoorState""#));
+    assert!(xml.contains(r#"enum="DoorFlags" is_bitfield="true""#));
+    assert!(xml.contains(r#"<constant name="Steel" value="4" enum="Material">Hard to break."#));
+    assert!(xml.contains(
+        r#"<constant name="Iron" value="5" enum="Material" deprecated="Rusts too quickly.">"#
+    ));
+    assert!(xml.contains("[b]Parameters:[/b][br]• [param ratio]: How far to open"));
+    assert!(xml.contains(r#"<method name="slam" deprecated="Doors should be closed gently.">"#));
+    assert!(xml.contains(r#"<return type="Gd&lt;RefCounted&gt;" />"#));
+    assert!(!xml.contains(":: godot ::"));
+
+    let page = godot::docs::class_docs(godot::docs::DocsFormat::Markdown)
+        .into_iter()
+        .find(|file| file.file_name == "DocumentedDoor.md")
+        .expect("DocumentedDoor.md exported")
+        .content;
+
+    assert!(page.contains("## Tutorials\n\n* [Doors in depth](https://example.com/doors)"));
+    assert!(page.contains("## Enumerations\n\n### `enum DoorState`"));
+    assert!(page.contains("### `flags DoorFlags`"));
+    assert!(page.contains("### `enum Material`"));
+}
+
 fn find_class_docs(class_name: &str) -> String {
     let mut count = 0;
     for xml in godot::docs::gather_xml_docs() {
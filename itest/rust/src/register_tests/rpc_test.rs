@@ -6,12 +6,17 @@
  */
 
 use godot::classes::multiplayer_api::RpcMode;
-use godot::classes::multiplayer_peer::TransferMode;
-use godot::classes::{Engine, MultiplayerApi};
+use godot::classes::multiplayer_peer::{ConnectionStatus, TransferMode};
+use godot::classes::{Engine, MultiplayerApi, MultiplayerPeerExtension, Window};
+use godot::global::Error;
 use godot::obj::Singleton;
 use godot::prelude::*;
 use godot::register::RpcConfig;
 use godot::test::itest;
+use godot::tools::multiplayer_peer::{
+    multiplayer_peer, CustomMultiplayerPeer, LoopbackTransport, MultiplayerPeerState,
+    OutgoingPacket,
+};
 
 #[derive(GodotClass)]
 #[class(init, base = Node2D)]
@@ -81,6 +86,122 @@ impl RpcTest {
     pub fn arg_config_fn(&mut self) {}
 }
 
+#[derive(GodotClass)]
+#[class(init, base = Node)]
+pub struct TypedRpcTest {
+    health: i32,
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl TypedRpcTest {
+    #[rpc(call_local, reliable)]
+    fn heal(&mut self, amount: i32, source: GString) {
+        assert_eq!(source, "potion");
+        self.health += amount;
+    }
+
+    #[rpc(any_peer)]
+    #[func(rename = renamed_ping)]
+    fn ping(&mut self) {}
+
+    #[func]
+    fn heal_self(&mut self) {
+        // Sent while `self` is borrowed; `call_local` re-enters through the held base_mut() guard.
+        let err = self.rpcs().heal(2, "potion").to_peer(1);
+        assert_eq!(err, godot::global::Error::OK);
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base = Node)]
+pub struct NetworkedPlayer {
+    #[init(val = 100)]
+    health: i32,
+    last_sender: i32,
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl NetworkedPlayer {
+    #[rpc(any_peer, reliable)]
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+        self.last_sender = self
+            .base()
+            .get_multiplayer()
+            .expect("node has multiplayer API")
+            .get_remote_sender_id();
+    }
+
+    #[rpc(authority, reliable)]
+    fn set_health(&mut self, health: i32) {
+        self.health = health;
+    }
+}
+
+#[derive(GodotClass)]
+#[class(no_init, base = MultiplayerPeerExtension)]
+struct LoopbackPeer {
+    transport: LoopbackTransport,
+    state: MultiplayerPeerState,
+    base: Base<MultiplayerPeerExtension>,
+}
+
+impl LoopbackPeer {
+    fn new_pair(client_id: i32) -> (Gd<Self>, Gd<Self>) {
+        let (server, client) = LoopbackTransport::pair(client_id);
+        let create = |transport| {
+            Gd::from_init_fn(|base| Self {
+                transport,
+                state: MultiplayerPeerState::new(),
+                base,
+            })
+        };
+
+        (create(server), create(client))
+    }
+}
+
+impl CustomMultiplayerPeer for LoopbackPeer {
+    fn peer_state(&self) -> &MultiplayerPeerState {
+        &self.state
+    }
+
+    fn peer_state_mut(&mut self) -> &mut MultiplayerPeerState {
+        &mut self.state
+    }
+
+    fn unique_id(&self) -> i32 {
+        self.transport.unique_id()
+    }
+
+    fn connection_status(&self) -> ConnectionStatus {
+        self.transport.connection_status()
+    }
+
+    fn send_packet(&mut self, packet: OutgoingPacket) -> Result<(), Error> {
+        self.transport.send(packet)
+    }
+
+    fn poll(&mut self) {
+        for event in self.transport.poll(&mut self.state) {
+            self.emit_peer_event(event);
+        }
+    }
+
+    fn close(&mut self) {
+        self.transport.close();
+        self.state.clear_incoming();
+    }
+}
+
+multiplayer_peer!(LoopbackPeer {
+    fn to_string(&self) -> GString {
+        format!("LoopbackPeer(id={})", self.transport.unique_id()).into()
+    }
+});
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Tests
 
@@ -91,14 +212,124 @@ fn node_enters_tree() {
     let node = RpcTest::new_alloc();
 
     // Registering is done in `UserClass::__before_ready()`, and it requires a multiplayer API to exist.
+    let mut root = root_with_multiplayer();
+    root.add_child(&node);
+    root.remove_child(&node);
+    node.free();
+}
+
+#[itest]
+fn typed_rpc_call_args() {
+    let node = TypedRpcTest::new_alloc();
+
+    let call = node.rpcs().heal(7, "potion");
+    assert_eq!(call.method_name(), &StringName::from("heal"));
+    assert_eq!(call.args(), &[7.to_variant(), "potion".to_variant()]);
+    drop(call);
+
+    // Godot name is used for renamed functions.
+    let call = node.rpcs().ping();
+    assert_eq!(call.method_name(), &StringName::from("renamed_ping"));
+    assert!(call.args().is_empty());
+    drop(call);
+
+    node.free();
+}
+
+#[itest]
+fn typed_rpc_call_local() {
+    let mut node = TypedRpcTest::new_alloc();
+
+    let mut root = root_with_multiplayer();
+    root.add_child(&node);
+
+    // The offline multiplayer peer has ID 1; targeting it only performs the local call.
+    let err = node.rpcs().heal(5, "potion").to_peer(1);
+    assert_eq!(err, godot::global::Error::OK);
+    assert_eq!(node.bind().health, 5);
+
+    node.call("heal_self", &[]);
+    assert_eq!(node.bind().health, 7);
+
+    root.remove_child(&node);
+    node.free();
+}
+
+#[itest]
+fn loopback_rpc_end_to_end() {
+    let mut root = root_with_multiplayer();
+    let mut tree = root.get_tree().unwrap();
+    let (server_peer, client_peer) = LoopbackPeer::new_pair(2);
+    assert_eq!(client_peer.to_string(), "LoopbackPeer(id=2)");
+
+    // Server and client live in separate subtrees of the same scene tree, each with its own multiplayer API. Node paths are relative to
+    // the subtree, so both players are found at `Player`.
+    let mut sides = [
+        ("LoopbackServer", server_peer),
+        ("LoopbackClient", client_peer),
+    ]
+    .map(|(name, peer)| {
+        let mut side_root = Node::new_alloc();
+        side_root.set_name(name);
+        root.add_child(&side_root);
+
+        let mut api = MultiplayerApi::create_default_interface().unwrap();
+        api.set_multiplayer_peer(&peer);
+        tree.set_multiplayer_ex(&api)
+            .root_path(&side_root.get_path())
+            .done();
+
+        let mut player = NetworkedPlayer::new_alloc();
+        player.set_name("Player");
+        side_root.add_child(&player);
+
+        (side_root, api, player)
+    });
+
+    let [(_, server_api, server_player), (_, client_api, client_player)] = &mut sides;
+
+    // First poll announces the peers to each other.
+    assert_eq!(server_api.poll(), Error::OK);
+    assert_eq!(client_api.poll(), Error::OK);
+    assert_eq!(server_api.get_peers().as_slice(), &[2]);
+    assert_eq!(client_api.get_peers().as_slice(), &[1]);
+
+    // Client -> server.
+    let err = client_player.rpcs().take_damage(30).to_peer(1);
+    assert_eq!(err, Error::OK);
+    assert_eq!(server_player.bind().health, 100, "not received before poll");
+
+    server_api.poll();
+    assert_eq!(server_player.bind().health, 70);
+    assert_eq!(server_player.bind().last_sender, 2);
+    assert_eq!(client_player.bind().health, 100, "no call_local");
+
+    // Server (authority) -> all clients.
+    let err = server_player.rpcs().set_health(250).to_all();
+    assert_eq!(err, Error::OK);
+
+    client_api.poll();
+    assert_eq!(client_player.bind().health, 250);
+
+    // Closing one side disconnects it on the other.
+    server_api.get_multiplayer_peer().unwrap().close();
+    client_api.poll();
+    assert!(client_api.get_peers().is_empty());
+
+    for (mut side_root, _, _) in sides {
+        tree.set_multiplayer_ex(Gd::null_arg())
+            .root_path(&side_root.get_path())
+            .done();
+        side_root.queue_free();
+    }
+}
+
+fn root_with_multiplayer() -> Gd<Window> {
     let mut scene_tree = Engine::singleton()
         .get_main_loop()
         .unwrap()
         .cast::<SceneTree>();
     scene_tree.set_multiplayer(MultiplayerApi::create_default_interface().as_ref());
 
-    let mut root = scene_tree.get_root().unwrap();
-    root.add_child(&node);
-    root.remove_child(&node);
-    node.free();
+    scene_tree.get_root().unwrap()
 }